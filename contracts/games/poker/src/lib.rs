@@ -8,7 +8,8 @@
 use soroban_sdk::{
     auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
     Address, Bytes, BytesN, Env, IntoVal, String, contract, contractclient, contracterror, contractimpl,
-    contracttype, symbol_short, vec, Symbol, Vec,
+    contracttype, events::Topics, panic_with_error, symbol_short, vec, Map, Symbol, TryFromVal,
+    Val, Vec,
 };
 
 #[contractclient(name = "GameHubClient")]
@@ -58,9 +59,61 @@ pub enum Error {
     NoWaitingSession = 26,
     WaitingTimeoutNotReached = 27,
     MessageTooLong = 28,
+    TournamentNotFound = 29,
+    InvalidTournamentConfig = 30,
+    RegistrationClosed = 31,
+    RegistrationOpen = 32,
+    AlreadyRegistered = 33,
+    TournamentFull = 34,
+    HandInProgress = 35,
+    InsufficientChips = 36,
+    StillHasChips = 37,
+    RebuyNotAllowed = 38,
+    HandMismatch = 39,
+    WaitingListFull = 40,
+    NotInvited = 41,
+    InviteExpired = 42,
+    InviteUsed = 43,
+    InviteRevoked = 44,
+    NoRematchOffer = 45,
+    InvalidSessionId = 46,
+    /// The game's storage expired before it settled; its stakes are still in escrow.
+    GameExpired = 47,
+    AdminNotSet = 48,
+    HubNotSet = 49,
+    /// The game has no second player yet.
+    NoOpponent = 50,
+    /// A seed was revealed before its commitment was stored.
+    NotCommitted = 51,
+    /// A chip amount overflowed or went negative.
+    ChipOverflow = 52,
+    /// Small blind not positive or above the big blind.
+    InvalidBlinds = 53,
+    /// Minimum buy-in not positive or above the maximum.
+    InvalidBuyInRange = 54,
+    /// Fewer than two seats.
+    InvalidSeats = 55,
+    TablePaused = 56,
+    TableRetired = 57,
+    /// New games and new stakes are paused.
+    ContractPaused = 58,
+    NotPaused = 59,
+    /// The contract has not been paused for EMERGENCY_GRACE yet.
+    GracePeriodNotOver = 60,
+    /// Caller does not hold the role the call needs.
+    Unauthorized = 61,
+    NoPendingOwner = 62,
+    /// Storage was written by a newer version of the contract than the one running.
+    SchemaTooNew = 63,
+    /// Every sequential game id the hub can tell apart from a session is taken.
+    GameIdsExhausted = 64,
+    /// initialize was called on a contract that already has its owner and config.
+    AlreadyInitialized = 65,
+    /// A table or seat allowlist names more than MAX_ALLOWLIST addresses.
+    AllowlistTooLong = 66,
 }
 
-/// A table (mesa) defines blinds, buy-in limits, and max seats. First table in constructor; more via add_table (admin only).
+/// A table (mesa) defines blinds, buy-in limits, and max seats. First table in constructor; more via add_table (operator only).
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Table {
@@ -72,7 +125,126 @@ pub struct Table {
     pub max_seats: u32,
 }
 
-/// One player waiting at a table. When 2nd sits, game is created.
+/// Accounts with a single duty besides the owner (the admin). A role nobody holds falls to the
+/// owner.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Role {
+    /// Adds, edits, pauses and retires tables and creates tournaments.
+    Operator = 0,
+    /// Can pause the contract.
+    Guardian = 1,
+}
+
+/// Whether a table takes new seats. A paused table can be reopened, a retired one cannot.
+/// Games already running at the table play on either way.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum TableStatus {
+    Open = 0,
+    Paused = 1,
+    Retired = 2,
+}
+
+/// Lobby metadata for a table: display name, game variant (e.g. "NLHE") and the currency its
+/// chips are counted in (e.g. "XLM"). Tables without metadata have an empty name and currency
+/// and variant NLHE.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TableMeta {
+    pub name: String,
+    pub variant: Symbol,
+    pub currency: Symbol,
+}
+
+/// How a heads-up session ends. Cash sessions run until a player leaves; a freezeout runs
+/// until one player has all the chips; BestOf(n) ends when a player has won a majority of n
+/// hands (or the other is out of chips). Match winners take both stacks.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MatchFormat {
+    Cash,
+    Freezeout,
+    BestOf(u32),
+}
+
+/// What happens when a player stops responding during the shuffle (no cards dealt yet).
+/// Refund: both players get their stacks back. Penalty(n): the abandoning player forfeits
+/// up to n chips to the opponent and the rest is refunded. The game is Cancelled, with no winner.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AbandonPolicy {
+    Refund,
+    Penalty(i128),
+}
+
+/// Where a keeper bounty is taken from. Forfeit: the stack of the player who timed out.
+/// Treasury: the rake the game has collected so far (the treasury's share).
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum BountySource {
+    Forfeit = 0,
+    Treasury = 1,
+}
+
+/// Reward for calling advance_timeout on a stalled cash game. Off until the admin sets it.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KeeperBounty {
+    /// Paid to the caller per successful advance_timeout.
+    pub amount: i128,
+    /// Most one game pays out in bounties over its whole session.
+    pub cap: i128,
+    pub source: BountySource,
+}
+
+/// Who may play at a table, or be paired with a waiting player.
+/// Allowlist: only the named addresses. Password: players must reveal a secret whose sha256
+/// is the stored hash (sit_at_table_with).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TableAccess {
+    Public,
+    Allowlist(Vec<Address>),
+    Password(BytesN<32>),
+}
+
+/// Player1's signed offer to start a session with start_game_with_invite. `opponent` None
+/// means anyone may accept. Valid up to ledger `expires_ledger`; `nonce` makes it single-use
+/// and lets player1 revoke it with revoke_invite.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Invite {
+    pub player1: Address,
+    pub opponent: Option<Address>,
+    pub table_id: u64,
+    pub buy_in: i128,
+    pub expires_ledger: u32,
+    pub nonce: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum InviteStatus {
+    Used = 0,
+    Revoked = 1,
+}
+
+/// A player's offer to play the same opponent again after a session ends. The offered buy-in
+/// is locked in escrow until the offer is accepted or cancelled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RematchOffer {
+    pub player: Address,
+    pub buy_in: i128,
+    pub created_at: u64,
+}
+
+/// One player waiting at a table. When a compatible opponent sits, a game is created.
 /// If nobody joins within waiting_timeout, anyone can cancel and player1 is refunded.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -82,6 +254,70 @@ pub struct WaitingSession {
     pub created_at: u64,
 }
 
+/// Lobby view of a table: its definition and current occupancy.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TableInfo {
+    pub table_id: u64,
+    pub table: Table,
+    /// Players waiting for an opponent at this table.
+    pub waiting: Vec<WaitingSession>,
+    /// Games at this table that are not finished or cancelled.
+    pub active_games: u32,
+    pub status: TableStatus,
+    pub meta: TableMeta,
+}
+
+/// One page of games. `next` is the cursor (game id) for the following page, None at the end.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GamePage {
+    pub games: Vec<Game>,
+    pub next: Option<u64>,
+}
+
+/// Response times (seconds) per phase. A table can override the global GameConfig values so
+/// turbo and deep-stack tables can coexist.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TimeoutConfig {
+    pub seed_commit: u64,
+    pub seed_reveal: u64,
+    pub betting: u64,
+    /// Time for the first hand reveal at showdown.
+    pub showdown: u64,
+    /// Time for the remaining player to submit their proof once the opponent has revealed.
+    pub proof_submission: u64,
+    /// Seconds after which a waiting session can be closed by anyone. 0 = no timeout.
+    pub waiting: u64,
+}
+
+/// Who is on the clock and until when. In betting rounds `deadline` includes the player's
+/// remaining timebank; `timebank` is what they have left right now.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TurnClock {
+    pub player: Address,
+    pub deadline: u64,
+    pub timebank: u64,
+}
+
+/// Games a player is in and the last ones they finished, newest first (reconnect index).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerGames {
+    pub active: Vec<u64>,
+    pub recent: Vec<u64>,
+}
+
+/// A player's seat in a table's waiting list.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WaitingSeat {
+    pub table_id: u64,
+    pub session: WaitingSession,
+}
+
 /// Result of sit_at_table: waiting (1/2) or game started. If !waiting then game_id is set.
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -129,6 +365,12 @@ pub struct GameConfig {
     pub bet_timeout: u64,
     /// Seconds after which a waiting session (1/2) can be closed; player1 refunded. 0 = no timeout.
     pub waiting_timeout: u64,
+    /// Per-player timebank (seconds) on top of bet_timeout in betting rounds; also its cap.
+    pub timebank: u64,
+    /// Seconds added back to each player's timebank at every new hand.
+    pub timebank_refill: u64,
+    /// Settlement when a cash game is abandoned during the shuffle.
+    pub abandon_policy: AbandonPolicy,
     pub treasury: Address,
     pub game_hub: Address,
     /// Optional: when set, lock_funds on create/join and payout_winner on game end.
@@ -145,6 +387,103 @@ pub struct Game {
     pub player1: Address,
     pub player2: Option<Address>,
     pub buy_in: i128,
+    /// Chips in the middle for the current hand.
+    pub pot: i128,
+    /// Chips behind for each player (not yet in the pot).
+    pub stack1: i128,
+    pub stack2: i128,
+    /// Total each player has brought to the session (buy-in plus rebuys and top-ups).
+    pub bought_in1: i128,
+    pub bought_in2: i128,
+    /// Rake taken from pots this session; paid to the treasury when the session settles.
+    pub rake: i128,
+    pub format: MatchFormat,
+    /// Hands won by each player this session.
+    pub hands_won1: u32,
+    pub hands_won2: u32,
+    /// Seconds of timebank left for each player.
+    pub timebank1: u64,
+    pub timebank2: u64,
+    /// Keeper bounties paid out of this game so far (bounded by KeeperBounty.cap).
+    pub bounty_paid: i128,
+    pub small_blind: i128,
+    pub big_blind: i128,
+    pub dealer_position: u32,
+    pub board: Vec<u32>,
+    pub board_revealed: u32,
+    pub current_bet_p1: i128,
+    pub current_bet_p2: i128,
+    pub total_bet_p1: i128,
+    pub total_bet_p2: i128,
+    pub min_raise: i128,
+    pub last_raise_amount: i128,
+    pub actor: u32,
+    pub folded: Option<Address>,
+    pub seed_commitment1: Option<BytesN<32>>,
+    pub seed_commitment2: Option<BytesN<32>>,
+    pub seed_reveal1: Option<BytesN<32>>,
+    pub seed_reveal2: Option<BytesN<32>>,
+    pub final_seed: Option<BytesN<32>>,
+    pub hand_commitment1: Option<BytesN<32>>,
+    pub hand_commitment2: Option<BytesN<32>>,
+    pub hand_rank1: Option<u32>,
+    pub hand_rank2: Option<u32>,
+    pub winner: Option<Address>,
+    pub created_at: u64,
+    pub last_action_at: u64,
+    pub table_id: u64,
+}
+
+/// Storage layout of a game: the session part, written when the game is created, when a
+/// player joins, rebuys or tops up, and when tournament blinds go up (DataKey::GameSession).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameSession {
+    pub player1: Address,
+    pub player2: Option<Address>,
+    pub table_id: u64,
+    pub buy_in: i128,
+    pub bought_in1: i128,
+    pub bought_in2: i128,
+    pub format: MatchFormat,
+    pub small_blind: i128,
+    pub big_blind: i128,
+    pub created_at: u64,
+}
+
+/// Storage layout of a game: the per-hand part, rewritten by every action (DataKey::GameHand).
+/// Small values are packed and the rest kept in positional vectors, so an action writes no
+/// field names.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HandState {
+    /// One byte each, from the lowest: state, actor, dealer_position, board_revealed, folded
+    /// and winner seats (0 none, 1 player1, 2 player2), hand_rank1, hand_rank2 (0 none);
+    /// then hands_won1 and hands_won2 in 32 bits each.
+    pub flags: u128,
+    /// pot, stack1, stack2, rake, current_bet_p1, current_bet_p2, total_bet_p1, total_bet_p2,
+    /// min_raise, last_raise_amount, bounty_paid.
+    pub chips: Vec<i128>,
+    /// timebank1, timebank2, last_action_at.
+    pub clock: Vec<u64>,
+    /// Board cards, one byte each from the lowest (cards are 1..=52, 0 ends the board).
+    pub board: u64,
+    /// seed_commitment1, seed_commitment2, seed_reveal1, seed_reveal2, final_seed,
+    /// hand_commitment1, hand_commitment2.
+    pub secrets: Vec<Option<BytesN<32>>>,
+}
+
+/// Game as schema 1 stored it, whole, under DataKey::Game and DataKey::SessionGame. Frozen:
+/// entries left in that layout are decoded with it and converted by upgrade_game.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameV1 {
+    pub id: u64,
+    pub state: GameState,
+    pub player1: Address,
+    pub player2: Option<Address>,
+    pub buy_in: i128,
+    /// Both buy-ins plus the bets of the hand: schema 1 kept no stacks.
     pub pot: i128,
     pub small_blind: i128,
     pub big_blind: i128,
@@ -174,6 +513,120 @@ pub struct Game {
     pub table_id: u64,
 }
 
+/// GameConfig as schema 1 stored it. Frozen: read (see load_config) until the first migrate
+/// call rewrites it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameConfigV1 {
+    pub min_buy_in: i128,
+    pub max_buy_in: i128,
+    pub small_blind: i128,
+    pub big_blind: i128,
+    pub rake_percentage: u32,
+    pub reveal_timeout: u64,
+    pub bet_timeout: u64,
+    pub waiting_timeout: u64,
+    pub treasury: Address,
+    pub game_hub: Address,
+    pub payment_controller: Option<Address>,
+    pub proof_verifier: Option<Address>,
+}
+
+/// Where a migration step stands (DataKey::MigrationCursor): the next table and game ids to
+/// scan, then the start_game sessions still to move.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationProgress {
+    pub table_id: u64,
+    pub game_id: u64,
+    pub session_ids: Vec<u64>,
+}
+
+/// Compact record of a game, archived in persistent storage once it is finished or cancelled
+/// so the result outlives the full Game entry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameSummary {
+    pub id: u64,
+    pub table_id: u64,
+    pub state: GameState,
+    pub player1: Address,
+    pub player2: Option<Address>,
+    pub winner: Option<Address>,
+    pub buy_in: i128,
+    pub bought_in1: i128,
+    pub bought_in2: i128,
+    pub format: MatchFormat,
+    pub hands_won1: u32,
+    pub hands_won2: u32,
+    pub created_at: u64,
+    pub ended_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum TournamentState {
+    Registering = 0,
+    Running = 1,
+    Finished = 2,
+    Cancelled = 3,
+}
+
+/// One step of a tournament blind schedule.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BlindLevel {
+    pub small_blind: i128,
+    pub big_blind: i128,
+}
+
+/// A tournament table. Games are heads-up, so a table seats at most two players; a lone
+/// survivor waits here until balancing brings an opponent from another table.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TournamentTable {
+    pub players: Vec<Address>,
+    /// Game currently being played at this table (None while fewer than 2 are seated).
+    pub game_id: Option<u64>,
+}
+
+/// Tournament parameters, fixed at creation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TournamentConfig {
+    /// Table definition the tournament is played on (variant/stakes template).
+    pub table_id: u64,
+    pub entry_fee: i128,
+    pub starting_chips: i128,
+    pub registration_deadline: u64,
+    pub max_entrants: u32,
+    pub blind_levels: Vec<BlindLevel>,
+    /// Seconds per blind level (0 = first level for the whole tournament). Tables pick up
+    /// the level in force when their game starts.
+    pub level_duration: u64,
+    /// Prize share per finishing position in basis points; index 0 is the winner.
+    pub payouts: Vec<u32>,
+}
+
+/// Multi-table freezeout. Players register until the deadline, are seated at random when it
+/// starts, and are eliminated when they lose their chips. Tables are merged as players bust
+/// until one final table decides the winner; prizes are paid by finishing position.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tournament {
+    pub id: u64,
+    pub state: TournamentState,
+    pub config: TournamentConfig,
+    pub entrants: Vec<Address>,
+    pub chips: Map<Address, i128>,
+    pub tables: Vec<TournamentTable>,
+    /// Busted players in elimination order (first out first).
+    pub eliminated: Vec<Address>,
+    pub remaining: u32,
+    pub started_at: u64,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
@@ -182,118 +635,1455 @@ pub enum DataKey {
     GameHubAddress,
     NextGameId,
     NextTableId,
+    /// A table. This and the other per-table keys are persistent; schema 1 kept tables in
+    /// instance storage.
     Table(u64),
-    /// One waiting session per table (1/2). Cleared when 2nd player sits and game starts.
+    /// Single waiting session per table, as written by earlier versions. Read through
+    /// load_table_queue and replaced by TableQueue on the next write.
     TableWaiting(u64),
+    /// Full game as stored by earlier versions. Read as is and replaced by GameSession and
+    /// GameHand on the next write.
     Game(u64),
-    /// Game created via start_game(session_id, ...) — client-provided session ID (XDR join flow).
+    /// start_game session stored by earlier versions under the client's session id. Read as
+    /// game SESSION_ID_BASE + session_id and moved to Game on the next write.
     SessionGame(u64),
+    NextTournamentId,
+    Tournament(u64),
+    /// Tournament a game belongs to, if it was seated by a tournament.
+    TournamentGame(u64),
+    /// Match format for games started at a table (Cash when unset).
+    TableFormat(u64),
+    /// Per-table timeout overrides (GameConfig timeouts when unset).
+    TableTimeouts(u64),
+    /// Keeper bounty for advance_timeout (none when unset).
+    KeeperBounty,
+    /// Table and index position of a game that is not finished or cancelled (lobby index).
+    ActiveGame(u64),
+    /// Game at a position of the lobby index. Positions 0..ActiveCount are all taken.
+    ActiveSlot(u32),
+    /// Number of games in the lobby index.
+    ActiveCount,
+    /// Number of active games per table.
+    TableActiveGames(u64),
+    /// Active and recently finished games of a player.
+    PlayerGames(Address),
+    /// Tables where a player is waiting for an opponent.
+    PlayerWaiting(Address),
+    /// Players waiting at a table, oldest first. Each newcomer is paired with one of them.
+    TableQueue(u64),
+    /// Buy-in difference (bps of the larger buy-in) accepted when pairing at a table (0 when unset).
+    TableTolerance(u64),
+    /// Who may play at a table (Public when unset).
+    TableAccess(u64),
+    /// Paused or retired tables (Open when unset).
+    TableStatus(u64),
+    /// Lobby metadata of a table, when set.
+    TableMeta(u64),
+    /// Who may be paired with a private waiting seat (table, waiting player).
+    SeatAccess(u64, Address),
+    /// Invites of a player (by nonce) that were used or revoked.
+    InviteNonce(Address, u64),
+    /// Pending rematch offer on a settled game (persistent: it backs a locked buy-in).
+    Rematch(u64),
+    /// Game a settled game was rematched into (persistent, so a game is rematched once).
+    RematchedTo(u64),
+    /// Summary of a finished or cancelled game (persistent).
+    Archive(u64),
+    /// Players, table and stakes of a game. Game ids: sequential below SESSION_ID_BASE,
+    /// start_game sessions at SESSION_ID_BASE + session_id.
+    GameSession(u64),
+    /// Current hand of a game.
+    GameHand(u64),
+    /// When the contract was paused (absent while it runs).
+    PausedAt,
+    Role(Role),
+    /// Owner proposed by propose_owner, until they accept.
+    PendingOwner,
+    /// Layout the stored entries follow (1 when unset: deployed before versioning).
+    SchemaVersion,
+    /// MigrationProgress, while a migration is under way.
+    MigrationCursor,
 }
 
 const GAME_TTL_LEDGERS: u32 = 518_400;
+/// How long (seconds) the contract must stay paused before players can withdraw with
+/// emergency_withdraw.
+pub const EMERGENCY_GRACE: u64 = 3 * 24 * 60 * 60;
+/// Entries are extended back to GAME_TTL_LEDGERS once their TTL drops below this.
+const TTL_THRESHOLD: u32 = GAME_TTL_LEDGERS / 2;
+/// Start of the game ids used by start_game sessions (client session ids are u32, like the hub's).
+/// Sequential game ids stay below it, so the two can never collide.
+pub const SESSION_ID_BASE: u64 = 1 << 32;
+/// The hub only knows u32 session ids: start_game sessions use theirs (below this), and
+/// sequential game `id` is reported as HUB_SEQUENTIAL_BASE + id.
+pub const HUB_SEQUENTIAL_BASE: u64 = 1 << 31;
+/// Storage layout this code writes. 1: GameConfigV1, whole GameV1 games under Game and
+/// SessionGame, and tables in instance storage with one waiting player under TableWaiting.
+/// 2: GameConfig, games split into GameSession and GameHand, and persistent tables and queues.
+/// Bump it with every change to a stored type, keep the old type frozen (like GameV1) and add
+/// the step to migrate_batch.
+pub const SCHEMA_VERSION: u32 = 2;
+/// Most table ids, game ids and sessions one migrate call handles.
+const MAX_MIGRATE_BATCH: u32 = 100;
+/// Most players waiting at one table.
+const MAX_WAITING: u32 = 20;
+/// Longest per-table timeout (seconds): a week, well within a game's storage lifetime.
+pub const MAX_TIMEOUT: u64 = 7 * 24 * 60 * 60;
+/// Most addresses in a table or seat allowlist.
+pub const MAX_ALLOWLIST: u32 = 50;
+/// Finished games kept per player in PlayerGames.recent.
+const RECENT_GAMES: u32 = 10;
+/// Most games a lobby page returns, and most index entries one page call reads.
+const MAX_PAGE: u32 = 50;
+const MAX_SCAN: u32 = 200;
 
-fn load_config(env: &Env) -> Result<GameConfig, Error> {
+/// Publish an event with tuple topics, the layout the frontend's history decodes. The
+/// PaymentController declares its few events as #[contractevent] types in that same layout;
+/// here the dozens of event kinds, some reusing a symbol with more topics (`REVEAL`), go
+/// through this one deprecated `Events::publish` call instead.
+#[allow(deprecated)]
+fn publish<T: Topics, D: IntoVal<Env, Val>>(env: &Env, topics: T, data: D) {
+    env.events().publish(topics, data);
+}
+
+fn load_admin(env: &Env) -> Result<Address, Error> {
     env.storage()
         .instance()
-        .get(&DataKey::Config)
-        .ok_or(Error::ConfigNotSet)
+        .get(&DataKey::Admin)
+        .ok_or(Error::AdminNotSet)
 }
 
-fn save_config(env: &Env, config: &GameConfig) {
-    env.storage().instance().set(&DataKey::Config, config);
+fn require_admin(env: &Env) -> Result<Address, Error> {
+    let admin = load_admin(env)?;
+    admin.require_auth();
+    Ok(admin)
 }
 
-fn save_game(env: &Env, game_id: u64, game: &Game) {
-    let key = DataKey::Game(game_id);
-    env.storage().temporary().set(&key, game);
-    env.storage()
-        .temporary()
-        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+/// Holder of `role`, the owner when unassigned.
+fn role_holder(env: &Env, role: Role) -> Result<Address, Error> {
+    match env.storage().instance().get(&DataKey::Role(role)) {
+        Some(holder) => Ok(holder),
+        None => load_admin(env),
+    }
+}
+
+fn require_role(env: &Env, role: Role) -> Result<Address, Error> {
+    let holder = role_holder(env, role)?;
+    holder.require_auth();
+    Ok(holder)
 }
 
-fn save_session_game(env: &Env, session_id: u64, game: &Game) {
-    let key = DataKey::SessionGame(session_id);
-    env.storage().temporary().set(&key, game);
+fn load_hub(env: &Env) -> Result<Address, Error> {
     env.storage()
-        .temporary()
-        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        .instance()
+        .get(&DataKey::GameHubAddress)
+        .ok_or(Error::HubNotSet)
 }
 
-fn load_game(env: &Env, game_id: u64) -> Result<Game, Error> {
-    if let Some(game) = env.storage().temporary().get(&DataKey::SessionGame(game_id)) {
-        return Ok(game);
+/// New games and new stakes are refused while the contract is paused.
+fn require_not_paused(env: &Env) -> Result<(), Error> {
+    if env.storage().instance().has(&DataKey::PausedAt) {
+        return Err(Error::ContractPaused);
     }
-    env.storage()
-        .temporary()
-        .get(&DataKey::Game(game_id))
-        .ok_or(Error::GameNotFound)
+    Ok(())
 }
 
-fn next_game_id(env: &Env) -> u64 {
-    let key = DataKey::NextGameId;
-    let id: u64 = env.storage().instance().get(&key).unwrap_or(0);
-    env.storage().instance().set(&key, &(id + 1));
-    id
+/// The stored config is still a GameConfigV1: schema 1 and no migration started yet.
+fn config_is_v1(env: &Env) -> bool {
+    schema_version(env) == 1 && !env.storage().instance().has(&DataKey::MigrationCursor)
 }
 
-fn next_table_id(env: &Env) -> u64 {
-    let key = DataKey::NextTableId;
-    let id: u64 = env.storage().instance().get(&key).unwrap_or(0);
-    env.storage().instance().set(&key, &(id + 1));
-    id
+fn load_config(env: &Env) -> Result<GameConfig, Error> {
+    let storage = env.storage().instance();
+    if config_is_v1(env) {
+        return storage
+            .get::<_, GameConfigV1>(&DataKey::Config)
+            .map(upgrade_config)
+            .ok_or(Error::ConfigNotSet);
+    }
+    storage.get(&DataKey::Config).ok_or(Error::ConfigNotSet)
 }
 
-fn load_table(env: &Env, table_id: u64) -> Result<Table, Error> {
-    env.storage()
-        .instance()
-        .get(&DataKey::Table(table_id))
-        .ok_or(Error::TableNotFound)
+/// Writing the config in the current layout starts the schema 1 migration, so that it is no
+/// longer read as a GameConfigV1.
+fn save_config(env: &Env, config: &GameConfig) {
+    let storage = env.storage().instance();
+    if config_is_v1(env) {
+        let progress = MigrationProgress {
+            table_id: 0,
+            game_id: 0,
+            session_ids: Vec::new(env),
+        };
+        storage.set(&DataKey::MigrationCursor, &progress);
+    }
+    storage.set(&DataKey::Config, config);
 }
 
-fn save_table(env: &Env, table_id: u64, table: &Table) {
-    env.storage().instance().set(&DataKey::Table(table_id), table);
+/// A schema 1 config, with the settings added since at their defaults.
+fn upgrade_config(config: GameConfigV1) -> GameConfig {
+    GameConfig {
+        min_buy_in: config.min_buy_in,
+        max_buy_in: config.max_buy_in,
+        small_blind: config.small_blind,
+        big_blind: config.big_blind,
+        rake_percentage: config.rake_percentage,
+        reveal_timeout: config.reveal_timeout,
+        bet_timeout: config.bet_timeout,
+        waiting_timeout: config.waiting_timeout,
+        timebank: 60,
+        timebank_refill: 10,
+        abandon_policy: AbandonPolicy::Refund,
+        treasury: config.treasury,
+        game_hub: config.game_hub,
+        payment_controller: config.payment_controller,
+        proof_verifier: config.proof_verifier,
+    }
 }
 
-fn load_table_waiting(env: &Env, table_id: u64) -> Option<WaitingSession> {
+/// Write a game's hand state, and its session when the game is new. Callers that change the
+/// session (players, buy-ins, blinds) call save_session first.
+fn save_game(env: &Env, game_id: u64, game: &Game) {
+    debug_assert!(chips_conserved(game), "chips not conserved in game {}", game_id);
+    let storage = env.storage().temporary();
+    let (session, hand) = split_game(game);
+    let hand_key = DataKey::GameHand(game_id);
+    let created = !storage.has(&hand_key);
+    storage.set(&hand_key, &hand);
+    storage.extend_ttl(&hand_key, TTL_THRESHOLD, GAME_TTL_LEDGERS);
+    let session_key = DataKey::GameSession(game_id);
+    if created {
+        storage.set(&session_key, &session);
+    } else {
+        debug_assert!(
+            storage.get::<_, GameSession>(&session_key).as_ref() == Some(&session),
+            "session of game {} changed without save_session",
+            game_id
+        );
+    }
+    storage.extend_ttl(&session_key, TTL_THRESHOLD, GAME_TTL_LEDGERS);
+    if storage.has(&DataKey::Game(game_id)) {
+        storage.remove(&DataKey::Game(game_id));
+    }
     env.storage()
         .instance()
-        .get(&DataKey::TableWaiting(table_id))
+        .extend_ttl(TTL_THRESHOLD, GAME_TTL_LEDGERS);
+    let settled =
+        is_over(game.state) && !env.storage().persistent().has(&DataKey::Archive(game_id));
+    if settled {
+        archive_game(env, game);
+    }
+    if let Some(legacy) = legacy_session_key(game_id) {
+        if env.storage().temporary().has(&legacy) {
+            let session_id = game_id - SESSION_ID_BASE;
+            env.storage().temporary().remove(&legacy);
+            // Schema 1 also wrote the session's moves under Game(session_id).
+            env.storage().temporary().remove(&DataKey::Game(session_id));
+            reindex_session_game(env, session_id, game);
+        }
+    }
+    // The indexes only change when a game starts or settles, not on every action.
+    if settled {
+        unindex_game(env, game_id, game);
+    } else if created && !is_over(game.state) {
+        index_game(env, game_id, game);
+    }
 }
 
-fn save_table_waiting(env: &Env, table_id: u64, session: &WaitingSession) {
+/// Write the session part of an existing game.
+fn save_session(env: &Env, game_id: u64, game: &Game) {
+    let (session, _) = split_game(game);
     env.storage()
-        .instance()
-        .set(&DataKey::TableWaiting(table_id), session);
+        .temporary()
+        .set(&DataKey::GameSession(game_id), &session);
 }
 
-fn clear_table_waiting(env: &Env, table_id: u64) {
-    env.storage()
-        .instance()
-        .remove(&DataKey::TableWaiting(table_id));
+/// Seat code of a player of the game: 0 for none, 1 for player1, 2 for player2.
+fn seat_code(game: &Game, player: &Option<Address>) -> u128 {
+    match player {
+        None => 0,
+        Some(player) if *player == game.player1 => 1,
+        Some(_) => 2,
+    }
 }
 
-fn is_player(game: &Game, player: &Address) -> bool {
-    *player == game.player1
-        || game
-            .player2
-            .as_ref()
-            .map_or(false, |p2| player == p2)
+fn seat_player(session: &GameSession, code: u128) -> Option<Address> {
+    match code {
+        1 => Some(session.player1.clone()),
+        2 => session.player2.clone(),
+        _ => None,
+    }
 }
 
-fn is_current_actor(game: &Game, player: &Address) -> bool {
-    let actor = if game.actor == 0u32 {
-        &game.player1
-    } else {
-        game.player2.as_ref().unwrap()
+fn split_game(game: &Game) -> (GameSession, HandState) {
+    let env = game.board.env();
+    let session = GameSession {
+        player1: game.player1.clone(),
+        player2: game.player2.clone(),
+        table_id: game.table_id,
+        buy_in: game.buy_in,
+        bought_in1: game.bought_in1,
+        bought_in2: game.bought_in2,
+        format: game.format,
+        small_blind: game.small_blind,
+        big_blind: game.big_blind,
+        created_at: game.created_at,
+    };
+    let flags = game.state as u128
+        | (game.actor as u128 & 0xff) << 8
+        | (game.dealer_position as u128 & 0xff) << 16
+        | (game.board_revealed as u128 & 0xff) << 24
+        | seat_code(game, &game.folded) << 32
+        | seat_code(game, &game.winner) << 40
+        | (game.hand_rank1.unwrap_or(0) as u128 & 0xff) << 48
+        | (game.hand_rank2.unwrap_or(0) as u128 & 0xff) << 56
+        | (game.hands_won1 as u128) << 64
+        | (game.hands_won2 as u128) << 96;
+    let mut board = 0u64;
+    for (at, card) in game.board.iter().take(8).enumerate() {
+        board |= (card as u64 & 0xff) << (8 * at);
+    }
+    let hand = HandState {
+        flags,
+        chips: vec![
+            env,
+            game.pot,
+            game.stack1,
+            game.stack2,
+            game.rake,
+            game.current_bet_p1,
+            game.current_bet_p2,
+            game.total_bet_p1,
+            game.total_bet_p2,
+            game.min_raise,
+            game.last_raise_amount,
+            game.bounty_paid,
+        ],
+        clock: vec![env, game.timebank1, game.timebank2, game.last_action_at],
+        board,
+        secrets: vec![
+            env,
+            game.seed_commitment1.clone(),
+            game.seed_commitment2.clone(),
+            game.seed_reveal1.clone(),
+            game.seed_reveal2.clone(),
+            game.final_seed.clone(),
+            game.hand_commitment1.clone(),
+            game.hand_commitment2.clone(),
+        ],
     };
-    player == actor
+    (session, hand)
 }
 
-fn advance_betting_round(game: &mut Game) -> GameState {
-    match game.state {
-        GameState::PreFlop => {
-            game.board_revealed = 3;
-            game.actor = game.dealer_position;
+fn assemble_game(env: &Env, game_id: u64, session: GameSession, hand: HandState) -> Game {
+    let byte = |at: u32| (hand.flags >> (8 * at)) & 0xff;
+    let rank = |at: u32| Some(byte(at) as u32).filter(|rank| *rank != 0);
+    let chips = |at: u32| hand.chips.get(at).unwrap_or(0);
+    let clock = |at: u32| hand.clock.get(at).unwrap_or(0);
+    let secret = |at: u32| hand.secrets.get(at).flatten();
+    let mut board = Vec::new(env);
+    for at in 0..8 {
+        let card = (hand.board >> (8 * at)) & 0xff;
+        if card == 0 {
+            break;
+        }
+        board.push_back(card as u32);
+    }
+    Game {
+        id: game_id,
+        state: game_state(byte(0) as u32),
+        folded: seat_player(&session, byte(4)),
+        winner: seat_player(&session, byte(5)),
+        player1: session.player1,
+        player2: session.player2,
+        buy_in: session.buy_in,
+        pot: chips(0),
+        stack1: chips(1),
+        stack2: chips(2),
+        bought_in1: session.bought_in1,
+        bought_in2: session.bought_in2,
+        rake: chips(3),
+        format: session.format,
+        hands_won1: (hand.flags >> 64) as u32,
+        hands_won2: (hand.flags >> 96) as u32,
+        timebank1: clock(0),
+        timebank2: clock(1),
+        bounty_paid: chips(10),
+        small_blind: session.small_blind,
+        big_blind: session.big_blind,
+        dealer_position: byte(2) as u32,
+        board,
+        board_revealed: byte(3) as u32,
+        current_bet_p1: chips(4),
+        current_bet_p2: chips(5),
+        total_bet_p1: chips(6),
+        total_bet_p2: chips(7),
+        min_raise: chips(8),
+        last_raise_amount: chips(9),
+        actor: byte(1) as u32,
+        seed_commitment1: secret(0),
+        seed_commitment2: secret(1),
+        seed_reveal1: secret(2),
+        seed_reveal2: secret(3),
+        final_seed: secret(4),
+        hand_commitment1: secret(5),
+        hand_commitment2: secret(6),
+        hand_rank1: rank(6),
+        hand_rank2: rank(7),
+        created_at: session.created_at,
+        last_action_at: clock(2),
+        table_id: session.table_id,
+    }
+}
+
+fn game_state(code: u32) -> GameState {
+    match code {
+        0 => GameState::WaitingForPlayers,
+        1 => GameState::ShuffleCommit,
+        2 => GameState::ShuffleReveal,
+        3 => GameState::DealCards,
+        4 => GameState::PreFlop,
+        5 => GameState::FlopBetting,
+        6 => GameState::TurnBetting,
+        7 => GameState::RiverBetting,
+        8 => GameState::Showdown,
+        9 => GameState::Finished,
+        _ => GameState::Cancelled,
+    }
+}
+
+fn summarize(game: &Game) -> GameSummary {
+    GameSummary {
+        id: game.id,
+        table_id: game.table_id,
+        state: game.state,
+        player1: game.player1.clone(),
+        player2: game.player2.clone(),
+        winner: game.winner.clone(),
+        buy_in: game.buy_in,
+        bought_in1: game.bought_in1,
+        bought_in2: game.bought_in2,
+        format: game.format,
+        hands_won1: game.hands_won1,
+        hands_won2: game.hands_won2,
+        created_at: game.created_at,
+        ended_at: game.last_action_at,
+    }
+}
+
+fn archive_game(env: &Env, game: &Game) {
+    let key = DataKey::Archive(game.id);
+    env.storage().persistent().set(&key, &summarize(game));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Game id of a start_game session. Session ids take the lower half of the hub's u32 range.
+fn session_game_id(session_id: u64) -> Result<u64, Error> {
+    if session_id >= HUB_SEQUENTIAL_BASE {
+        return Err(Error::InvalidSessionId);
+    }
+    Ok(SESSION_ID_BASE + session_id)
+}
+
+/// Session id the hub knows game `game_id` by.
+fn hub_session_id(game_id: u64) -> u32 {
+    if game_id >= SESSION_ID_BASE {
+        (game_id - SESSION_ID_BASE) as u32
+    } else {
+        (HUB_SEQUENTIAL_BASE + game_id) as u32
+    }
+}
+
+/// Where earlier versions stored the session game `game_id`, if it is one.
+fn legacy_session_key(game_id: u64) -> Option<DataKey> {
+    (game_id >= SESSION_ID_BASE).then(|| DataKey::SessionGame(game_id - SESSION_ID_BASE))
+}
+
+/// Where schema 1 stored sequential game `game_id`, if it is there. Schema 1 also wrote a
+/// start_game session's moves under Game(session_id) but read SessionGame(session_id) first,
+/// so a Game entry shadowed by a SessionGame one belongs to that session, not to a game.
+fn legacy_game_key(env: &Env, game_id: u64) -> Option<DataKey> {
+    let storage = env.storage().temporary();
+    let key = DataKey::Game(game_id);
+    let shadowed = storage.has(&DataKey::SessionGame(game_id));
+    (storage.has(&key) && !shadowed).then_some(key)
+}
+
+fn is_over(state: GameState) -> bool {
+    matches!(state, GameState::Finished | GameState::Cancelled)
+}
+
+fn active_count(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::ActiveCount).unwrap_or(0)
+}
+
+fn is_active_game(env: &Env, game_id: u64) -> bool {
+    env.storage().persistent().has(&DataKey::ActiveGame(game_id))
+}
+
+fn set_active_slot(env: &Env, slot: u32, game_id: u64, table_id: u64) {
+    let storage = env.storage().persistent();
+    for (key, value) in [
+        (DataKey::ActiveSlot(slot), game_id.into_val(env)),
+        (DataKey::ActiveGame(game_id), (table_id, slot).into_val(env)),
+    ] {
+        storage.set::<_, Val>(&key, &value);
+        storage.extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+}
+
+/// Append a game to the lobby index. Returns false if it was already listed.
+fn add_active_game(env: &Env, game_id: u64, table_id: u64) -> bool {
+    if is_active_game(env, game_id) {
+        return false;
+    }
+    let count = active_count(env);
+    set_active_slot(env, count, game_id, table_id);
+    env.storage().instance().set(&DataKey::ActiveCount, &(count + 1));
+    add_table_active_games(env, table_id, 1);
+    true
+}
+
+/// Drop a game from the lobby index; the last listed game takes its position, so the index
+/// stays dense and every change touches a fixed number of entries. Returns false if the
+/// game was not listed.
+fn remove_active_game(env: &Env, game_id: u64) -> bool {
+    let storage = env.storage().persistent();
+    let Some((table_id, slot)) = storage.get::<_, (u64, u32)>(&DataKey::ActiveGame(game_id)) else {
+        return false;
+    };
+    let last = active_count(env).saturating_sub(1);
+    if slot != last {
+        if let Some(moved) = storage.get::<_, u64>(&DataKey::ActiveSlot(last)) {
+            if let Some((moved_table, _)) = storage.get::<_, (u64, u32)>(&DataKey::ActiveGame(moved)) {
+                set_active_slot(env, slot, moved, moved_table);
+            }
+        }
+    }
+    storage.remove(&DataKey::ActiveSlot(last));
+    storage.remove(&DataKey::ActiveGame(game_id));
+    env.storage().instance().set(&DataKey::ActiveCount, &last);
+    add_table_active_games(env, table_id, -1);
+    true
+}
+
+fn table_active_games(env: &Env, table_id: u64) -> u32 {
+    load_table_entry(env, &DataKey::TableActiveGames(table_id)).unwrap_or(0)
+}
+
+/// Count a game starting (+1) or settling (-1) at a table. A retired table is deleted when
+/// its last game settles.
+fn add_table_active_games(env: &Env, table_id: u64, delta: i32) {
+    let key = DataKey::TableActiveGames(table_id);
+    let count = table_active_games(env, table_id).saturating_add_signed(delta);
+    if count > 0 {
+        save_table_entry(env, &key, &count);
+    } else {
+        env.storage().persistent().remove(&key);
+        if load_table_status(env, table_id) == TableStatus::Retired {
+            purge_table(env, table_id);
+        }
+    }
+}
+
+/// List a new game in the lobby index and its players' indexes.
+fn index_game(env: &Env, game_id: u64, game: &Game) {
+    if add_active_game(env, game_id, game.table_id) {
+        for player in core::iter::once(&game.player1).chain(game.player2.as_ref()) {
+            index_player_game(env, player, game_id, false);
+        }
+    }
+}
+
+/// A game settled: it leaves the lobby index (and its table's count) and moves to its
+/// players' recent games.
+fn unindex_game(env: &Env, game_id: u64, game: &Game) {
+    if remove_active_game(env, game_id) {
+        for player in core::iter::once(&game.player1).chain(game.player2.as_ref()) {
+            index_player_game(env, player, game_id, true);
+        }
+    }
+}
+
+fn game_exists(env: &Env, game_id: u64) -> bool {
+    let storage = env.storage().temporary();
+    storage.has(&DataKey::GameHand(game_id))
+        || legacy_game_key(env, game_id).is_some()
+        || legacy_session_key(game_id).is_some_and(|legacy| storage.has(&legacy))
+}
+
+fn load_player_games(env: &Env, player: &Address) -> PlayerGames {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PlayerGames(player.clone()))
+        .unwrap_or(PlayerGames {
+            active: Vec::new(env),
+            recent: Vec::new(env),
+        })
+}
+
+/// Record a game as active for a player, or move it to their recent games once it is over.
+/// Ids of games whose storage expired are dropped on the way.
+fn index_player_game(env: &Env, player: &Address, game_id: u64, over: bool) {
+    let mut games = load_player_games(env, player);
+    let mut active = Vec::new(env);
+    for id in games.active.iter() {
+        if id != game_id && game_exists(env, id) {
+            active.push_back(id);
+        }
+    }
+    if over {
+        games.recent.push_front(game_id);
+        while games.recent.len() > RECENT_GAMES {
+            games.recent.pop_back();
+        }
+    } else {
+        active.push_back(game_id);
+    }
+    games.active = active;
+    let key = DataKey::PlayerGames(player.clone());
+    env.storage().persistent().set(&key, &games);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Walk the active index from position `cursor`, keeping games accepted by `keep`. Reads at
+/// most MAX_SCAN entries and returns at most `limit` (capped at MAX_PAGE) games.
+fn page_games(env: &Env, cursor: u64, limit: u32, keep: impl Fn(&Game) -> bool) -> GamePage {
+    let count = active_count(env) as u64;
+    let limit = limit.min(MAX_PAGE);
+    let mut games = Vec::new(env);
+    let mut next = None;
+    for (scanned, slot) in (cursor..count).enumerate() {
+        if games.len() == limit || scanned as u32 == MAX_SCAN {
+            next = Some(slot);
+            break;
+        }
+        let Some(game_id) = env.storage().persistent().get(&DataKey::ActiveSlot(slot as u32)) else {
+            continue;
+        };
+        // Games whose storage expired stay in the index until prune_games drops them.
+        if let Some(game) = stored_game(env, game_id) {
+            if keep(&game) {
+                games.push_back(game);
+            }
+        }
+    }
+    GamePage { games, next }
+}
+
+/// Deadline for the player on the clock (timebank included); None when no timeout applies.
+fn game_deadline(env: &Env, game: &Game, config: &GameConfig) -> Option<u64> {
+    phase_timeout(env, game, config)
+        .ok()
+        .map(|timeout| {
+            game.last_action_at
+                .saturating_add(timeout)
+                .saturating_add(actor_timebank(game))
+        })
+}
+
+fn load_game(env: &Env, game_id: u64) -> Result<Game, Error> {
+    if let Some(game) = stored_game(env, game_id) {
+        return Ok(game);
+    }
+    env.storage()
+        .persistent()
+        .get(&DataKey::Archive(game_id))
+        .map(|summary| closed_game(env, summary))
+        .ok_or_else(|| missing_game_error(env, game_id))
+}
+
+/// A closed game as get_game shows it: the archived result, with no chips left and nothing
+/// of the last hand.
+fn closed_game(env: &Env, summary: GameSummary) -> Game {
+    Game {
+        id: summary.id,
+        state: summary.state,
+        player1: summary.player1,
+        player2: summary.player2,
+        buy_in: summary.buy_in,
+        pot: 0,
+        stack1: 0,
+        stack2: 0,
+        bought_in1: summary.bought_in1,
+        bought_in2: summary.bought_in2,
+        rake: 0,
+        format: summary.format,
+        hands_won1: summary.hands_won1,
+        hands_won2: summary.hands_won2,
+        timebank1: 0,
+        timebank2: 0,
+        bounty_paid: 0,
+        small_blind: 0,
+        big_blind: 0,
+        dealer_position: 0,
+        board: Vec::new(env),
+        board_revealed: 0,
+        current_bet_p1: 0,
+        current_bet_p2: 0,
+        total_bet_p1: 0,
+        total_bet_p2: 0,
+        min_raise: 0,
+        last_raise_amount: 0,
+        actor: 0,
+        folded: None,
+        seed_commitment1: None,
+        seed_commitment2: None,
+        seed_reveal1: None,
+        seed_reveal2: None,
+        final_seed: None,
+        hand_commitment1: None,
+        hand_commitment2: None,
+        hand_rank1: None,
+        hand_rank2: None,
+        winner: summary.winner,
+        created_at: summary.created_at,
+        last_action_at: summary.ended_at,
+        table_id: summary.table_id,
+    }
+}
+
+/// The game's full state, if it is still stored. Reading an active game keeps it alive.
+fn stored_game(env: &Env, game_id: u64) -> Option<Game> {
+    let storage = env.storage().temporary();
+    let hand_key = DataKey::GameHand(game_id);
+    if let Some(hand) = storage.get::<_, HandState>(&hand_key) {
+        let session_key = DataKey::GameSession(game_id);
+        let game = assemble_game(env, game_id, storage.get(&session_key)?, hand);
+        if !is_over(game.state) {
+            storage.extend_ttl(&hand_key, TTL_THRESHOLD, GAME_TTL_LEDGERS);
+            storage.extend_ttl(&session_key, TTL_THRESHOLD, GAME_TTL_LEDGERS);
+        }
+        return Some(game);
+    }
+    if let Some(key) = legacy_game_key(env, game_id) {
+        let game = storage.get::<_, GameV1>(&key)?;
+        return Some(upgrade_game(env, game_id, game));
+    }
+    let legacy = legacy_session_key(game_id)?;
+    let game = storage.get::<_, GameV1>(&legacy)?;
+    Some(upgrade_game(env, game_id, game))
+}
+
+/// A schema 1 game as game `game_id` in the current layout. Schema 1 kept no stacks: each
+/// seated player had bought in for buy_in, and the bets of the hand in progress (at most the
+/// buy-in) are what is in the pot. A game that is over holds no chips.
+fn upgrade_game(env: &Env, game_id: u64, game: GameV1) -> Game {
+    let timebank = load_config(env).map(|c| c.timebank).unwrap_or(0);
+    let bought_in1 = game.buy_in.max(0);
+    let bought_in2 = if game.player2.is_some() { bought_in1 } else { 0 };
+    let over = is_over(game.state);
+    let bet1 = if over { 0 } else { game.total_bet_p1.clamp(0, bought_in1) };
+    let bet2 = if over { 0 } else { game.total_bet_p2.clamp(0, bought_in2) };
+    let (stack1, stack2) = if over {
+        (0, 0)
+    } else {
+        (bought_in1 - bet1, bought_in2 - bet2)
+    };
+    Game {
+        id: game_id,
+        state: game.state,
+        player1: game.player1,
+        player2: game.player2,
+        buy_in: game.buy_in,
+        pot: bet1 + bet2,
+        stack1,
+        stack2,
+        bought_in1,
+        bought_in2,
+        rake: 0,
+        format: MatchFormat::Cash,
+        hands_won1: 0,
+        hands_won2: 0,
+        timebank1: timebank,
+        timebank2: timebank,
+        bounty_paid: 0,
+        small_blind: game.small_blind,
+        big_blind: game.big_blind,
+        dealer_position: game.dealer_position,
+        board: game.board,
+        board_revealed: game.board_revealed,
+        current_bet_p1: game.current_bet_p1.clamp(0, bet1),
+        current_bet_p2: game.current_bet_p2.clamp(0, bet2),
+        total_bet_p1: bet1,
+        total_bet_p2: bet2,
+        min_raise: game.min_raise,
+        last_raise_amount: game.last_raise_amount,
+        actor: game.actor,
+        folded: game.folded,
+        seed_commitment1: game.seed_commitment1,
+        seed_commitment2: game.seed_commitment2,
+        seed_reveal1: game.seed_reveal1,
+        seed_reveal2: game.seed_reveal2,
+        final_seed: game.final_seed,
+        hand_commitment1: game.hand_commitment1,
+        hand_commitment2: game.hand_commitment2,
+        hand_rank1: game.hand_rank1,
+        hand_rank2: game.hand_rank2,
+        winner: game.winner,
+        created_at: game.created_at,
+        last_action_at: game.last_action_at,
+        table_id: game.table_id,
+    }
+}
+
+/// A game that is gone from storage but still listed as active expired with its stakes in
+/// escrow; anything else was never created (or settled long ago).
+fn missing_game_error(env: &Env, game_id: u64) -> Error {
+    if is_active_game(env, game_id) {
+        Error::GameExpired
+    } else {
+        Error::GameNotFound
+    }
+}
+
+/// Point the lobby and player indexes at a legacy session game's new id. Entries under the old
+/// id are left alone where a sequential game with that id owns them.
+fn reindex_session_game(env: &Env, session_id: u64, game: &Game) {
+    let owner = stored_game(env, session_id);
+    let old_key = DataKey::ActiveGame(session_id);
+    if owner.is_none() {
+        if let Some((table_id, slot)) = env.storage().persistent().get::<_, (u64, u32)>(&old_key) {
+            env.storage().persistent().remove(&old_key);
+            set_active_slot(env, slot, game.id, table_id);
+        }
+    }
+    for player in core::iter::once(&game.player1).chain(game.player2.as_ref()) {
+        if owner.as_ref().is_some_and(|owner| is_player(owner, player)) {
+            continue;
+        }
+        let mut games = load_player_games(env, player);
+        for list in [&mut games.active, &mut games.recent] {
+            if let Some(at) = list.first_index_of(session_id) {
+                list.set(at, game.id);
+            }
+        }
+        let key = DataKey::PlayerGames(player.clone());
+        env.storage().persistent().set(&key, &games);
+    }
+}
+
+fn schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(1)
+}
+
+/// Rewrite the entries of up to `limit` tables, games and sessions (in that order) from the
+/// layout of schema `from` into the next one, moving `progress` along.
+fn migrate_batch(env: &Env, from: u32, progress: &mut MigrationProgress, limit: u32) {
+    let table_count: u64 = env.storage().instance().get(&DataKey::NextTableId).unwrap_or(0);
+    let game_count: u64 = env.storage().instance().get(&DataKey::NextGameId).unwrap_or(0);
+    for _ in 0..limit {
+        if progress.table_id < table_count {
+            let table_id = progress.table_id;
+            let instance = env.storage().instance();
+            if from == 1 && instance.has(&DataKey::Table(table_id)) {
+                if let Ok(table) = load_table(env, table_id) {
+                    save_table(env, table_id, &table);
+                }
+            }
+            if from == 1 && instance.has(&DataKey::TableWaiting(table_id)) {
+                save_table_queue(env, table_id, &load_table_queue(env, table_id));
+            }
+            progress.table_id += 1;
+        } else if progress.game_id < game_count {
+            let game_id = progress.game_id;
+            if from == 1 && env.storage().temporary().has(&DataKey::Game(game_id)) {
+                if legacy_game_key(env, game_id).is_some() {
+                    if let Some(game) = stored_game(env, game_id) {
+                        save_game(env, game_id, &game);
+                    }
+                } else {
+                    // Shadowed by a session: moving the session drops it.
+                    move_legacy_session(env, game_id);
+                }
+            }
+            progress.game_id += 1;
+        } else if let Some(session_id) = progress.session_ids.pop_front() {
+            if from == 1 {
+                move_legacy_session(env, session_id);
+            }
+        } else {
+            break;
+        }
+    }
+}
+
+/// Move a schema 1 session from SessionGame(session_id) to its game id, if it is still there.
+/// Ids too large to have a game id were never readable and are skipped.
+fn move_legacy_session(env: &Env, session_id: u64) {
+    let Some(game_id) = SESSION_ID_BASE.checked_add(session_id) else {
+        return;
+    };
+    let storage = env.storage().temporary();
+    if storage.has(&DataKey::SessionGame(session_id)) && !storage.has(&DataKey::GameHand(game_id)) {
+        if let Some(game) = stored_game(env, game_id) {
+            save_game(env, game_id, &game);
+        }
+    }
+}
+
+fn next_game_id(env: &Env) -> Result<u64, Error> {
+    let key = DataKey::NextGameId;
+    let id: u64 = env.storage().instance().get(&key).unwrap_or(0);
+    if id >= HUB_SEQUENTIAL_BASE {
+        return Err(Error::GameIdsExhausted);
+    }
+    env.storage().instance().set(&key, &(id + 1));
+    Ok(id)
+}
+
+fn next_table_id(env: &Env) -> u64 {
+    let key = DataKey::NextTableId;
+    let id: u64 = env.storage().instance().get(&key).unwrap_or(0);
+    env.storage().instance().set(&key, &(id + 1));
+    id
+}
+
+/// Per-table entries live in persistent storage, so instance storage does not grow with the
+/// number of tables. Using an entry keeps it alive.
+fn load_table_entry<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let storage = env.storage().persistent();
+    let value = storage.get(key)?;
+    storage.extend_ttl(key, TTL_THRESHOLD, GAME_TTL_LEDGERS);
+    Some(value)
+}
+
+fn save_table_entry<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    let storage = env.storage().persistent();
+    storage.set(key, value);
+    storage.extend_ttl(key, TTL_THRESHOLD, GAME_TTL_LEDGERS);
+}
+
+/// Set an optional per-table entry, or delete it (None).
+fn set_table_entry<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: Option<&V>) {
+    match value {
+        Some(value) => save_table_entry(env, key, value),
+        None => env.storage().persistent().remove(key),
+    }
+}
+
+/// A table, or one schema 1 still holds in instance storage (moved by migrate).
+fn load_table(env: &Env, table_id: u64) -> Result<Table, Error> {
+    let key = DataKey::Table(table_id);
+    load_table_entry(env, &key)
+        .or_else(|| env.storage().instance().get(&key))
+        .ok_or(Error::TableNotFound)
+}
+
+/// Delete a retired table that has no games left. Its waiting list was refunded on retire.
+fn purge_table(env: &Env, table_id: u64) {
+    let storage = env.storage().persistent();
+    for key in [
+        DataKey::Table(table_id),
+        DataKey::TableQueue(table_id),
+        DataKey::TableFormat(table_id),
+        DataKey::TableTimeouts(table_id),
+        DataKey::TableTolerance(table_id),
+        DataKey::TableAccess(table_id),
+        DataKey::TableStatus(table_id),
+        DataKey::TableMeta(table_id),
+        DataKey::TableActiveGames(table_id),
+    ] {
+        storage.remove(&key);
+    }
+    publish(env, (symbol_short!("TBL_DEL"), table_id), ());
+}
+
+/// A table that takes new seats: TablePaused or TableRetired otherwise.
+fn load_open_table(env: &Env, table_id: u64) -> Result<Table, Error> {
+    let table = load_table(env, table_id)?;
+    match load_table_status(env, table_id) {
+        TableStatus::Open => Ok(table),
+        TableStatus::Paused => Err(Error::TablePaused),
+        TableStatus::Retired => Err(Error::TableRetired),
+    }
+}
+
+fn load_table_status(env: &Env, table_id: u64) -> TableStatus {
+    load_table_entry(env, &DataKey::TableStatus(table_id)).unwrap_or(TableStatus::Open)
+}
+
+fn load_table_meta(env: &Env, table_id: u64) -> TableMeta {
+    load_table_entry(env, &DataKey::TableMeta(table_id)).unwrap_or_else(|| TableMeta {
+            name: String::from_str(env, ""),
+            variant: symbol_short!("NLHE"),
+            currency: symbol_short!(""),
+        })
+}
+
+fn validate_table(table: &Table) -> Result<(), Error> {
+    if table.small_blind <= 0 || table.small_blind > table.big_blind {
+        return Err(Error::InvalidBlinds);
+    }
+    if table.min_buy_in <= 0 || table.min_buy_in > table.max_buy_in {
+        return Err(Error::InvalidBuyInRange);
+    }
+    if table.max_seats < 2 {
+        return Err(Error::InvalidSeats);
+    }
+    Ok(())
+}
+
+fn save_table(env: &Env, table_id: u64, table: &Table) {
+    let key = DataKey::Table(table_id);
+    save_table_entry(env, &key, table);
+    if env.storage().instance().has(&key) {
+        env.storage().instance().remove(&key);
+    }
+}
+
+fn load_table_format(env: &Env, table_id: u64) -> MatchFormat {
+    load_table_entry(env, &DataKey::TableFormat(table_id)).unwrap_or(MatchFormat::Cash)
+}
+
+/// Timeouts in force at a table: its override, or the global GameConfig values.
+fn table_timeouts(env: &Env, table_id: u64, config: &GameConfig) -> TimeoutConfig {
+    load_table_entry(env, &DataKey::TableTimeouts(table_id)).unwrap_or(TimeoutConfig {
+        seed_commit: config.reveal_timeout,
+        seed_reveal: config.reveal_timeout,
+        betting: config.bet_timeout,
+        showdown: config.bet_timeout,
+        proof_submission: config.bet_timeout,
+        waiting: config.waiting_timeout,
+    })
+}
+
+/// Players waiting at a table, oldest first. A table still holding the single session written
+/// by earlier versions (TableWaiting, in instance storage) reads as a queue of one.
+fn load_table_queue(env: &Env, table_id: u64) -> Vec<WaitingSession> {
+    if let Some(queue) = load_table_entry(env, &DataKey::TableQueue(table_id)) {
+        return queue;
+    }
+    let legacy = DataKey::TableWaiting(table_id);
+    match env.storage().instance().get::<_, WaitingSession>(&legacy) {
+        Some(session) => vec![env, session],
+        None => Vec::new(env),
+    }
+}
+
+fn save_table_queue(env: &Env, table_id: u64, queue: &Vec<WaitingSession>) {
+    let legacy = DataKey::TableWaiting(table_id);
+    if env.storage().instance().has(&legacy) {
+        env.storage().instance().remove(&legacy);
+    }
+    set_table_entry(env, &DataKey::TableQueue(table_id), (!queue.is_empty()).then_some(queue));
+}
+
+/// Oldest player waiting at a table.
+fn load_table_waiting(env: &Env, table_id: u64) -> Option<WaitingSession> {
+    load_table_queue(env, table_id).first()
+}
+
+fn load_player_waiting(env: &Env, player: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PlayerWaiting(player.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Add or remove a table from the list of tables where a player waits.
+fn set_player_waiting(env: &Env, player: &Address, table_id: u64, waiting: bool) {
+    let mut tables = load_player_waiting(env, player);
+    if let Some(at) = tables.first_index_of(table_id) {
+        tables.remove(at);
+    }
+    if waiting {
+        tables.push_back(table_id);
+    } else {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::SeatAccess(table_id, player.clone()));
+    }
+    let key = DataKey::PlayerWaiting(player.clone());
+    if tables.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &tables);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+}
+
+fn load_table_access(env: &Env, table_id: u64) -> TableAccess {
+    load_table_entry(env, &DataKey::TableAccess(table_id)).unwrap_or(TableAccess::Public)
+}
+
+/// A waiting seat's access lives as long as the seat: reading it keeps it alive.
+fn load_seat_access(env: &Env, table_id: u64, player: &Address) -> TableAccess {
+    let key = DataKey::SeatAccess(table_id, player.clone());
+    let storage = env.storage().persistent();
+    let Some(access) = storage.get(&key) else {
+        return TableAccess::Public;
+    };
+    storage.extend_ttl(&key, TTL_THRESHOLD, GAME_TTL_LEDGERS);
+    access
+}
+
+fn save_seat_access(env: &Env, table_id: u64, player: &Address, access: &TableAccess) {
+    let key = DataKey::SeatAccess(table_id, player.clone());
+    env.storage().persistent().set(&key, access);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Allowlists are stored whole and read on every sit, so their length is capped.
+fn check_access_size(access: &TableAccess) -> Result<(), Error> {
+    match access {
+        TableAccess::Allowlist(allowed) if allowed.len() > MAX_ALLOWLIST => {
+            Err(Error::AllowlistTooLong)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Whether `player` (revealing `secret`, if any) passes `access`.
+fn admits(env: &Env, access: &TableAccess, player: &Address, secret: &Option<Bytes>) -> bool {
+    match access {
+        TableAccess::Public => true,
+        TableAccess::Allowlist(allowed) => allowed.contains(player),
+        TableAccess::Password(hash) => secret
+            .as_ref()
+            .is_some_and(|secret| env.crypto().sha256(secret).to_bytes() == *hash),
+    }
+}
+
+fn check_table_access(
+    env: &Env,
+    table_id: u64,
+    player: &Address,
+    secret: &Option<Bytes>,
+) -> Result<(), Error> {
+    if admits(env, &load_table_access(env, table_id), player, secret) {
+        Ok(())
+    } else {
+        Err(Error::NotInvited)
+    }
+}
+
+fn load_table_tolerance(env: &Env, table_id: u64) -> u32 {
+    load_table_entry(env, &DataKey::TableTolerance(table_id)).unwrap_or(0)
+}
+
+/// Mark an invite nonce used or revoked, kept at least until the invite expires.
+fn set_invite_status(env: &Env, invite: &Invite, status: InviteStatus) {
+    let key = DataKey::InviteNonce(invite.player1.clone(), invite.nonce);
+    let ttl = invite
+        .expires_ledger
+        .saturating_sub(env.ledger().sequence())
+        .clamp(1, env.storage().max_ttl());
+    env.storage().persistent().set(&key, &status);
+    env.storage().persistent().extend_ttl(&key, ttl, ttl);
+}
+
+fn check_invite_unused(env: &Env, player1: &Address, nonce: u64) -> Result<(), Error> {
+    let key = DataKey::InviteNonce(player1.clone(), nonce);
+    match env.storage().persistent().get(&key) {
+        Some(InviteStatus::Used) => Err(Error::InviteUsed),
+        Some(InviteStatus::Revoked) => Err(Error::InviteRevoked),
+        None => Ok(()),
+    }
+}
+
+/// Validate and start a two-player session as game SESSION_ID_BASE + session_id; both players
+/// have already authorized it. Returns the game id.
+fn start_session(
+    env: &Env,
+    session_id: u64,
+    table_id: u64,
+    player1: Address,
+    player2: Address,
+    buy_in: i128,
+) -> Result<u64, Error> {
+    require_not_paused(env)?;
+    if player1 == player2 {
+        return Err(Error::CannotPlaySelf);
+    }
+    let config = load_config(env)?;
+    let table = load_open_table(env, table_id)?;
+    if buy_in < table.min_buy_in {
+        return Err(Error::BuyInTooLow);
+    }
+    if buy_in > table.max_buy_in {
+        return Err(Error::BuyInTooHigh);
+    }
+    let game_id = session_game_id(session_id)?;
+    // A closed session keeps its id: starting it again would overwrite its archived result.
+    if game_exists(env, game_id) || env.storage().persistent().has(&DataKey::Archive(game_id)) {
+        return Err(Error::GameFull);
+    }
+    // Both players signed, so a password adds nothing here; allowlists still apply.
+    let access = load_table_access(env, table_id);
+    if let TableAccess::Allowlist(_) = access {
+        if !admits(env, &access, &player1, &None) || !admits(env, &access, &player2, &None) {
+            return Err(Error::NotInvited);
+        }
+    }
+
+    let game = new_game(
+        env,
+        game_id,
+        table_id,
+        &table,
+        player1.clone(),
+        Some(player2.clone()),
+        buy_in,
+    );
+    save_game(env, game_id, &game);
+    if let Some(ref pc) = config.payment_controller {
+        lock_funds(env, pc, &player1, buy_in);
+        lock_funds(env, pc, &player2, buy_in);
+    }
+    game_hub(env)?.start_game(
+        &env.current_contract_address(),
+        &hub_session_id(game_id),
+        &player1,
+        &player2,
+        &buy_in,
+        &buy_in,
+    );
+    publish(env, (symbol_short!("CREATE"), game_id), player1.clone());
+    publish(env, (symbol_short!("JOIN"), game_id), player2.clone());
+    publish(env, (symbol_short!("TBL_START"), table_id, game_id), player2);
+    Ok(game_id)
+}
+
+/// Waiting player to pair a newcomer with: buy-ins may differ by at most `tolerance_bps` of
+/// the larger one, and private seats must admit the newcomer. The closest buy-in wins, oldest
+/// first on ties.
+fn best_match(
+    env: &Env,
+    table_id: u64,
+    queue: &Vec<WaitingSession>,
+    newcomer: &Address,
+    secret: &Option<Bytes>,
+    buy_in: i128,
+) -> Option<u32> {
+    let tolerance_bps = load_table_tolerance(env, table_id);
+    let mut best: Option<(u32, i128)> = None;
+    for (at, session) in queue.iter().enumerate() {
+        let distance = (session.buy_in - buy_in).abs();
+        let allowed = session.buy_in.max(buy_in).saturating_mul(tolerance_bps as i128) / 10_000;
+        if distance > allowed {
+            continue;
+        }
+        let seat_access = load_seat_access(env, table_id, &session.player1);
+        if !admits(env, &seat_access, newcomer, secret) {
+            continue;
+        }
+        if best.is_none_or(|(_, d)| distance < d) {
+            best = Some((at as u32, distance));
+        }
+    }
+    best.map(|(at, _)| at)
+}
+
+fn load_tournament(env: &Env, tournament_id: u64) -> Result<Tournament, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Tournament(tournament_id))
+        .ok_or(Error::TournamentNotFound)
+}
+
+fn save_tournament(env: &Env, tournament: &Tournament) {
+    let key = DataKey::Tournament(tournament.id);
+    env.storage().persistent().set(&key, tournament);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+fn load_tournament_game(env: &Env, game_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TournamentGame(game_id))
+}
+
+fn is_player(game: &Game, player: &Address) -> bool {
+    *player == game.player1 || game.player2.as_ref() == Some(player)
+}
+
+/// The second seat's player, for paths that only run once the game has one.
+fn opponent(game: &Game) -> Result<Address, Error> {
+    game.player2.clone().ok_or(Error::NoOpponent)
+}
+
+fn is_current_actor(game: &Game, player: &Address) -> Result<bool, Error> {
+    if game.actor == 0u32 {
+        Ok(*player == game.player1)
+    } else {
+        Ok(*player == opponent(game)?)
+    }
+}
+
+/// Fresh game at `table` stakes. Each seated player starts with `buy_in` chips behind;
+/// with both seats filled the game goes straight to the shuffle.
+fn new_game(
+    env: &Env,
+    game_id: u64,
+    table_id: u64,
+    table: &Table,
+    player1: Address,
+    player2: Option<Address>,
+    buy_in: i128,
+) -> Game {
+    let now = env.ledger().timestamp();
+    let timebank = load_config(env).map(|c| c.timebank).unwrap_or(0);
+    let (state, stack2) = if player2.is_some() {
+        (GameState::ShuffleCommit, buy_in)
+    } else {
+        (GameState::WaitingForPlayers, 0)
+    };
+    Game {
+        id: game_id,
+        state,
+        player1,
+        player2,
+        buy_in,
+        pot: 0,
+        stack1: buy_in,
+        stack2,
+        bought_in1: buy_in,
+        bought_in2: stack2,
+        rake: 0,
+        format: load_table_format(env, table_id),
+        hands_won1: 0,
+        hands_won2: 0,
+        timebank1: timebank,
+        timebank2: timebank,
+        bounty_paid: 0,
+        small_blind: table.small_blind,
+        big_blind: table.big_blind,
+        dealer_position: 0,
+        board: Vec::new(env),
+        board_revealed: 0,
+        current_bet_p1: 0,
+        current_bet_p2: 0,
+        total_bet_p1: 0,
+        total_bet_p2: 0,
+        min_raise: table.big_blind,
+        last_raise_amount: table.big_blind,
+        actor: 0,
+        folded: None,
+        seed_commitment1: None,
+        seed_commitment2: None,
+        seed_reveal1: None,
+        seed_reveal2: None,
+        final_seed: None,
+        hand_commitment1: None,
+        hand_commitment2: None,
+        hand_rank1: None,
+        hand_rank2: None,
+        winner: None,
+        created_at: now,
+        last_action_at: now,
+        table_id,
+    }
+}
+
+/// Chip sums and differences: an overflow is an error instead of a wrap or a trap.
+fn chips_add(a: i128, b: i128) -> Result<i128, Error> {
+    a.checked_add(b).ok_or(Error::ChipOverflow)
+}
+
+fn chips_sub(a: i128, b: i128) -> Result<i128, Error> {
+    a.checked_sub(b).ok_or(Error::ChipOverflow)
+}
+
+/// `amount * bps / 10_000` rounded down, for rake and prize shares, capped at `amount`
+/// (rates above 10_000 count as 10_000). Worked out per 10_000 chips, so it cannot overflow
+/// however large the pot grows with rebuys.
+fn chips_bps(amount: i128, bps: u32) -> i128 {
+    let bps = bps.min(10_000) as i128;
+    amount / 10_000 * bps + amount % 10_000 * bps / 10_000
+}
+
+/// Move `amount` out of a chip count that must not go negative.
+fn take_chips(from: &mut i128, amount: i128) -> Result<(), Error> {
+    if amount < 0 || amount > *from {
+        return Err(Error::ChipOverflow);
+    }
+    *from -= amount;
+    Ok(())
+}
+
+/// Stacks, pot, rake and the keeper bounties paid out of them add up to what both players
+/// bought in for, until the session is paid out and they are zeroed.
+fn chips_conserved(game: &Game) -> bool {
+    let held = [game.stack1, game.stack2, game.pot, game.rake, game.bounty_paid];
+    if held.iter().any(|c| *c < 0) {
+        return false;
+    }
+    if is_over(game.state) && held[..4].iter().all(|c| *c == 0) {
+        return true;
+    }
+    let in_play = held.iter().try_fold(0i128, |sum, c| sum.checked_add(*c));
+    in_play.is_some() && in_play == game.bought_in1.checked_add(game.bought_in2)
+}
+
+fn stack_of(game: &Game, is_player1: bool) -> i128 {
+    if is_player1 {
+        game.stack1
+    } else {
+        game.stack2
+    }
+}
+
+/// Move `amount` from a player's stack into the pot as part of their current bet.
+fn put_chips(game: &mut Game, is_player1: bool, amount: i128) -> Result<(), Error> {
+    let (stack, current_bet, total_bet) = if is_player1 {
+        (&mut game.stack1, &mut game.current_bet_p1, &mut game.total_bet_p1)
+    } else {
+        (&mut game.stack2, &mut game.current_bet_p2, &mut game.total_bet_p2)
+    };
+    take_chips(stack, amount)?;
+    *current_bet = chips_add(*current_bet, amount)?;
+    *total_bet = chips_add(*total_bet, amount)?;
+    game.pot = chips_add(game.pot, amount)?;
+    Ok(())
+}
+
+/// Give back the part of a bet the opponent could not cover (all-in for less).
+fn return_uncalled(game: &mut Game, is_player1: bool, amount: i128) -> Result<(), Error> {
+    let (stack, current_bet, total_bet) = if is_player1 {
+        (&mut game.stack1, &mut game.current_bet_p1, &mut game.total_bet_p1)
+    } else {
+        (&mut game.stack2, &mut game.current_bet_p2, &mut game.total_bet_p2)
+    };
+    take_chips(current_bet, amount)?;
+    take_chips(total_bet, amount)?;
+    take_chips(&mut game.pot, amount)?;
+    *stack = chips_add(*stack, amount)?;
+    Ok(())
+}
+
+/// Bets are matched: move to the next street. With a player all-in there is nothing left to
+/// bet, so the board runs out and the hand goes to showdown.
+fn close_betting_round(game: &mut Game) {
+    game.current_bet_p1 = 0;
+    game.current_bet_p2 = 0;
+    game.min_raise = game.big_blind;
+    game.last_raise_amount = game.big_blind;
+    game.state = advance_betting_round(game);
+    if game.state != GameState::Showdown && (game.stack1 == 0 || game.stack2 == 0) {
+        game.board_revealed = 5;
+        game.actor = 0u32;
+        game.state = GameState::Showdown;
+    }
+}
+
+fn advance_betting_round(game: &mut Game) -> GameState {
+    match game.state {
+        GameState::PreFlop => {
+            game.board_revealed = 3;
+            game.actor = game.dealer_position;
             GameState::FlopBetting
         }
         GameState::FlopBetting => {
@@ -314,20 +2104,57 @@ fn advance_betting_round(game: &mut Game) -> GameState {
     }
 }
 
+fn is_betting_round(state: GameState) -> bool {
+    matches!(
+        state,
+        GameState::PreFlop | GameState::FlopBetting | GameState::TurnBetting | GameState::RiverBetting
+    )
+}
+
+/// Base response time for the current phase, from the game's table settings.
+fn phase_timeout(env: &Env, game: &Game, config: &GameConfig) -> Result<u64, Error> {
+    let timeouts = table_timeouts(env, game.table_id, config);
+    match game.state {
+        GameState::ShuffleCommit => Ok(timeouts.seed_commit),
+        GameState::ShuffleReveal => Ok(timeouts.seed_reveal),
+        GameState::PreFlop
+        | GameState::FlopBetting
+        | GameState::TurnBetting
+        | GameState::RiverBetting => Ok(timeouts.betting),
+        GameState::Showdown if game.hand_rank1.is_some() || game.hand_rank2.is_some() => {
+            Ok(timeouts.proof_submission)
+        }
+        GameState::Showdown => Ok(timeouts.showdown),
+        _ => Err(Error::NoTimeoutApplicable),
+    }
+}
+
+/// Timebank the player to act can draw on (betting decisions only).
+fn actor_timebank(game: &Game) -> u64 {
+    if !is_betting_round(game.state) {
+        return 0;
+    }
+    if game.actor == 0u32 {
+        game.timebank1
+    } else {
+        game.timebank2
+    }
+}
+
 fn determine_non_acting_player(game: &Game) -> Result<Address, Error> {
     match game.state {
         GameState::ShuffleCommit => {
             if game.seed_commitment1.is_none() {
                 Ok(game.player1.clone())
             } else {
-                Ok(game.player2.clone().unwrap())
+                opponent(game)
             }
         }
         GameState::ShuffleReveal => {
             if game.seed_reveal1.is_none() {
                 Ok(game.player1.clone())
             } else {
-                Ok(game.player2.clone().unwrap())
+                opponent(game)
             }
         }
         GameState::PreFlop
@@ -337,14 +2164,14 @@ fn determine_non_acting_player(game: &Game) -> Result<Address, Error> {
             if game.actor == 0u32 {
                 Ok(game.player1.clone())
             } else {
-                Ok(game.player2.clone().unwrap())
+                opponent(game)
             }
         }
         GameState::Showdown => {
             if game.hand_rank1.is_none() {
                 Ok(game.player1.clone())
             } else {
-                Ok(game.player2.clone().unwrap())
+                opponent(game)
             }
         }
         _ => Err(Error::InvalidState),
@@ -354,8 +2181,8 @@ fn determine_non_acting_player(game: &Game) -> Result<Address, Error> {
 /// Derive 5 board cards from seed (Fisher-Yates shuffle with LCG).
 fn derive_board_cards(env: &Env, seed: &BytesN<32>) -> Vec<u32> {
     let mut deck: [u32; 52] = [0; 52];
-    for i in 0..52u32 {
-        deck[i as usize] = i + 1;
+    for (i, card) in deck.iter_mut().enumerate() {
+        *card = i as u32 + 1;
     }
     let seed_bytes = seed.to_array();
     let mut rng_state =
@@ -365,13 +2192,11 @@ fn derive_board_cards(env: &Env, seed: &BytesN<32>) -> Vec<u32> {
             .wrapping_mul(6364136223846793005)
             .wrapping_add(1);
         let j = (rng_state % (i as u64 + 1)) as usize;
-        let tmp = deck[i];
-        deck[i] = deck[j];
-        deck[j] = tmp;
+        deck.swap(i, j);
     }
     let mut cards = Vec::new(env);
-    for i in 0..5 {
-        cards.push_back(deck[i]);
+    for card in deck.iter().take(5) {
+        cards.push_back(*card);
     }
     cards
 }
@@ -381,49 +2206,582 @@ fn board_hash(env: &Env, board: &Vec<u32>) -> BytesN<32> {
     for i in 0..5u32 {
         let c = board.get(i).unwrap_or(0);
         let start = (i as usize) * 4;
-        buf[start..start + 4].copy_from_slice(&(c as u32).to_le_bytes());
+        buf[start..start + 4].copy_from_slice(&c.to_le_bytes());
     }
     env.crypto().sha256(&Bytes::from_slice(env, &buf)).into()
 }
 
-/// Execute game payout: call PaymentController when configured, else no-op.
-fn payout_game(env: Env, game: &Game) -> Result<(), Error> {
-    let config = load_config(&env)?;
-    let Some(ref pc) = config.payment_controller else {
-        return Ok(());
-    };
-    let winner = game.winner.as_ref().ok_or(Error::InvalidState)?;
-    let rake = (game.pot as u64)
-        .saturating_mul(config.rake_percentage as u64)
-        .saturating_div(10_000) as i128;
-    let payout = game.pot.saturating_sub(rake);
+/// Lock a player's funds in the PaymentController escrow (player auth flows through).
+fn lock_funds(env: &Env, pc: &Address, player: &Address, amount: i128) {
+    env.invoke_contract::<()>(
+        pc,
+        &Symbol::new(env, "lock_funds"),
+        vec![env, player.into_val(env), amount.into_val(env)],
+    );
+}
+
+/// Call a game-manager-only PaymentController function, authorizing as this contract.
+fn invoke_escrow(env: &Env, pc: &Address, fn_name: &str, args: Vec<soroban_sdk::Val>) {
     let sub = SubContractInvocation {
         context: ContractContext {
             contract: pc.clone(),
-            fn_name: Symbol::new(&env, "payout_winner"),
-            args: vec![&env, winner.into_val(&env), payout.into_val(&env), rake.into_val(&env)],
+            fn_name: Symbol::new(env, fn_name),
+            args: args.clone(),
         },
-        sub_invocations: Vec::new(&env),
+        sub_invocations: Vec::new(env),
     };
-    env.authorize_as_current_contract(vec![&env, InvokerContractAuthEntry::Contract(sub)]);
-    env.invoke_contract::<()>(
-        pc,
-        &Symbol::new(&env, "payout_winner"),
-        vec![&env, winner.into_val(&env), payout.into_val(&env), rake.into_val(&env)],
+    env.authorize_as_current_contract(vec![env, InvokerContractAuthEntry::Contract(sub)]);
+    env.invoke_contract::<()>(pc, &Symbol::new(env, fn_name), args);
+}
+
+fn payout_winner(env: &Env, pc: &Address, winner: &Address, payout: i128, rake: i128) {
+    let args = vec![env, winner.into_val(env), payout.into_val(env), rake.into_val(env)];
+    invoke_escrow(env, pc, "payout_winner", args);
+}
+
+fn refund_player(env: &Env, pc: &Address, player: &Address, amount: i128) {
+    let args = vec![env, player.into_val(env), amount.into_val(env)];
+    invoke_escrow(env, pc, "refund_on_timeout", args);
+}
+
+fn game_hub(env: &Env) -> Result<GameHubClient<'_>, Error> {
+    Ok(GameHubClient::new(env, &load_hub(env)?))
+}
+
+/// End a cash session: pay each player's remaining stack (and the session rake to the
+/// treasury) out of escrow, then tell the hub whether player1 won.
+fn end_session(env: &Env, game: &mut Game) -> Result<(), Error> {
+    let config = load_config(env)?;
+    if let Some(ref pc) = config.payment_controller {
+        if game.stack1 > 0 || game.rake > 0 {
+            payout_winner(env, pc, &game.player1, game.stack1, game.rake);
+        }
+        if let Some(ref player2) = game.player2 {
+            if game.stack2 > 0 {
+                payout_winner(env, pc, player2, game.stack2, 0);
+            }
+        }
+    }
+    game.stack1 = 0;
+    game.stack2 = 0;
+    game.rake = 0;
+    if game.player2.is_some() {
+        let player1_won = game.winner.as_ref() == Some(&game.player1);
+        game_hub(env)?.end_game(&hub_session_id(game.id), &player1_won);
+    }
+    Ok(())
+}
+
+/// Cancel a seated cash game: bets in the current hand go back to their owners and each stake
+/// is refunded (refund_stakes). Returns whether player1 leaves with more chips, for the hub.
+fn cancel_with_refunds(env: &Env, game: &mut Game) -> Result<bool, Error> {
+    game.stack1 = chips_add(game.stack1, game.total_bet_p1)?;
+    game.stack2 = chips_add(game.stack2, game.total_bet_p2)?;
+    game.pot = 0;
+    game.current_bet_p1 = 0;
+    game.current_bet_p2 = 0;
+    game.total_bet_p1 = 0;
+    game.total_bet_p2 = 0;
+    let player1_won = chips_sub(game.stack1, game.bought_in1)?
+        > chips_sub(game.stack2, game.bought_in2)?;
+    refund_stakes(env, game)?;
+    game.winner = None;
+    game.state = GameState::Cancelled;
+    game.last_action_at = env.ledger().timestamp();
+    Ok(player1_won)
+}
+
+/// Return each player's stake with refund_on_timeout; chips won beyond it (and the session
+/// rake) are paid out on top. Stacks are zeroed.
+fn refund_stakes(env: &Env, game: &mut Game) -> Result<(), Error> {
+    let config = load_config(env)?;
+    let player2 = game.player2.clone().ok_or(Error::InvalidState)?;
+    if let Some(ref pc) = config.payment_controller {
+        let seats = [
+            (&game.player1, game.stack1, game.bought_in1, game.rake),
+            (&player2, game.stack2, game.bought_in2, 0),
+        ];
+        for (player, stack, bought_in, rake) in seats {
+            let refund = stack.min(bought_in);
+            if refund > 0 {
+                refund_player(env, pc, player, refund);
+            }
+            if stack > refund || rake > 0 {
+                payout_winner(env, pc, player, stack - refund, rake);
+            }
+        }
+    }
+    game.stack1 = 0;
+    game.stack2 = 0;
+    game.rake = 0;
+    Ok(())
+}
+
+/// Pay the keeper who advanced a timeout. Cash games only, and never to one of the game's own
+/// addresses. That check is best effort: a player calling from a second account is paid like
+/// any keeper, so the cap is what bounds such self-dealing. The bounty is bounded by the
+/// configured amount, what is left of the game's cap and what the source holds (the timed-out
+/// player's stack or the game's rake).
+fn pay_keeper_bounty(
+    env: &Env,
+    game: &mut Game,
+    caller: &Address,
+    timed_out: &Address,
+) -> Result<(), Error> {
+    let Some(bounty) = env
+        .storage()
+        .instance()
+        .get::<_, KeeperBounty>(&DataKey::KeeperBounty)
+    else {
+        return Ok(());
+    };
+    let Some(pc) = load_config(env).ok().and_then(|c| c.payment_controller) else {
+        return Ok(());
+    };
+    if is_player(game, caller) || load_tournament_game(env, game.id).is_some() {
+        return Ok(());
+    }
+    let timed_out_is_p1 = *timed_out == game.player1;
+    let available = match bounty.source {
+        BountySource::Forfeit => stack_of(game, timed_out_is_p1),
+        BountySource::Treasury => game.rake,
+    };
+    let amount = bounty
+        .amount
+        .min(chips_sub(bounty.cap, game.bounty_paid)?)
+        .min(available);
+    if amount <= 0 {
+        return Ok(());
+    }
+    match bounty.source {
+        BountySource::Forfeit if timed_out_is_p1 => take_chips(&mut game.stack1, amount)?,
+        BountySource::Forfeit => take_chips(&mut game.stack2, amount)?,
+        BountySource::Treasury => take_chips(&mut game.rake, amount)?,
+    }
+    game.bounty_paid = chips_add(game.bounty_paid, amount)?;
+    payout_winner(env, &pc, caller, amount, 0);
+    publish(env, (symbol_short!("BOUNTY"), game.id), (caller.clone(), amount));
+    Ok(())
+}
+
+/// Hands settled so far in a session; mutual_cancel signatures are bound to it.
+fn hands_played(game: &Game) -> u32 {
+    game.hands_won1 + game.hands_won2
+}
+
+/// A cash game was abandoned during the shuffle: apply the abandonment policy, then return
+/// each player's stake with refund_on_timeout (chips won in earlier hands are paid out on top),
+/// cancel the game and close the hub session.
+fn abandon_game(env: &Env, mut game: Game, abandoner: &Address) -> Result<(), Error> {
+    let config = load_config(env)?;
+    let abandoner_is_p1 = *abandoner == game.player1;
+    let penalty = match config.abandon_policy {
+        AbandonPolicy::Refund => 0,
+        AbandonPolicy::Penalty(amount) => amount.clamp(0, stack_of(&game, abandoner_is_p1)),
+    };
+    if abandoner_is_p1 {
+        take_chips(&mut game.stack1, penalty)?;
+        game.stack2 = chips_add(game.stack2, penalty)?;
+    } else {
+        take_chips(&mut game.stack2, penalty)?;
+        game.stack1 = chips_add(game.stack1, penalty)?;
+    }
+    refund_stakes(env, &mut game)?;
+    game.winner = None;
+    game.state = GameState::Cancelled;
+    game.last_action_at = env.ledger().timestamp();
+    save_game(env, game.id, &game);
+    // The hub only records wins; the player who kept responding is reported.
+    game_hub(env)?.end_game(&hub_session_id(game.id), &!abandoner_is_p1);
+    publish(env, (symbol_short!("CANCEL"), game.id), (abandoner.clone(), penalty));
+    Ok(())
+}
+
+/// Settle a decided hand with award_hand. A cash hand whose chip counts overflow cannot be
+/// settled, so the game is cancelled instead: bets go back and both stakes are refunded, and
+/// the players are not stuck in a hand nobody can finish.
+fn settle_hand(env: &Env, game_id: u64, game: Game) -> Result<(), Error> {
+    let unsettled = game.clone();
+    match award_hand(env, game_id, game) {
+        // award_hand does all its chip arithmetic before paying anything out.
+        Err(Error::ChipOverflow) if load_tournament_game(env, game_id).is_none() => {
+            void_game(env, unsettled)
+        }
+        result => result,
+    }
+}
+
+/// Cancel a cash game that cannot be settled, with refunds, and close its hub session.
+fn void_game(env: &Env, mut game: Game) -> Result<(), Error> {
+    let player1_won = cancel_with_refunds(env, &mut game)?;
+    save_game(env, game.id, &game);
+    game_hub(env)?.end_game(&hub_session_id(game.id), &player1_won);
+    publish(env, (symbol_short!("VOID"), game.id), hands_played(&game));
+    Ok(())
+}
+
+/// Settle a decided hand (winner set, state Finished, or Cancelled for an abandoned tournament
+/// table): the winner takes the pot, less rake in cash sessions. Tournament tables report to
+/// their tournament; a cash session deals the next hand unless its match is over.
+fn award_hand(env: &Env, game_id: u64, mut game: Game) -> Result<(), Error> {
+    let winner = game.winner.clone().ok_or(Error::InvalidState)?;
+    let winner_is_p1 = winner == game.player1;
+    let tournament_id = load_tournament_game(env, game_id);
+    let rake = if tournament_id.is_some() {
+        0
+    } else {
+        chips_bps(game.pot, load_config(env)?.rake_percentage)
+    };
+    game.rake = chips_add(game.rake, rake)?;
+    let mut won = chips_sub(game.pot, rake)?;
+    if game.state == GameState::Cancelled {
+        // Tournament chips cannot be refunded: abandoning a table forfeits the stack.
+        let forfeited = if winner_is_p1 {
+            core::mem::take(&mut game.stack2)
+        } else {
+            core::mem::take(&mut game.stack1)
+        };
+        won = chips_add(won, forfeited)?;
+    }
+    if winner_is_p1 {
+        game.stack1 = chips_add(game.stack1, won)?;
+    } else {
+        game.stack2 = chips_add(game.stack2, won)?;
+    }
+    game.pot = 0;
+
+    if let Some(tournament_id) = tournament_id {
+        return settle_tournament_hand(env, tournament_id, game);
+    }
+    let hands_won = if winner_is_p1 {
+        &mut game.hands_won1
+    } else {
+        &mut game.hands_won2
+    };
+    *hands_won = hands_won.checked_add(1).ok_or(Error::InvalidState)?;
+    if let Some(match_winner) = match_winner(&game) {
+        award_match(&mut game, &match_winner)?;
+        end_session(env, &mut game)?;
+        save_game(env, game_id, &game);
+        publish(env, (symbol_short!("MATCH_END"), game_id), match_winner);
+        return Ok(());
+    }
+    if game.state == GameState::Finished && game.player2.is_some() {
+        reset_game_for_next_hand(env, &mut game)?;
+        save_game(env, game_id, &game);
+        return Ok(());
+    }
+    end_session(env, &mut game)?;
+    save_game(env, game_id, &game);
+    Ok(())
+}
+
+/// Winner of a freezeout or best-of-N match, once it is decided.
+fn match_winner(game: &Game) -> Option<Address> {
+    let needed = match game.format {
+        MatchFormat::Cash => return None,
+        MatchFormat::Freezeout => u32::MAX,
+        MatchFormat::BestOf(hands) => hands / 2 + 1,
+    };
+    if game.stack2 == 0 || game.hands_won1 >= needed {
+        Some(game.player1.clone())
+    } else if game.stack1 == 0 || game.hands_won2 >= needed {
+        game.player2.clone()
+    } else {
+        None
+    }
+}
+
+/// The match winner takes the loser's remaining chips; the session is over (an abandoned
+/// game stays Cancelled).
+fn award_match(game: &mut Game, winner: &Address) -> Result<(), Error> {
+    if *winner == game.player1 {
+        game.stack1 = chips_add(game.stack1, core::mem::take(&mut game.stack2))?;
+    } else {
+        game.stack2 = chips_add(game.stack2, core::mem::take(&mut game.stack1))?;
+    }
+    game.winner = Some(winner.clone());
+    if game.state != GameState::Cancelled {
+        game.state = GameState::Finished;
+    }
+    Ok(())
+}
+
+/// Cash games are settled between hands: no bets in the middle and no seed committed yet.
+fn is_between_hands(game: &Game) -> bool {
+    game.state == GameState::ShuffleCommit
+        && game.seed_commitment1.is_none()
+        && game.seed_commitment2.is_none()
+}
+
+/// Add chips to a player's stack between hands (rebuy requires a busted stack). The new
+/// stack must stay within the table's buy-in limits.
+fn add_chips(
+    env: &Env,
+    player: &Address,
+    game_id: u64,
+    amount: i128,
+    rebuy: bool,
+) -> Result<(), Error> {
+    require_not_paused(env)?;
+    let mut game = load_game(env, game_id)?;
+    if !is_player(&game, player) {
+        return Err(Error::NotPlayer);
+    }
+    if load_tournament_game(env, game_id).is_some() {
+        return Err(Error::InvalidState);
+    }
+    if !is_between_hands(&game) {
+        return Err(Error::HandInProgress);
+    }
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    let is_p1 = *player == game.player1;
+    let stack = stack_of(&game, is_p1);
+    if game.format != MatchFormat::Cash {
+        return Err(Error::RebuyNotAllowed);
+    }
+    if rebuy && stack > 0 {
+        return Err(Error::StillHasChips);
+    }
+    let table = load_table(env, game.table_id)?;
+    let new_stack = chips_add(stack, amount)?;
+    if new_stack < table.min_buy_in {
+        return Err(Error::BuyInTooLow);
+    }
+    if new_stack > table.max_buy_in {
+        return Err(Error::BuyInTooHigh);
+    }
+    if let Some(ref pc) = load_config(env)?.payment_controller {
+        lock_funds(env, pc, player, amount);
+    }
+    if is_p1 {
+        game.stack1 = new_stack;
+        game.bought_in1 = chips_add(game.bought_in1, amount)?;
+    } else {
+        game.stack2 = new_stack;
+        game.bought_in2 = chips_add(game.bought_in2, amount)?;
+    }
+    save_session(env, game_id, &game);
+    save_game(env, game_id, &game);
+    Ok(())
+}
+
+/// Blind level in force at `now` (the last level repeats once the schedule runs out).
+fn tournament_level(tournament: &Tournament, now: u64) -> Result<BlindLevel, Error> {
+    let elapsed = now.saturating_sub(tournament.started_at);
+    let level = elapsed.checked_div(tournament.config.level_duration).unwrap_or(0);
+    let last = tournament.config.blind_levels.len().saturating_sub(1);
+    let idx = if level > last as u64 { last } else { level as u32 };
+    tournament
+        .config
+        .blind_levels
+        .get(idx)
+        .ok_or(Error::InvalidTournamentConfig)
+}
+
+/// Start a heads-up game between the two players seated at `table_idx`. Chips stay with
+/// the tournament (escrowed entry fees), so nothing is locked here.
+fn start_tournament_table(
+    env: &Env,
+    tournament: &mut Tournament,
+    table_idx: u32,
+) -> Result<(), Error> {
+    let mut table = tournament.tables.get(table_idx).ok_or(Error::InvalidState)?;
+    let player1 = table.players.get(0).ok_or(Error::InvalidState)?;
+    let player2 = table.players.get(1).ok_or(Error::NoOpponent)?;
+    let chips1 = tournament.chips.get(player1.clone()).unwrap_or(0);
+    let chips2 = tournament.chips.get(player2.clone()).unwrap_or(0);
+    let now = env.ledger().timestamp();
+    let level = tournament_level(tournament, now)?;
+    let game_id = next_game_id(env)?;
+    let stakes = Table {
+        small_blind: level.small_blind,
+        big_blind: level.big_blind,
+        ..load_table(env, tournament.config.table_id)?
+    };
+    let mut game = new_game(
+        env,
+        game_id,
+        tournament.config.table_id,
+        &stakes,
+        player1.clone(),
+        Some(player2.clone()),
+        if chips1 < chips2 { chips1 } else { chips2 },
+    );
+    game.format = MatchFormat::Freezeout;
+    game.stack1 = chips1;
+    game.stack2 = chips2;
+    game.bought_in1 = chips1;
+    game.bought_in2 = chips2;
+    save_game(env, game_id, &game);
+    let key = DataKey::TournamentGame(game_id);
+    env.storage().persistent().set(&key, &tournament.id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    table.game_id = Some(game_id);
+    tournament.tables.set(table_idx, table);
+    game_hub(env)?.start_game(
+        &env.current_contract_address(),
+        &hub_session_id(game_id),
+        &player1,
+        &player2,
+        &chips1,
+        &chips2,
+    );
+    publish(
+        env,
+        (symbol_short!("TRN_SEAT"), tournament.id, table_idx),
+        (player1, player2, game_id),
+    );
+    Ok(())
+}
+
+/// Merge tables: while two tables each hold a lone survivor, move the player from the
+/// higher-numbered table onto the lower one and start a game there.
+fn balance_tournament(env: &Env, tournament: &mut Tournament) -> Result<(), Error> {
+    loop {
+        let mut first: Option<u32> = None;
+        let mut last: Option<u32> = None;
+        for (idx, table) in tournament.tables.iter().enumerate() {
+            if table.players.len() == 1 && table.game_id.is_none() {
+                if first.is_none() {
+                    first = Some(idx as u32);
+                } else {
+                    last = Some(idx as u32);
+                }
+            }
+        }
+        let (Some(to), Some(from)) = (first, last) else {
+            return Ok(());
+        };
+        let mut from_table = tournament.tables.get(from).ok_or(Error::InvalidState)?;
+        let mut to_table = tournament.tables.get(to).ok_or(Error::InvalidState)?;
+        let player = from_table.players.pop_back().ok_or(Error::InvalidState)?;
+        to_table.players.push_back(player.clone());
+        tournament.tables.set(from, from_table);
+        tournament.tables.set(to, to_table);
+        publish(env, (symbol_short!("TRN_MOVE"), tournament.id, from, to), player);
+        start_tournament_table(env, tournament, to)?;
+    }
+}
+
+/// Pay prizes by finishing position (winner first) from the pooled entry fees.
+fn finish_tournament(env: &Env, tournament: &mut Tournament, winner: &Address) -> Result<(), Error> {
+    tournament.state = TournamentState::Finished;
+    let config = load_config(env)?;
+    let pool = tournament
+        .config
+        .entry_fee
+        .checked_mul(tournament.entrants.len() as i128)
+        .ok_or(Error::ChipOverflow)?;
+    let rake = chips_bps(pool, config.rake_percentage);
+    let prize_pool = chips_sub(pool, rake)?;
+    let mut standings = Vec::new(env);
+    standings.push_back(winner.clone());
+    for player in tournament.eliminated.iter().rev() {
+        standings.push_back(player);
+    }
+    // Places below the winner get their share; the winner takes the rest of the pool, which
+    // includes the shares of places nobody finished in and the rounding dust.
+    let mut prizes = Vec::new(env);
+    let mut placed = 0;
+    for (position, share) in tournament.config.payouts.iter().enumerate().skip(1) {
+        if position as u32 >= standings.len() {
+            break;
+        }
+        let prize = chips_bps(prize_pool, share);
+        placed = chips_add(placed, prize)?;
+        prizes.push_back(prize);
+    }
+    prizes.push_front(chips_sub(prize_pool, placed)?);
+    for (position, prize) in prizes.iter().enumerate() {
+        let player = standings.get(position as u32).ok_or(Error::InvalidState)?;
+        let position_rake = if position == 0 { rake } else { 0 };
+        if let Some(ref pc) = config.payment_controller {
+            if prize > 0 || position_rake > 0 {
+                payout_winner(env, pc, &player, prize, position_rake);
+            }
+        }
+        publish(
+            env,
+            (symbol_short!("TRN_PRIZE"), tournament.id, position as u32 + 1),
+            (player, prize),
+        );
+    }
+    publish(env, (symbol_short!("TRN_END"), tournament.id), winner.clone());
+    Ok(())
+}
+
+/// A tournament hand is settled: chip counts follow the table's stacks. While both players
+/// have chips the next hand is dealt at the blind level in force; otherwise the loser is
+/// eliminated and tables are balanced, or the tournament ends with one player left.
+fn settle_tournament_hand(env: &Env, tournament_id: u64, mut game: Game) -> Result<(), Error> {
+    let mut tournament = load_tournament(env, tournament_id)?;
+    let player2 = game.player2.clone().ok_or(Error::InvalidState)?;
+    tournament.chips.set(game.player1.clone(), game.stack1);
+    tournament.chips.set(player2.clone(), game.stack2);
+    if game.stack1 > 0 && game.stack2 > 0 {
+        let level = tournament_level(&tournament, env.ledger().timestamp())?;
+        let blinds = (game.small_blind, game.big_blind);
+        game.small_blind = level.small_blind;
+        game.big_blind = level.big_blind;
+        reset_game_for_next_hand(env, &mut game)?;
+        if blinds != (game.small_blind, game.big_blind) {
+            save_session(env, game.id, &game);
+        }
+        save_game(env, game.id, &game);
+        save_tournament(env, &tournament);
+        return Ok(());
+    }
+
+    let (winner, loser) = if game.stack1 > 0 {
+        (game.player1.clone(), player2)
+    } else {
+        (player2, game.player1.clone())
+    };
+    game.winner = Some(winner.clone());
+    save_game(env, game.id, &game);
+    game_hub(env)?.end_game(&hub_session_id(game.id), &(winner == game.player1));
+
+    let table_idx = tournament
+        .tables
+        .iter()
+        .position(|t| t.game_id == Some(game.id))
+        .ok_or(Error::InvalidState)? as u32;
+    tournament.eliminated.push_back(loser.clone());
+    // The loser finishes in the place that was last before this hand.
+    let place = tournament.remaining;
+    tournament.remaining = place.checked_sub(1).ok_or(Error::InvalidState)?;
+    let mut table = tournament.tables.get(table_idx).ok_or(Error::InvalidState)?;
+    table.players = vec![env, winner.clone()];
+    table.game_id = None;
+    tournament.tables.set(table_idx, table);
+    publish(
+        env,
+        (symbol_short!("TRN_BUST"), tournament_id, place),
+        loser,
     );
+
+    if tournament.remaining == 1 {
+        finish_tournament(env, &mut tournament, &winner)?;
+    } else {
+        balance_tournament(env, &mut tournament)?;
+    }
+    save_tournament(env, &tournament);
     Ok(())
 }
 
-/// Com 2 jogadores, após o payout reinicia o jogo para outra mão: estado ShuffleCommit,
-/// seeds/board/apostas zerados, dealer alternado. Não chama hub.end_game.
-fn reset_game_for_next_hand(env: &Env, game_id: u64) -> Result<(), Error> {
-    let mut game = load_game(env, game_id)?;
-    if game.state != GameState::Finished {
-        return Err(Error::InvalidState);
-    }
-    if game.player2.is_none() {
-        return Ok(());
-    }
+/// Com 2 jogadores, depois de entregar o pote ao vencedor reinicia o jogo para outra mão:
+/// estado ShuffleCommit, seeds/board/apostas zerados, dealer alternado. Stacks são mantidos e
+/// cada timebank recebe timebank_refill (até o limite config.timebank).
+/// Não chama hub.end_game.
+fn reset_game_for_next_hand(env: &Env, game: &mut Game) -> Result<(), Error> {
+    let config = load_config(env)?;
+    game.timebank1 = game.timebank1.saturating_add(config.timebank_refill).min(config.timebank);
+    game.timebank2 = game.timebank2.saturating_add(config.timebank_refill).min(config.timebank);
     game.dealer_position = 1u32.saturating_sub(game.dealer_position);
     game.state = GameState::ShuffleCommit;
     game.pot = 0;
@@ -448,10 +2806,7 @@ fn reset_game_for_next_hand(env: &Env, game_id: u64) -> Result<(), Error> {
     game.hand_rank2 = None;
     game.winner = None;
     game.last_action_at = env.ledger().timestamp();
-    save_game(env, game_id, &game);
-    save_session_game(env, game_id, &game);
-    env.events()
-        .publish((symbol_short!("NEXT_HAND"), game_id), ());
+    publish(env, (symbol_short!("NEXT_HAND"), game.id), ());
     Ok(())
 }
 
@@ -460,22 +2815,41 @@ pub struct PokerZkContract;
 
 #[contractimpl]
 impl PokerZkContract {
-    /// Initialize with admin and config (or use __constructor(admin, game_hub) for minimal).
+    /// Set the admin and config of a contract that has neither (__constructor sets both, so
+    /// deployed contracts change their config with set_config). Fails with AlreadyInitialized
+    /// once both are set. It cannot change the owner (see propose_owner).
     pub fn initialize(env: Env, admin: Address, config: GameConfig) -> Result<(), Error> {
-        if env.storage().instance().has(&DataKey::Admin) {
-            admin.require_auth();
+        if let Ok(owner) = load_admin(&env) {
+            owner.require_auth();
+            if admin != owner {
+                return Err(Error::Unauthorized);
+            }
+            if env.storage().instance().has(&DataKey::Config) {
+                return Err(Error::AlreadyInitialized);
+            }
         }
         env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage().instance().set(&DataKey::Config, &config);
+        save_config(&env, &config);
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &config.game_hub);
+        Ok(())
+    }
+
+    /// Replace the config (owner only). The hub follows config.game_hub.
+    pub fn set_config(env: Env, config: GameConfig) -> Result<(), Error> {
+        require_admin(&env)?;
+        save_config(&env, &config);
         env.storage()
             .instance()
             .set(&DataKey::GameHubAddress, &config.game_hub);
         Ok(())
     }
 
-    /// Constructor: admin, game_hub, and first table (mesa). Further tables via add_table (admin only).
+    /// Constructor: admin, game_hub, and first table (mesa). Further tables via add_table (operator only).
     /// max_seats: e.g. 2 for MVP, 4 for 4-player tables later.
     /// Also sets Config so start_game and other functions work (load_config).
+    #[allow(clippy::too_many_arguments)]
     pub fn __constructor(
         env: Env,
         admin: Address,
@@ -497,6 +2871,9 @@ impl PokerZkContract {
             reveal_timeout: 300,
             bet_timeout: 120,
             waiting_timeout: 600,
+            timebank: 60,
+            timebank_refill: 10,
+            abandon_policy: AbandonPolicy::Refund,
             treasury: admin.clone(),
             game_hub: game_hub.clone(),
             payment_controller: None,
@@ -510,11 +2887,17 @@ impl PokerZkContract {
             max_buy_in,
             max_seats,
         };
+        if let Err(error) = validate_table(&first_table) {
+            panic_with_error!(&env, error);
+        }
         save_table(&env, 0, &first_table);
         env.storage().instance().set(&DataKey::NextTableId, &1u64);
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
     }
 
-    /// Add a new table (mesa). Only admin. max_seats e.g. 2 (MVP) or 4.
+    /// Add a new table (mesa). Operator only (the owner until an operator is assigned). max_seats e.g. 2 (MVP) or 4.
     pub fn add_table(
         env: Env,
         admin: Address,
@@ -524,8 +2907,10 @@ impl PokerZkContract {
         max_buy_in: i128,
         max_seats: u32,
     ) -> Result<u64, Error> {
+        if admin != role_holder(&env, Role::Operator)? {
+            return Err(Error::Unauthorized);
+        }
         admin.require_auth();
-        let table_id = next_table_id(&env);
         let table = Table {
             small_blind,
             big_blind,
@@ -533,106 +2918,99 @@ impl PokerZkContract {
             max_buy_in,
             max_seats,
         };
+        validate_table(&table)?;
+        let table_id = next_table_id(&env);
         save_table(&env, table_id, &table);
-        env.events()
-            .publish((symbol_short!("TBL_ADDED"), table_id), admin);
+        publish(&env, (symbol_short!("TBL_ADDED"), table_id), admin);
         Ok(table_id)
     }
 
-    /// Sit at a table. If nobody waiting → you're 1/2 (waiting). If one waiting → game starts (2/2), returns game_id.
+    /// Sit at a table. You are paired with the waiting player whose buy-in is closest (oldest first on
+    /// ties), if it is within the table's tolerance, and a game starts with the effective stack (the
+    /// smaller buy-in), returning game_id. Otherwise you join the table's waiting list. Many pairs can
+    /// play at the same table.
     /// Conforme vão entrando usuários, montam-se as mesas virtuais; a mesa inicia quando tem 2 jogadores.
     pub fn sit_at_table(
         env: Env,
         player: Address,
         table_id: u64,
         buy_in: i128,
+    ) -> Result<SitResult, Error> {
+        Self::sit_at_table_with(env, player, table_id, buy_in, None, TableAccess::Public)
+    }
+
+    /// sit_at_table for private play: `secret` is revealed to pass a Password table or seat, and
+    /// `seat_access` restricts who may be paired with you if you end up waiting. A private table
+    /// rejects anyone it does not admit with NotInvited; a private seat is only paired with players
+    /// it admits (others queue as usual).
+    pub fn sit_at_table_with(
+        env: Env,
+        player: Address,
+        table_id: u64,
+        buy_in: i128,
+        secret: Option<Bytes>,
+        seat_access: TableAccess,
     ) -> Result<SitResult, Error> {
         player.require_auth();
+        require_not_paused(&env)?;
         let config = load_config(&env)?;
-        let table = load_table(&env, table_id)?;
+        let table = load_open_table(&env, table_id)?;
         if buy_in < table.min_buy_in {
             return Err(Error::BuyInTooLow);
         }
         if buy_in > table.max_buy_in {
             return Err(Error::BuyInTooHigh);
         }
+        check_table_access(&env, table_id, &player, &secret)?;
+        check_access_size(&seat_access)?;
 
-        if let Some(session) = load_table_waiting(&env, table_id) {
-            if session.player1 == player {
-                return Err(Error::CannotPlaySelf);
-            }
-            if session.buy_in != buy_in {
-                return Err(Error::InvalidAmount);
-            }
-            clear_table_waiting(&env, table_id);
-            let game_id = next_game_id(&env);
-            let game = Game {
-                id: game_id,
-                state: GameState::ShuffleCommit,
-                player1: session.player1.clone(),
-                player2: Some(player.clone()),
-                buy_in,
-                pot: buy_in + buy_in,
-                small_blind: table.small_blind,
-                big_blind: table.big_blind,
-                dealer_position: 0,
-                board: Vec::new(&env),
-                board_revealed: 0,
-                current_bet_p1: 0,
-                current_bet_p2: 0,
-                total_bet_p1: 0,
-                total_bet_p2: 0,
-                min_raise: table.big_blind,
-                last_raise_amount: table.big_blind,
-                actor: 0,
-                folded: None,
-                seed_commitment1: None,
-                seed_commitment2: None,
-                seed_reveal1: None,
-                seed_reveal2: None,
-                final_seed: None,
-                hand_commitment1: None,
-                hand_commitment2: None,
-                hand_rank1: None,
-                hand_rank2: None,
-                winner: None,
-                created_at: env.ledger().timestamp(),
-                last_action_at: env.ledger().timestamp(),
+        let mut queue = load_table_queue(&env, table_id);
+        if queue.iter().any(|session| session.player1 == player) {
+            return Err(Error::CannotPlaySelf);
+        }
+        if let Some(at) = best_match(&env, table_id, &queue, &player, &secret, buy_in) {
+            let session = queue.get_unchecked(at);
+            queue.remove(at);
+            save_table_queue(&env, table_id, &queue);
+            set_player_waiting(&env, &session.player1, table_id, false);
+            // Different buy-ins play the effective stack; the waiting player's excess goes back.
+            let stack = session.buy_in.min(buy_in);
+            let game_id = next_game_id(&env)?;
+            let game = new_game(
+                &env,
+                game_id,
                 table_id,
-            };
+                &table,
+                session.player1.clone(),
+                Some(player.clone()),
+                stack,
+            );
             save_game(&env, game_id, &game);
             if let Some(ref pc) = config.payment_controller {
-                env.invoke_contract::<()>(
-                    pc,
-                    &Symbol::new(&env, "lock_funds"),
-                    vec![&env, player.into_val(&env), buy_in.into_val(&env)],
-                );
+                if session.buy_in > stack {
+                    refund_player(&env, pc, &session.player1, session.buy_in - stack);
+                }
+                lock_funds(&env, pc, &player, stack);
             }
-            let hub_addr: Address = env
-                .storage()
-                .instance()
-                .get(&DataKey::GameHubAddress)
-                .unwrap();
-            let hub = GameHubClient::new(&env, &hub_addr);
-            hub.start_game(
+            game_hub(&env)?.start_game(
                 &env.current_contract_address(),
-                &(game_id as u32),
+                &hub_session_id(game_id),
                 &game.player1,
                 &player,
-                &buy_in,
-                &buy_in,
+                &stack,
+                &stack,
             );
-            env.events()
-                .publish((symbol_short!("CREATE"), game_id), session.player1.clone());
-            env.events()
-                .publish((symbol_short!("JOIN"), game_id), player.clone());
-            env.events()
-                .publish((symbol_short!("TBL_START"), table_id, game_id), player);
+            publish(&env, (symbol_short!("CREATE"), game_id), session.player1.clone());
+            publish(&env, (symbol_short!("JOIN"), game_id), player.clone());
+            publish(&env, (symbol_short!("TBL_START"), table_id, game_id), player);
             return Ok(SitResult {
                 waiting: false,
                 game_id,
             });
         }
+        if queue.len() >= MAX_WAITING {
+            return Err(Error::WaitingListFull);
+        }
 
         let now = env.ledger().timestamp();
         let session = WaitingSession {
@@ -640,31 +3018,66 @@ impl PokerZkContract {
             buy_in,
             created_at: now,
         };
-        save_table_waiting(&env, table_id, &session);
+        queue.push_back(session);
+        save_table_queue(&env, table_id, &queue);
+        set_player_waiting(&env, &player, table_id, true);
+        if seat_access != TableAccess::Public {
+            save_seat_access(&env, table_id, &player, &seat_access);
+        }
         if let Some(ref pc) = config.payment_controller {
-            env.invoke_contract::<()>(
-                pc,
-                &Symbol::new(&env, "lock_funds"),
-                vec![&env, player.into_val(&env), buy_in.into_val(&env)],
-            );
+            lock_funds(&env, pc, &player, buy_in);
         }
-        env.events()
-            .publish((symbol_short!("TBL_SIT"), table_id), player);
+        publish(&env, (symbol_short!("TBL_SIT"), table_id), player);
         Ok(SitResult {
             waiting: true,
             game_id: 0,
         })
     }
 
-    /// Get current waiting session for a table (1/2). Returns error when 0/2.
+    /// Games the player is in (not finished or cancelled), so a client can resume after a reload.
+    pub fn get_active_games(env: Env, player: Address) -> Vec<u64> {
+        let mut active = Vec::new(&env);
+        for game_id in load_player_games(&env, &player).active.iter() {
+            if game_exists(&env, game_id) {
+                active.push_back(game_id);
+            }
+        }
+        active
+    }
+
+    /// The player's last finished or cancelled games, newest first.
+    pub fn get_recent_games(env: Env, player: Address) -> Vec<u64> {
+        load_player_games(&env, &player).recent
+    }
+
+    /// Where the player is waiting for an opponent (their earliest seat if they wait at
+    /// several tables). Returns error when not seated.
+    pub fn get_waiting_seat(env: Env, player: Address) -> Result<WaitingSeat, Error> {
+        for table_id in load_player_waiting(&env, &player).iter() {
+            let queue = load_table_queue(&env, table_id);
+            if let Some(session) = queue.iter().find(|session| session.player1 == player) {
+                return Ok(WaitingSeat { table_id, session });
+            }
+        }
+        Err(Error::NoWaitingSession)
+    }
+
+    /// All players waiting at a table, oldest first.
+    pub fn get_table_queue(env: Env, table_id: u64) -> Result<Vec<WaitingSession>, Error> {
+        load_table(&env, table_id)?;
+        Ok(load_table_queue(&env, table_id))
+    }
+
+    /// Oldest player waiting at a table. Returns error when nobody waits.
     pub fn get_table_waiting(env: Env, table_id: u64) -> Result<WaitingSession, Error> {
         load_table(&env, table_id)?;
         load_table_waiting(&env, table_id).ok_or(Error::NoWaitingSession)
     }
 
+    /// [Deprecated] Use start_game_with_invite: this auth has no expiry and is not bound to player2.
     /// Start a game with a client-provided session ID (XDR / Game Studio flow).
     /// P1 signs auth for (session_id, table_id, buy_in); P2 submits the tx with P1's auth injected.
-    /// Game is stored under SessionGame(session_id); get_game(session_id) returns it.
+    /// `session_id` must be below HUB_SEQUENTIAL_BASE; the game id is get_session_game_id(session_id).
     pub fn start_game(
         env: Env,
         session_id: u64,
@@ -686,215 +3099,582 @@ impl PokerZkContract {
             table_id.into_val(&env),
             buy_in.into_val(&env),
         ]);
-        if player1 == player2 {
-            return Err(Error::CannotPlaySelf);
+        start_session(&env, session_id, table_id, player1, player2, buy_in)?;
+        Ok(())
+    }
+
+    /// Start a session from player1's invite (XDR flow): player1 signs (session_id, invite) and
+    /// player2 submits. Fails with NotInvited if the invite names another opponent, InviteExpired
+    /// after `expires_ledger`, and InviteUsed / InviteRevoked once consumed or revoked.
+    /// Returns the game id.
+    pub fn start_game_with_invite(
+        env: Env,
+        session_id: u64,
+        player2: Address,
+        invite: Invite,
+    ) -> Result<u64, Error> {
+        let args = vec![&env, session_id.into_val(&env), invite.clone().into_val(&env)];
+        invite.player1.require_auth_for_args(args.clone());
+        player2.require_auth_for_args(args);
+        check_invite_unused(&env, &invite.player1, invite.nonce)?;
+        if env.ledger().sequence() > invite.expires_ledger {
+            return Err(Error::InviteExpired);
+        }
+        if invite.opponent.as_ref().is_some_and(|opponent| *opponent != player2) {
+            return Err(Error::NotInvited);
         }
+        set_invite_status(&env, &invite, InviteStatus::Used);
+        let game_id = start_session(
+            &env,
+            session_id,
+            invite.table_id,
+            invite.player1.clone(),
+            player2,
+            invite.buy_in,
+        )?;
+        publish(&env, (symbol_short!("INV_USED"), invite.player1), invite.nonce);
+        Ok(game_id)
+    }
+
+    /// Game id of the session started with `session_id` (SESSION_ID_BASE + session_id).
+    pub fn get_session_game_id(_env: Env, session_id: u64) -> Result<u64, Error> {
+        session_game_id(session_id)
+    }
+
+    /// Revoke an unused invite (player1 only). Pass the invite so the revocation lasts until it
+    /// would have expired.
+    pub fn revoke_invite(env: Env, invite: Invite) -> Result<(), Error> {
+        invite.player1.require_auth();
+        check_invite_unused(&env, &invite.player1, invite.nonce)?;
+        set_invite_status(&env, &invite, InviteStatus::Revoked);
+        publish(&env, (symbol_short!("INV_REVOK"), invite.player1), invite.nonce);
+        Ok(())
+    }
+
+    /// Leave a table's waiting list, with a refund.
+    /// - If caller is waiting at the table: removes their own seat, anytime.
+    /// - Else: removes every seat older than the table's waiting timeout (so the mesa can be "encerrada" after a period).
+    pub fn cancel_waiting(env: Env, caller: Address, table_id: u64) -> Result<(), Error> {
+        caller.require_auth();
         let config = load_config(&env)?;
-        let table = load_table(&env, table_id)?;
-        if buy_in < table.min_buy_in {
-            return Err(Error::BuyInTooLow);
+        let queue = load_table_queue(&env, table_id);
+        if queue.is_empty() {
+            return Err(Error::NoWaitingSession);
         }
-        if buy_in > table.max_buy_in {
-            return Err(Error::BuyInTooHigh);
+        let now = env.ledger().timestamp();
+        let waiting_timeout = table_timeouts(&env, table_id, &config).waiting;
+        let own_seat = queue.iter().any(|session| session.player1 == caller);
+
+        let mut kept = Vec::new(&env);
+        let mut removed = Vec::new(&env);
+        for session in queue.iter() {
+            let expired = !own_seat
+                && waiting_timeout > 0
+                && now >= session.created_at.saturating_add(waiting_timeout);
+            if session.player1 == caller || expired {
+                removed.push_back(session);
+            } else {
+                kept.push_back(session);
+            }
         }
-        if env.storage().temporary().has(&DataKey::SessionGame(session_id))
-            || env.storage().temporary().has(&DataKey::Game(session_id))
-        {
-            return Err(Error::GameFull);
+        if removed.is_empty() {
+            return Err(Error::WaitingTimeoutNotReached);
         }
 
-        let game = Game {
-            id: session_id,
-            state: GameState::ShuffleCommit,
-            player1: player1.clone(),
-            player2: Some(player2.clone()),
-            buy_in,
-            pot: buy_in + buy_in,
-            small_blind: table.small_blind,
-            big_blind: table.big_blind,
-            dealer_position: 0,
-            board: Vec::new(&env),
-            board_revealed: 0,
-            current_bet_p1: 0,
-            current_bet_p2: 0,
-            total_bet_p1: 0,
-            total_bet_p2: 0,
-            min_raise: table.big_blind,
-            last_raise_amount: table.big_blind,
-            actor: 0,
-            folded: None,
-            seed_commitment1: None,
-            seed_commitment2: None,
-            seed_reveal1: None,
-            seed_reveal2: None,
-            final_seed: None,
-            hand_commitment1: None,
-            hand_commitment2: None,
-            hand_rank1: None,
-            hand_rank2: None,
-            winner: None,
-            created_at: env.ledger().timestamp(),
-            last_action_at: env.ledger().timestamp(),
-            table_id,
-        };
-        save_session_game(&env, session_id, &game);
-        if let Some(ref pc) = config.payment_controller {
-            env.invoke_contract::<()>(
-                pc,
-                &Symbol::new(&env, "lock_funds"),
-                vec![&env, player1.into_val(&env), buy_in.into_val(&env)],
-            );
-            env.invoke_contract::<()>(
-                pc,
-                &Symbol::new(&env, "lock_funds"),
-                vec![&env, player2.into_val(&env), buy_in.into_val(&env)],
-            );
+        save_table_queue(&env, table_id, &kept);
+        for session in removed.iter() {
+            set_player_waiting(&env, &session.player1, table_id, false);
+            if let Some(ref pc) = config.payment_controller {
+                refund_player(&env, pc, &session.player1, session.buy_in);
+            }
+            publish(&env, (symbol_short!("TBL_CLOSE"), table_id), session.player1);
         }
-        let hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .unwrap();
-        let hub = GameHubClient::new(&env, &hub_addr);
-        hub.start_game(
-            &env.current_contract_address(),
-            &(session_id as u32),
-            &player1,
-            &player2,
-            &buy_in,
-            &buy_in,
-        );
-        env.events()
-            .publish((symbol_short!("CREATE"), session_id), player1.clone());
-        env.events()
-            .publish((symbol_short!("JOIN"), session_id), player2.clone());
-        env.events()
-            .publish((symbol_short!("TBL_START"), table_id, session_id), player2);
         Ok(())
     }
 
-    /// Cancel a waiting session (1/2). Frees the table for new players.
-    /// - If caller is the waiting player (player1): can cancel anytime → refund and clear.
-    /// - Else: only after waiting_timeout seconds → refund player1 and clear (so the mesa can be "encerrada" after a period).
-    pub fn cancel_waiting(env: Env, caller: Address, table_id: u64) -> Result<(), Error> {
-        caller.require_auth();
+    pub fn get_table(env: Env, table_id: u64) -> Result<Table, Error> {
+        load_table(&env, table_id)
+    }
+
+    /// Lobby: tables `start..start+limit` (limit capped at MAX_PAGE) with their waiting seat
+    /// and number of active games.
+    pub fn get_tables(env: Env, start: u64, limit: u32) -> Vec<TableInfo> {
+        let count = Self::get_table_count(env.clone());
+        let end = count.min(start.saturating_add(limit.min(MAX_PAGE) as u64));
+        let mut tables = Vec::new(&env);
+        for table_id in start..end {
+            if let Ok(table) = load_table(&env, table_id) {
+                tables.push_back(TableInfo {
+                    table_id,
+                    table,
+                    waiting: load_table_queue(&env, table_id),
+                    active_games: table_active_games(&env, table_id),
+                    status: load_table_status(&env, table_id),
+                    meta: load_table_meta(&env, table_id),
+                });
+            }
+        }
+        tables
+    }
+
+    /// Lobby: active games (not finished or cancelled), optionally only those in `state`, from
+    /// position `cursor` of the active index (0 for the first page). Continue from `next` for
+    /// the following page. A game that settles hands its position to the last listed game, so
+    /// a walk through the pages can miss a game that moved meanwhile.
+    pub fn get_games(env: Env, state: Option<GameState>, cursor: u64, limit: u32) -> GamePage {
+        page_games(&env, cursor, limit, |game| state.is_none_or(|s| game.state == s))
+    }
+
+    /// Lobby: active games whose player on the clock is out of time (advance_timeout applies).
+    /// Paged like get_games.
+    pub fn get_expired_games(env: Env, cursor: u64, limit: u32) -> Result<GamePage, Error> {
         let config = load_config(&env)?;
-        let session = load_table_waiting(&env, table_id).ok_or(Error::NoWaitingSession)?;
         let now = env.ledger().timestamp();
+        Ok(page_games(&env, cursor, limit, |game| {
+            game_deadline(&env, game, &config).is_some_and(|deadline| now > deadline)
+        }))
+    }
 
-        let can_cancel = if caller == session.player1 {
-            true
-        } else {
-            if config.waiting_timeout == 0 {
-                return Err(Error::WaitingTimeoutNotReached);
+    /// Drop index entries for games whose storage has expired, checking up to `limit` entries
+    /// from position `cursor`. Anyone may call. Returns how many were removed.
+    pub fn prune_games(env: Env, cursor: u64, limit: u32) -> u32 {
+        let mut slot = cursor;
+        let mut removed = 0u32;
+        for _ in 0..limit.min(MAX_SCAN) {
+            if slot >= active_count(&env) as u64 {
+                break;
             }
-            now >= session.created_at.saturating_add(config.waiting_timeout)
-        };
+            let key = DataKey::ActiveSlot(slot as u32);
+            let Some(game_id) = env.storage().persistent().get::<_, u64>(&key) else {
+                break;
+            };
+            if game_exists(&env, game_id) {
+                slot += 1;
+                continue;
+            }
+            // The last listed game moves into this position and is checked next.
+            remove_active_game(&env, game_id);
+            removed += 1;
+        }
+        if removed > 0 {
+            publish(&env, (symbol_short!("PRUNE"),), removed);
+        }
+        removed
+    }
 
-        if !can_cancel {
-            return Err(Error::WaitingTimeoutNotReached);
+    /// Change a table's blinds, buy-in limits or seats (operator only). Games already started
+    /// keep the stakes they started with; players already waiting keep their buy-in.
+    pub fn update_table(env: Env, table_id: u64, table: Table) -> Result<(), Error> {
+        require_role(&env, Role::Operator)?;
+        load_table(&env, table_id)?;
+        if load_table_status(&env, table_id) == TableStatus::Retired {
+            return Err(Error::TableRetired);
+        }
+        validate_table(&table)?;
+        save_table(&env, table_id, &table);
+        publish(&env, (symbol_short!("TBL_UPD"), table_id), table);
+        Ok(())
+    }
+
+    /// Stop (paused = true) or resume new seats at a table (operator only). Waiting players stay
+    /// queued and games in progress play on.
+    pub fn pause_table(env: Env, table_id: u64, paused: bool) -> Result<(), Error> {
+        require_role(&env, Role::Operator)?;
+        load_table(&env, table_id)?;
+        if load_table_status(&env, table_id) == TableStatus::Retired {
+            return Err(Error::TableRetired);
+        }
+        let key = DataKey::TableStatus(table_id);
+        set_table_entry(&env, &key, paused.then_some(&TableStatus::Paused));
+        publish(&env, (symbol_short!("TBL_PAUSE"), table_id), paused);
+        Ok(())
+    }
+
+    /// Close a table for good (operator only). It takes no new seats, its waiting list is
+    /// refunded and games in progress play on to the end. The table is then deleted: at once
+    /// if it has no games in progress, otherwise when the last one settles.
+    pub fn retire_table(env: Env, table_id: u64) -> Result<(), Error> {
+        require_role(&env, Role::Operator)?;
+        load_table(&env, table_id)?;
+        if load_table_status(&env, table_id) == TableStatus::Retired {
+            return Err(Error::TableRetired);
+        }
+        save_table_entry(&env, &DataKey::TableStatus(table_id), &TableStatus::Retired);
+        let config = load_config(&env)?;
+        let queue = load_table_queue(&env, table_id);
+        save_table_queue(&env, table_id, &Vec::new(&env));
+        for session in queue.iter() {
+            set_player_waiting(&env, &session.player1, table_id, false);
+            if let Some(ref pc) = config.payment_controller {
+                refund_player(&env, pc, &session.player1, session.buy_in);
+            }
+            publish(&env, (symbol_short!("TBL_CLOSE"), table_id), session.player1);
+        }
+        publish(&env, (symbol_short!("TBL_RETIR"), table_id), ());
+        if table_active_games(&env, table_id) == 0 {
+            purge_table(&env, table_id);
+        }
+        Ok(())
+    }
+
+    pub fn get_table_status(env: Env, table_id: u64) -> Result<TableStatus, Error> {
+        load_table(&env, table_id)?;
+        Ok(load_table_status(&env, table_id))
+    }
+
+    /// Set a table's lobby metadata (operator only). Names are at most 64 characters.
+    pub fn set_table_meta(env: Env, table_id: u64, meta: TableMeta) -> Result<(), Error> {
+        require_role(&env, Role::Operator)?;
+        load_table(&env, table_id)?;
+        if meta.name.len() > 64 {
+            return Err(Error::MessageTooLong);
+        }
+        save_table_entry(&env, &DataKey::TableMeta(table_id), &meta);
+        publish(&env, (symbol_short!("TBL_META"), table_id), meta);
+        Ok(())
+    }
+
+    pub fn get_table_meta(env: Env, table_id: u64) -> Result<TableMeta, Error> {
+        load_table(&env, table_id)?;
+        Ok(load_table_meta(&env, table_id))
+    }
+
+    /// Number of tables (ids 0..count-1). Frontend can iterate get_table(0)..get_table(count-1).
+    pub fn get_table_count(env: Env) -> u64 {
+        env.storage().instance().get(&DataKey::NextTableId).unwrap_or(0)
+    }
+
+    /// Make a table private (Allowlist or Password) or public again (operator only). Seats and
+    /// games already started are not affected.
+    pub fn set_table_access(env: Env, table_id: u64, access: TableAccess) -> Result<(), Error> {
+        require_role(&env, Role::Operator)?;
+        load_table(&env, table_id)?;
+        check_access_size(&access)?;
+        let key = DataKey::TableAccess(table_id);
+        set_table_entry(&env, &key, (access != TableAccess::Public).then_some(&access));
+        publish(&env, (symbol_short!("TBL_ACCES"), table_id), access);
+        Ok(())
+    }
+
+    pub fn get_table_access(env: Env, table_id: u64) -> Result<TableAccess, Error> {
+        load_table(&env, table_id)?;
+        Ok(load_table_access(&env, table_id))
+    }
+
+    /// Set how far apart two buy-ins may be (bps of the larger one) to be paired at a table
+    /// (operator only). 0 pairs only equal buy-ins.
+    pub fn set_table_tolerance(env: Env, table_id: u64, tolerance_bps: u32) -> Result<(), Error> {
+        require_role(&env, Role::Operator)?;
+        load_table(&env, table_id)?;
+        if tolerance_bps > 10_000 {
+            return Err(Error::InvalidAmount);
+        }
+        save_table_entry(&env, &DataKey::TableTolerance(table_id), &tolerance_bps);
+        publish(&env, (symbol_short!("TBL_TOL"), table_id), tolerance_bps);
+        Ok(())
+    }
+
+    pub fn get_table_tolerance(env: Env, table_id: u64) -> Result<u32, Error> {
+        load_table(&env, table_id)?;
+        Ok(load_table_tolerance(&env, table_id))
+    }
+
+    /// Set the match format for new games at a table (operator only). Games keep the format
+    /// they started with.
+    pub fn set_table_format(env: Env, table_id: u64, format: MatchFormat) -> Result<(), Error> {
+        require_role(&env, Role::Operator)?;
+        load_table(&env, table_id)?;
+        if format == MatchFormat::BestOf(0) {
+            return Err(Error::InvalidAmount);
+        }
+        save_table_entry(&env, &DataKey::TableFormat(table_id), &format);
+        publish(&env, (symbol_short!("TBL_FMT"), table_id), format);
+        Ok(())
+    }
+
+    pub fn get_table_format(env: Env, table_id: u64) -> Result<MatchFormat, Error> {
+        load_table(&env, table_id)?;
+        Ok(load_table_format(&env, table_id))
+    }
+
+    /// Override (Some) or clear (None) a table's timeouts. Operator only. Phase timeouts must be
+    /// non-zero; waiting = 0 keeps waiting sessions open until cancelled by their player.
+    pub fn set_table_timeouts(
+        env: Env,
+        table_id: u64,
+        timeouts: Option<TimeoutConfig>,
+    ) -> Result<(), Error> {
+        require_role(&env, Role::Operator)?;
+        load_table(&env, table_id)?;
+        let key = DataKey::TableTimeouts(table_id);
+        match timeouts {
+            Some(t) => {
+                let phases = [
+                    t.seed_commit,
+                    t.seed_reveal,
+                    t.betting,
+                    t.showdown,
+                    t.proof_submission,
+                ];
+                if phases.contains(&0)
+                    || phases.iter().chain([&t.waiting]).any(|&secs| secs > MAX_TIMEOUT)
+                {
+                    return Err(Error::InvalidAmount);
+                }
+                save_table_entry(&env, &key, &t);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+        publish(&env, (symbol_short!("TBL_TMOUT"), table_id), timeouts);
+        Ok(())
+    }
+
+    /// Set or clear the keeper bounty paid by advance_timeout (admin only).
+    pub fn set_keeper_bounty(env: Env, bounty: Option<KeeperBounty>) -> Result<(), Error> {
+        require_admin(&env)?;
+        match bounty {
+            Some(b) => {
+                if b.amount <= 0 || b.cap < b.amount {
+                    return Err(Error::InvalidAmount);
+                }
+                env.storage().instance().set(&DataKey::KeeperBounty, &b);
+            }
+            None => env.storage().instance().remove(&DataKey::KeeperBounty),
+        }
+        publish(&env, (symbol_short!("BOUNTY_CF"),), bounty);
+        Ok(())
+    }
+
+    pub fn get_keeper_bounty(env: Env) -> Option<KeeperBounty> {
+        env.storage().instance().get(&DataKey::KeeperBounty)
+    }
+
+    /// Timeouts in force at a table (its override or the global config).
+    pub fn get_table_timeouts(env: Env, table_id: u64) -> Result<TimeoutConfig, Error> {
+        load_table(&env, table_id)?;
+        let config = load_config(&env)?;
+        Ok(table_timeouts(&env, table_id, &config))
+    }
+
+    /// Create a tournament (operator only). Registration stays open until registration_deadline.
+    pub fn create_tournament(env: Env, config: TournamentConfig) -> Result<u64, Error> {
+        let operator = require_role(&env, Role::Operator)?;
+        load_open_table(&env, config.table_id)?;
+        let now = env.ledger().timestamp();
+        let payout_total = config.payouts.iter().try_fold(0u32, |sum, share| sum.checked_add(share));
+        if config.registration_deadline <= now
+            || config.entry_fee < 0
+            || config.starting_chips <= 0
+            || config.max_entrants < 2
+            || config.blind_levels.is_empty()
+            || config.blind_levels.iter().any(|l| l.small_blind <= 0 || l.small_blind > l.big_blind)
+            || config.payouts.is_empty()
+            || config.payouts.len() > config.max_entrants
+            || payout_total.is_none_or(|total| total > 10_000)
+        {
+            return Err(Error::InvalidTournamentConfig);
         }
+        let key = DataKey::NextTournamentId;
+        let tournament_id: u64 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(tournament_id + 1));
+        let tournament = Tournament {
+            id: tournament_id,
+            state: TournamentState::Registering,
+            config,
+            entrants: Vec::new(&env),
+            chips: Map::new(&env),
+            tables: Vec::new(&env),
+            eliminated: Vec::new(&env),
+            remaining: 0,
+            started_at: 0,
+        };
+        save_tournament(&env, &tournament);
+        publish(&env, (symbol_short!("TRN_NEW"), tournament_id), operator);
+        Ok(tournament_id)
+    }
 
-        clear_table_waiting(&env, table_id);
+    /// Register for a tournament before its deadline. Locks the entry fee in escrow.
+    pub fn register_tournament(env: Env, player: Address, tournament_id: u64) -> Result<(), Error> {
+        player.require_auth();
+        require_not_paused(&env)?;
+        let mut tournament = load_tournament(&env, tournament_id)?;
+        if tournament.state != TournamentState::Registering
+            || env.ledger().timestamp() > tournament.config.registration_deadline
+        {
+            return Err(Error::RegistrationClosed);
+        }
+        if tournament.chips.contains_key(player.clone()) {
+            return Err(Error::AlreadyRegistered);
+        }
+        if tournament.entrants.len() >= tournament.config.max_entrants {
+            return Err(Error::TournamentFull);
+        }
+        let config = load_config(&env)?;
         if let Some(ref pc) = config.payment_controller {
-            let sub = SubContractInvocation {
-                context: ContractContext {
-                    contract: pc.clone(),
-                    fn_name: Symbol::new(&env, "refund_on_timeout"),
-                    args: vec![
-                        &env,
-                        session.player1.into_val(&env),
-                        session.buy_in.into_val(&env),
-                    ],
-                },
-                sub_invocations: Vec::new(&env),
-            };
-            env.authorize_as_current_contract(vec![&env, InvokerContractAuthEntry::Contract(sub)]);
-            env.invoke_contract::<()>(
-                pc,
-                &Symbol::new(&env, "refund_on_timeout"),
-                vec![
-                    &env,
-                    session.player1.into_val(&env),
-                    session.buy_in.into_val(&env),
-                ],
-            );
+            if tournament.config.entry_fee > 0 {
+                lock_funds(&env, pc, &player, tournament.config.entry_fee);
+            }
         }
-        env.events()
-            .publish((symbol_short!("TBL_CLOSE"), table_id), session.player1);
+        tournament.entrants.push_back(player.clone());
+        tournament
+            .chips
+            .set(player.clone(), tournament.config.starting_chips);
+        save_tournament(&env, &tournament);
+        publish(&env, (symbol_short!("TRN_REG"), tournament_id), player);
         Ok(())
     }
 
-    pub fn get_table(env: Env, table_id: u64) -> Result<Table, Error> {
-        load_table(&env, table_id)
+    /// Start a tournament once registration has closed (anyone may call). Entrants are seated
+    /// at random, two per table. With fewer than 2 entrants it is cancelled and fees refunded.
+    pub fn start_tournament(env: Env, tournament_id: u64) -> Result<(), Error> {
+        let mut tournament = load_tournament(&env, tournament_id)?;
+        if tournament.state != TournamentState::Registering {
+            return Err(Error::InvalidState);
+        }
+        let now = env.ledger().timestamp();
+        let full = tournament.entrants.len() >= tournament.config.max_entrants;
+        if now <= tournament.config.registration_deadline && !full {
+            return Err(Error::RegistrationOpen);
+        }
+        if tournament.entrants.len() < 2 {
+            tournament.state = TournamentState::Cancelled;
+            let config = load_config(&env)?;
+            if let Some(ref pc) = config.payment_controller {
+                if tournament.config.entry_fee > 0 {
+                    for player in tournament.entrants.iter() {
+                        refund_player(&env, pc, &player, tournament.config.entry_fee);
+                    }
+                }
+            }
+            save_tournament(&env, &tournament);
+            publish(&env, (symbol_short!("TRN_CNCL"), tournament_id), ());
+            return Ok(());
+        }
+
+        let mut seating = tournament.entrants.clone();
+        env.prng().shuffle(&mut seating);
+        tournament.state = TournamentState::Running;
+        tournament.started_at = now;
+        tournament.remaining = seating.len();
+        let mut i = 0;
+        while let Some(player) = seating.get(i) {
+            let mut players = vec![&env, player];
+            if let Some(opponent) = seating.get(i + 1) {
+                players.push_back(opponent);
+            }
+            tournament.tables.push_back(TournamentTable {
+                players,
+                game_id: None,
+            });
+            i += 2;
+        }
+        for (idx, table) in tournament.tables.clone().iter().enumerate() {
+            if table.players.len() == 2 {
+                start_tournament_table(&env, &mut tournament, idx as u32)?;
+            }
+        }
+        save_tournament(&env, &tournament);
+        publish(&env, (symbol_short!("TRN_START"), tournament_id), tournament.remaining);
+        Ok(())
+    }
+
+    /// Deal a new game at a tournament table whose game was lost because its storage expired
+    /// (anyone may call). The players go on with the chips they had after their last settled
+    /// hand, so the hand in progress is void; its hub session is closed for the chip leader.
+    /// Returns the new game id.
+    pub fn restart_tournament_table(
+        env: Env,
+        tournament_id: u64,
+        table_idx: u32,
+    ) -> Result<u64, Error> {
+        let mut tournament = load_tournament(&env, tournament_id)?;
+        if tournament.state != TournamentState::Running {
+            return Err(Error::InvalidState);
+        }
+        let table = tournament.tables.get(table_idx).ok_or(Error::InvalidState)?;
+        let lost = table.game_id.ok_or(Error::InvalidState)?;
+        if stored_game(&env, lost).is_some() {
+            return Err(Error::InvalidState);
+        }
+        remove_active_game(&env, lost);
+        env.storage().persistent().remove(&DataKey::TournamentGame(lost));
+        let chips = |seat: u32| {
+            let player = table.players.get(seat).ok_or(Error::InvalidState)?;
+            Ok::<_, Error>(tournament.chips.get(player).unwrap_or(0))
+        };
+        let player1_leads = chips(0)? >= chips(1)?;
+        game_hub(&env)?.end_game(&hub_session_id(lost), &player1_leads);
+        start_tournament_table(&env, &mut tournament, table_idx)?;
+        let game_id = tournament
+            .tables
+            .get(table_idx)
+            .and_then(|t| t.game_id)
+            .ok_or(Error::InvalidState)?;
+        save_tournament(&env, &tournament);
+        publish(
+            &env,
+            (symbol_short!("TRN_RSTRT"), tournament_id, table_idx),
+            (lost, game_id),
+        );
+        Ok(game_id)
+    }
+
+    /// Tournament state: entrants, chip counts, tables, eliminations and remaining count.
+    pub fn get_tournament(env: Env, tournament_id: u64) -> Result<Tournament, Error> {
+        load_tournament(&env, tournament_id)
+    }
+
+    /// Players still in the tournament (chips > 0).
+    pub fn get_tournament_remaining(env: Env, tournament_id: u64) -> Result<Vec<Address>, Error> {
+        let tournament = load_tournament(&env, tournament_id)?;
+        let mut remaining = Vec::new(&env);
+        for player in tournament.entrants.iter() {
+            if tournament.chips.get(player.clone()).unwrap_or(0) > 0 {
+                remaining.push_back(player);
+            }
+        }
+        Ok(remaining)
     }
 
-    /// Number of tables (ids 0..count-1). Frontend can iterate get_table(0)..get_table(count-1).
-    pub fn get_table_count(env: Env) -> u64 {
-        env.storage().instance().get(&DataKey::NextTableId).unwrap_or(0)
+    /// Blind level in force now (the first level until the tournament starts).
+    pub fn get_tournament_level(env: Env, tournament_id: u64) -> Result<BlindLevel, Error> {
+        let tournament = load_tournament(&env, tournament_id)?;
+        if tournament.state == TournamentState::Registering {
+            return tournament
+                .config
+                .blind_levels
+                .get(0)
+                .ok_or(Error::InvalidTournamentConfig);
+        }
+        tournament_level(&tournament, env.ledger().timestamp())
     }
 
     pub fn create_game(env: Env, player: Address, table_id: u64, buy_in: i128) -> Result<u64, Error> {
         player.require_auth();
+        require_not_paused(&env)?;
         let config = load_config(&env)?;
-        let table = load_table(&env, table_id)?;
+        let table = load_open_table(&env, table_id)?;
         if buy_in < table.min_buy_in {
             return Err(Error::BuyInTooLow);
         }
         if buy_in > table.max_buy_in {
             return Err(Error::BuyInTooHigh);
         }
+        check_table_access(&env, table_id, &player, &None)?;
 
-        let game_id = next_game_id(&env);
-        let game = Game {
-            id: game_id,
-            state: GameState::WaitingForPlayers,
-            player1: player.clone(),
-            player2: None,
-            buy_in,
-            pot: buy_in,
-            small_blind: table.small_blind,
-            big_blind: table.big_blind,
-            dealer_position: 0,
-            board: Vec::new(&env),
-            board_revealed: 0,
-            current_bet_p1: 0,
-            current_bet_p2: 0,
-            total_bet_p1: 0,
-            total_bet_p2: 0,
-            min_raise: table.big_blind,
-            last_raise_amount: table.big_blind,
-            actor: 0,
-            folded: None,
-            seed_commitment1: None,
-            seed_commitment2: None,
-            seed_reveal1: None,
-            seed_reveal2: None,
-            final_seed: None,
-            hand_commitment1: None,
-            hand_commitment2: None,
-            hand_rank1: None,
-            hand_rank2: None,
-            winner: None,
-            created_at: env.ledger().timestamp(),
-            last_action_at: env.ledger().timestamp(),
-            table_id,
-        };
+        let game_id = next_game_id(&env)?;
+        let game = new_game(&env, game_id, table_id, &table, player.clone(), None, buy_in);
         save_game(&env, game_id, &game);
         if let Some(ref pc) = config.payment_controller {
-            env.invoke_contract::<()>(
-                pc,
-                &Symbol::new(&env, "lock_funds"),
-                vec![&env, player.into_val(&env), buy_in.into_val(&env)],
-            );
+            lock_funds(&env, pc, &player, buy_in);
         }
-        env.events()
-            .publish((symbol_short!("CREATE"), game_id), player);
+        publish(&env, (symbol_short!("CREATE"), game_id), player);
         Ok(game_id)
     }
 
     pub fn join_game(env: Env, player: Address, game_id: u64) -> Result<(), Error> {
         player.require_auth();
+        require_not_paused(&env)?;
         let mut game = load_game(&env, game_id)?;
         if game.state != GameState::WaitingForPlayers {
             return Err(Error::InvalidState);
@@ -905,39 +3685,358 @@ impl PokerZkContract {
         if game.player2.is_some() {
             return Err(Error::GameFull);
         }
+        load_open_table(&env, game.table_id)?;
+        check_table_access(&env, game.table_id, &player, &None)?;
 
         game.player2 = Some(player.clone());
-        game.pot += game.buy_in;
+        game.stack2 = game.buy_in;
+        game.bought_in2 = game.buy_in;
         game.state = GameState::ShuffleCommit;
         game.last_action_at = env.ledger().timestamp();
+        save_session(&env, game_id, &game);
         save_game(&env, game_id, &game);
+        index_player_game(&env, &player, game_id, false);
         let config = load_config(&env)?;
         if let Some(ref pc) = config.payment_controller {
-            env.invoke_contract::<()>(
-                pc,
-                &Symbol::new(&env, "lock_funds"),
-                vec![&env, player.into_val(&env), game.buy_in.into_val(&env)],
-            );
+            lock_funds(&env, pc, &player, game.buy_in);
         }
 
-        let hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .unwrap();
-        let hub = GameHubClient::new(&env, &hub_addr);
-        let session_id = game_id as u32;
+        let hub = game_hub(&env)?;
         hub.start_game(
             &env.current_contract_address(),
-            &session_id,
+            &hub_session_id(game_id),
             &game.player1,
             &player,
             &game.buy_in,
             &game.buy_in,
         );
 
-        env.events()
-            .publish((symbol_short!("JOIN"), game_id), player);
+        publish(&env, (symbol_short!("JOIN"), game_id), player);
+        Ok(())
+    }
+
+    /// Leave a cash session between hands (or a game nobody has joined yet). Both stacks and
+    /// the session rake are paid out of escrow, the game ends and the hub session is closed;
+    /// the player who came out ahead (stack minus chips bought) is recorded as the winner.
+    /// Leaving a freezeout or best-of-N match forfeits it to the opponent.
+    pub fn leave_game(env: Env, player: Address, game_id: u64) -> Result<(), Error> {
+        player.require_auth();
+        let mut game = load_game(&env, game_id)?;
+        if !is_player(&game, &player) {
+            return Err(Error::NotPlayer);
+        }
+        if load_tournament_game(&env, game_id).is_some() {
+            return Err(Error::InvalidState);
+        }
+        match game.state {
+            GameState::Finished => return Err(Error::GameAlreadyFinished),
+            GameState::Cancelled => return Err(Error::GameCancelled),
+            GameState::WaitingForPlayers => {
+                let config = load_config(&env)?;
+                if let Some(ref pc) = config.payment_controller {
+                    refund_player(&env, pc, &player, game.stack1);
+                }
+                game.stack1 = 0;
+                game.state = GameState::Cancelled;
+            }
+            _ => {
+                if !is_between_hands(&game) {
+                    return Err(Error::HandInProgress);
+                }
+                if game.format == MatchFormat::Cash {
+                    let net1 = chips_sub(game.stack1, game.bought_in1)?;
+                    let net2 = chips_sub(game.stack2, game.bought_in2)?;
+                    game.winner = if net1 >= net2 {
+                        Some(game.player1.clone())
+                    } else {
+                        game.player2.clone()
+                    };
+                    game.state = GameState::Finished;
+                } else {
+                    // Leaving a match forfeits it to the opponent.
+                    let opponent = if player == game.player1 {
+                        game.player2.clone().ok_or(Error::InvalidState)?
+                    } else {
+                        game.player1.clone()
+                    };
+                    award_match(&mut game, &opponent)?;
+                }
+                end_session(&env, &mut game)?;
+            }
+        }
+        game.last_action_at = env.ledger().timestamp();
+        save_game(&env, game_id, &game);
+        publish(&env, (symbol_short!("LEAVE"), game_id), player);
+        Ok(())
+    }
+
+    /// Cancel a cash game by agreement. Both players authorize `(game_id, hand)`, where `hand`
+    /// is the number of hands already settled (`hands_won1 + hands_won2`), so an agreement
+    /// cannot be replayed on a later hand. Bets in the current hand go back to their owners,
+    /// each stake is refunded from escrow and the game ends Cancelled with no winner.
+    pub fn mutual_cancel(env: Env, game_id: u64, hand: u32) -> Result<(), Error> {
+        let mut game = load_game(&env, game_id)?;
+        match game.state {
+            GameState::Finished => return Err(Error::GameAlreadyFinished),
+            GameState::Cancelled => return Err(Error::GameCancelled),
+            // A lone player leaves with cancel_waiting or leave_game.
+            GameState::WaitingForPlayers => return Err(Error::InvalidState),
+            _ => {}
+        }
+        if load_tournament_game(&env, game_id).is_some() {
+            return Err(Error::InvalidState);
+        }
+        if hand != hands_played(&game) {
+            return Err(Error::HandMismatch);
+        }
+        let player2 = game.player2.clone().ok_or(Error::InvalidState)?;
+        let args = vec![&env, game_id.into_val(&env), hand.into_val(&env)];
+        game.player1.require_auth_for_args(args.clone());
+        player2.require_auth_for_args(args);
+
+        // The hub only records wins; whoever leaves with more chips is reported.
+        let player1_won = cancel_with_refunds(&env, &mut game)?;
+        save_game(&env, game_id, &game);
+        game_hub(&env)?.end_game(&hub_session_id(game_id), &player1_won);
+        publish(&env, (symbol_short!("MCANCEL"), game_id), hand);
+        Ok(())
+    }
+
+    /// Emergency stop (owner or guardian): no new seats, games, joins, rematches, rebuys or
+    /// tournament entries, so no new funds are locked. Games in progress play on and can
+    /// still be cancelled and refunded.
+    pub fn pause(env: Env, caller: Address) -> Result<(), Error> {
+        caller.require_auth();
+        if caller != load_admin(&env)? && caller != role_holder(&env, Role::Guardian)? {
+            return Err(Error::Unauthorized);
+        }
+        if env.storage().instance().has(&DataKey::PausedAt) {
+            return Err(Error::ContractPaused);
+        }
+        let now = env.ledger().timestamp();
+        env.storage().instance().set(&DataKey::PausedAt, &now);
+        publish(&env, (symbol_short!("PAUSE"),), caller);
+        Ok(())
+    }
+
+    /// Lift the emergency stop (owner only).
+    pub fn unpause(env: Env) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
+        if !env.storage().instance().has(&DataKey::PausedAt) {
+            return Err(Error::NotPaused);
+        }
+        env.storage().instance().remove(&DataKey::PausedAt);
+        publish(&env, (symbol_short!("UNPAUSE"),), admin);
+        Ok(())
+    }
+
+    /// When the contract was paused, None while it runs.
+    pub fn get_paused_at(env: Env) -> Option<u64> {
+        env.storage().instance().get(&DataKey::PausedAt)
+    }
+
+    /// Once the contract has been paused for EMERGENCY_GRACE, either player can end a cash
+    /// game on their own: bets in the current hand go back, each stake is refunded from escrow
+    /// (chips won beyond it are paid out) and the game is Cancelled. A game still waiting for
+    /// an opponent refunds its creator. Tournament games are not covered: their chips are the
+    /// tournament's prize pool.
+    pub fn emergency_withdraw(env: Env, player: Address, game_id: u64) -> Result<(), Error> {
+        player.require_auth();
+        let paused_at: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PausedAt)
+            .ok_or(Error::NotPaused)?;
+        if env.ledger().timestamp() < paused_at.saturating_add(EMERGENCY_GRACE) {
+            return Err(Error::GracePeriodNotOver);
+        }
+        let mut game = load_game(&env, game_id)?;
+        if !is_player(&game, &player) {
+            return Err(Error::NotPlayer);
+        }
+        match game.state {
+            GameState::Finished => return Err(Error::GameAlreadyFinished),
+            GameState::Cancelled => return Err(Error::GameCancelled),
+            _ => {}
+        }
+        if load_tournament_game(&env, game_id).is_some() {
+            return Err(Error::InvalidState);
+        }
+        if game.player2.is_none() {
+            if let Some(ref pc) = load_config(&env)?.payment_controller {
+                refund_player(&env, pc, &game.player1, game.stack1);
+            }
+            game.stack1 = 0;
+            game.state = GameState::Cancelled;
+            game.last_action_at = env.ledger().timestamp();
+            save_game(&env, game_id, &game);
+        } else {
+            let player1_won = cancel_with_refunds(&env, &mut game)?;
+            save_game(&env, game_id, &game);
+            game_hub(&env)?.end_game(&hub_session_id(game_id), &player1_won);
+        }
+        publish(&env, (symbol_short!("EMERGENCY"), game_id), player);
+        Ok(())
+    }
+
+    /// Offer the opponent of a settled cash game a rematch at the same table and stakes. The
+    /// game's buy-in is locked now, so the opponent alone starts the rematch with
+    /// accept_rematch. A game can be rematched once.
+    pub fn offer_rematch(env: Env, player: Address, game_id: u64) -> Result<(), Error> {
+        player.require_auth();
+        require_not_paused(&env)?;
+        let game = load_game(&env, game_id)?;
+        if !is_player(&game, &player) {
+            return Err(Error::NotPlayer);
+        }
+        if !is_over(game.state)
+            || game.player2.is_none()
+            || load_tournament_game(&env, game_id).is_some()
+            || env.storage().persistent().has(&DataKey::Rematch(game_id))
+            || env.storage().persistent().has(&DataKey::RematchedTo(game_id))
+        {
+            return Err(Error::InvalidState);
+        }
+        let config = load_config(&env)?;
+        let table = load_open_table(&env, game.table_id)?;
+        if game.buy_in < table.min_buy_in {
+            return Err(Error::BuyInTooLow);
+        }
+        if game.buy_in > table.max_buy_in {
+            return Err(Error::BuyInTooHigh);
+        }
+        let offer = RematchOffer {
+            player: player.clone(),
+            buy_in: game.buy_in,
+            created_at: env.ledger().timestamp(),
+        };
+        let key = DataKey::Rematch(game_id);
+        env.storage().persistent().set(&key, &offer);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        if let Some(ref pc) = config.payment_controller {
+            lock_funds(&env, pc, &player, offer.buy_in);
+        }
+        publish(&env, (symbol_short!("RM_OFFER"), game_id), player);
+        Ok(())
+    }
+
+    /// Accept the opponent's rematch offer: a new game starts with the same players, table and
+    /// stakes, and the dealer button moves on as if it were the next hand. The offering
+    /// player's buy-in was locked with the offer, the accepting player's is locked now; each
+    /// lock reuses what the PaymentController still holds for the player (set_keep_balance).
+    /// The table must still be open and take the offer's buy-in; otherwise the offer can only
+    /// be cancelled. Returns the new game id.
+    pub fn accept_rematch(env: Env, player: Address, game_id: u64) -> Result<u64, Error> {
+        player.require_auth();
+        require_not_paused(&env)?;
+        let game = load_game(&env, game_id)?;
+        if !is_player(&game, &player) {
+            return Err(Error::NotPlayer);
+        }
+        let offer: RematchOffer = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Rematch(game_id))
+            .ok_or(Error::NoRematchOffer)?;
+        if offer.player == player {
+            return Err(Error::CannotPlaySelf);
+        }
+        let config = load_config(&env)?;
+        let table = load_open_table(&env, game.table_id)?;
+        if offer.buy_in < table.min_buy_in {
+            return Err(Error::BuyInTooLow);
+        }
+        if offer.buy_in > table.max_buy_in {
+            return Err(Error::BuyInTooHigh);
+        }
+        let player2 = game.player2.clone().ok_or(Error::InvalidState)?;
+
+        let rematch_id = next_game_id(&env)?;
+        let mut rematch = new_game(
+            &env,
+            rematch_id,
+            game.table_id,
+            &table,
+            game.player1.clone(),
+            Some(player2.clone()),
+            offer.buy_in,
+        );
+        rematch.dealer_position = 1u32.saturating_sub(game.dealer_position);
+        env.storage().persistent().remove(&DataKey::Rematch(game_id));
+        let key = DataKey::RematchedTo(game_id);
+        env.storage().persistent().set(&key, &rematch_id);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        save_game(&env, rematch_id, &rematch);
+        if let Some(ref pc) = config.payment_controller {
+            lock_funds(&env, pc, &player, offer.buy_in);
+        }
+        game_hub(&env)?.start_game(
+            &env.current_contract_address(),
+            &hub_session_id(rematch_id),
+            &rematch.player1,
+            &player2,
+            &offer.buy_in,
+            &offer.buy_in,
+        );
+        publish(&env, (symbol_short!("CREATE"), rematch_id), rematch.player1);
+        publish(&env, (symbol_short!("JOIN"), rematch_id), player2);
+        publish(&env, (symbol_short!("REMATCH"), game_id), rematch_id);
+        Ok(rematch_id)
+    }
+
+    /// Withdraw a rematch offer with a refund. The offering player can do so anytime; anyone
+    /// else once the table's waiting timeout has passed since the offer.
+    pub fn cancel_rematch(env: Env, caller: Address, game_id: u64) -> Result<(), Error> {
+        caller.require_auth();
+        let key = DataKey::Rematch(game_id);
+        let offer: RematchOffer = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::NoRematchOffer)?;
+        let config = load_config(&env)?;
+        if caller != offer.player {
+            let game = load_game(&env, game_id)?;
+            let waiting_timeout = table_timeouts(&env, game.table_id, &config).waiting;
+            let now = env.ledger().timestamp();
+            if waiting_timeout == 0 || now < offer.created_at.saturating_add(waiting_timeout) {
+                return Err(Error::WaitingTimeoutNotReached);
+            }
+        }
+        env.storage().persistent().remove(&key);
+        if let Some(ref pc) = config.payment_controller {
+            refund_player(&env, pc, &offer.player, offer.buy_in);
+        }
+        publish(&env, (symbol_short!("RM_CANCEL"), game_id), offer.player);
+        Ok(())
+    }
+
+    /// Pending rematch offer on a game.
+    pub fn get_rematch(env: Env, game_id: u64) -> Result<RematchOffer, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Rematch(game_id))
+            .ok_or(Error::NoRematchOffer)
+    }
+
+    /// Rebuy after busting: between hands, a player with no chips buys back in for an amount
+    /// within the table's buy-in limits. Funds are locked in escrow.
+    pub fn rebuy(env: Env, player: Address, game_id: u64, amount: i128) -> Result<(), Error> {
+        player.require_auth();
+        add_chips(&env, &player, game_id, amount, true)?;
+        publish(&env, (symbol_short!("REBUY"), game_id, amount), player);
+        Ok(())
+    }
+
+    /// Top up a stack between hands, up to the table's max_buy_in. Funds are locked in escrow.
+    pub fn top_up(env: Env, player: Address, game_id: u64, amount: i128) -> Result<(), Error> {
+        player.require_auth();
+        add_chips(&env, &player, game_id, amount, false)?;
+        publish(&env, (symbol_short!("TOP_UP"), game_id, amount), player);
         Ok(())
     }
 
@@ -955,6 +4054,10 @@ impl PokerZkContract {
         if !is_player(&game, &player) {
             return Err(Error::NotPlayer);
         }
+        // A busted player has to rebuy (or someone leaves) before the next hand.
+        if game.stack1 == 0 || game.stack2 == 0 {
+            return Err(Error::InsufficientChips);
+        }
 
         if player == game.player1 {
             if game.seed_commitment1.is_some() {
@@ -972,8 +4075,7 @@ impl PokerZkContract {
         }
         game.last_action_at = env.ledger().timestamp();
         save_game(&env, game_id, &game);
-        env.events()
-            .publish((symbol_short!("COMMIT"), game_id), player);
+        publish(&env, (symbol_short!("COMMIT"), game_id), player);
         Ok(())
     }
 
@@ -998,7 +4100,8 @@ impl PokerZkContract {
             if game.seed_reveal1.is_some() {
                 return Err(Error::AlreadyRevealed);
             }
-            if computed != *game.seed_commitment1.as_ref().unwrap() {
+            let commitment = game.seed_commitment1.clone().ok_or(Error::NotCommitted)?;
+            if computed != commitment {
                 return Err(Error::InvalidSeed);
             }
             game.seed_reveal1 = Some(seed);
@@ -1006,15 +4109,14 @@ impl PokerZkContract {
             if game.seed_reveal2.is_some() {
                 return Err(Error::AlreadyRevealed);
             }
-            if computed != *game.seed_commitment2.as_ref().unwrap() {
+            let commitment = game.seed_commitment2.clone().ok_or(Error::NotCommitted)?;
+            if computed != commitment {
                 return Err(Error::InvalidSeed);
             }
             game.seed_reveal2 = Some(seed);
         }
 
-        if game.seed_reveal1.is_some() && game.seed_reveal2.is_some() {
-            let s1 = game.seed_reveal1.as_ref().unwrap().clone();
-            let s2 = game.seed_reveal2.as_ref().unwrap().clone();
+        if let (Some(s1), Some(s2)) = (game.seed_reveal1.clone(), game.seed_reveal2.clone()) {
             let mut combined = [0u8; 64];
             combined[0..32].copy_from_slice(&s1.to_array());
             combined[32..64].copy_from_slice(&s2.to_array());
@@ -1026,18 +4128,19 @@ impl PokerZkContract {
             let b2 = Bytes::from_slice(&env, &second);
             let h1 = env.crypto().sha256(&b1);
             let h2 = env.crypto().sha256(&b2);
+            let (a1, a2) = (h1.to_array(), h2.to_array());
             let mut xor_seed = [0u8; 32];
-            for i in 0..32 {
-                xor_seed[i] = h1.to_array()[i] ^ h2.to_array()[i];
+            for (i, byte) in xor_seed.iter_mut().enumerate() {
+                *byte = a1[i] ^ a2[i];
             }
-            game.final_seed = Some(BytesN::from_array(&env, &xor_seed));
-            game.board = derive_board_cards(&env, game.final_seed.as_ref().unwrap());
+            let final_seed = BytesN::from_array(&env, &xor_seed);
+            game.board = derive_board_cards(&env, &final_seed);
+            game.final_seed = Some(final_seed);
             game.state = GameState::DealCards;
         }
         game.last_action_at = env.ledger().timestamp();
         save_game(&env, game_id, &game);
-        env.events()
-            .publish((symbol_short!("REVEAL"), game_id), player);
+        publish(&env, (symbol_short!("REVEAL"), game_id), player);
         Ok(())
     }
 
@@ -1046,23 +4149,17 @@ impl PokerZkContract {
         if game.state != GameState::DealCards {
             return Err(Error::InvalidState);
         }
-        let config = load_config(&env)?;
-        game.pot += config.small_blind + config.big_blind;
-        if game.dealer_position == 0 {
-            game.current_bet_p1 = config.small_blind;
-            game.current_bet_p2 = config.big_blind;
-            game.total_bet_p1 = config.small_blind;
-            game.total_bet_p2 = config.big_blind;
-            game.actor = 1u32;
-        } else {
-            game.current_bet_p1 = config.big_blind;
-            game.current_bet_p2 = config.small_blind;
-            game.total_bet_p1 = config.big_blind;
-            game.total_bet_p2 = config.small_blind;
-            game.actor = 0u32;
-        }
-        game.min_raise = config.big_blind;
-        game.last_raise_amount = config.big_blind;
+        // Blinds come from the game (its table, or the tournament level it was seated at).
+        // A player short of a blind posts what they have and is all-in.
+        let (small_blind, big_blind) = (game.small_blind, game.big_blind);
+        let sb_is_p1 = game.dealer_position == 0;
+        let sb = small_blind.min(stack_of(&game, sb_is_p1));
+        let bb = big_blind.min(stack_of(&game, !sb_is_p1));
+        put_chips(&mut game, sb_is_p1, sb)?;
+        put_chips(&mut game, !sb_is_p1, bb)?;
+        game.actor = if sb_is_p1 { 1u32 } else { 0u32 };
+        game.min_raise = big_blind;
+        game.last_raise_amount = big_blind;
         game.state = GameState::PreFlop;
         game.last_action_at = env.ledger().timestamp();
         save_game(&env, game_id, &game);
@@ -1080,54 +4177,51 @@ impl PokerZkContract {
     ) -> Result<(), Error> {
         player.require_auth();
         let mut game = load_game(&env, game_id)?;
-        let betting_states = [
-            GameState::PreFlop,
-            GameState::FlopBetting,
-            GameState::TurnBetting,
-            GameState::RiverBetting,
-        ];
-        if !betting_states.contains(&game.state) {
+        if !is_betting_round(game.state) {
             return Err(Error::InvalidState);
         }
         if game.folded.is_some() {
             return Err(Error::GameAlreadyDecided);
         }
-        if !is_current_actor(&game, &player) {
+        if !is_current_actor(&game, &player)? {
             return Err(Error::NotYourTurn);
         }
 
-        let (current_bet_self, current_bet_other) = if player == game.player1 {
+        let is_p1 = player == game.player1;
+        // Time spent past the betting timeout comes out of the player's timebank.
+        let config = load_config(&env)?;
+        let overage = env
+            .ledger()
+            .timestamp()
+            .saturating_sub(
+                game.last_action_at
+                    .saturating_add(phase_timeout(&env, &game, &config)?),
+            );
+        if is_p1 {
+            game.timebank1 = game.timebank1.saturating_sub(overage);
+        } else {
+            game.timebank2 = game.timebank2.saturating_sub(overage);
+        }
+        let (current_bet_self, current_bet_other) = if is_p1 {
             (game.current_bet_p1, game.current_bet_p2)
         } else {
             (game.current_bet_p2, game.current_bet_p1)
         };
-        let to_call = current_bet_other - current_bet_self;
+        let to_call = chips_sub(current_bet_other, current_bet_self)?;
 
         match action {
             Action::Fold => {
                 game.folded = Some(player.clone());
                 let winner = if player == game.player1 {
-                    game.player2.clone().unwrap()
+                    opponent(&game)?
                 } else {
                     game.player1.clone()
                 };
-                game.winner = Some(winner.clone());
+                game.winner = Some(winner);
                 game.state = GameState::Finished;
-                save_game(&env, game_id, &game);
-                payout_game(env.clone(), &game)?;
-                if game.player2.is_some() {
-                    reset_game_for_next_hand(&env, game_id)?;
-                } else {
-                    let hub_addr: Address = env
-                        .storage()
-                        .instance()
-                        .get(&DataKey::GameHubAddress)
-                        .unwrap();
-                    let hub = GameHubClient::new(&env, &hub_addr);
-                    hub.end_game(&(game_id as u32), &(winner == game.player1));
-                }
-                env.events()
-                    .publish((symbol_short!("FOLD"), game_id), player);
+                game.last_action_at = env.ledger().timestamp();
+                settle_hand(&env, game_id, game)?;
+                publish(&env, (symbol_short!("FOLD"), game_id), player);
                 return Ok(());
             }
             Action::Check => {
@@ -1146,39 +4240,33 @@ impl PokerZkContract {
                         game.hand_commitment2 = Some(c.clone());
                     }
                 }
-                game.pot += to_call;
-                if player == game.player1 {
-                    game.current_bet_p1 += to_call;
-                    game.total_bet_p1 += to_call;
-                } else {
-                    game.current_bet_p2 += to_call;
-                    game.total_bet_p2 += to_call;
+                // Calling for less than the bet is all-in; the uncalled part goes back.
+                let paid = to_call.min(stack_of(&game, is_p1));
+                put_chips(&mut game, is_p1, paid)?;
+                if paid < to_call {
+                    return_uncalled(&mut game, !is_p1, to_call - paid)?;
                 }
             }
             Action::Raise => {
-                let min_raise_total = current_bet_other + game.min_raise;
+                let min_raise_total = chips_add(current_bet_other, game.min_raise)?;
                 if raise_amount < min_raise_total {
                     return Err(Error::RaiseTooSmall);
                 }
-                let add = raise_amount - current_bet_self;
+                let add = chips_sub(raise_amount, current_bet_self)?;
                 if add <= 0 {
                     return Err(Error::InvalidAmount);
                 }
+                if add > stack_of(&game, is_p1) {
+                    return Err(Error::InsufficientChips);
+                }
                 let c = commitment.ok_or(Error::InvalidCommitment)?;
                 if player == game.player1 {
                     game.hand_commitment1 = Some(c);
                 } else {
                     game.hand_commitment2 = Some(c);
                 }
-                game.pot += add;
-                if player == game.player1 {
-                    game.current_bet_p1 = raise_amount;
-                    game.total_bet_p1 += add;
-                } else {
-                    game.current_bet_p2 = raise_amount;
-                    game.total_bet_p2 += add;
-                }
-                game.last_raise_amount = raise_amount - current_bet_other;
+                put_chips(&mut game, is_p1, add)?;
+                game.last_raise_amount = chips_sub(raise_amount, current_bet_other)?;
                 game.min_raise = game.last_raise_amount;
             }
         }
@@ -1186,15 +4274,10 @@ impl PokerZkContract {
         game.actor = 1u32 - game.actor;
         game.last_action_at = env.ledger().timestamp();
         if game.current_bet_p1 == game.current_bet_p2 {
-            game.current_bet_p1 = 0;
-            game.current_bet_p2 = 0;
-            game.min_raise = game.big_blind;
-            game.last_raise_amount = game.big_blind;
-            game.state = advance_betting_round(&mut game);
+            close_betting_round(&mut game);
         }
         save_game(&env, game_id, &game);
-        env.events()
-            .publish((symbol_short!("ACT"), game_id, action as u32), player);
+        publish(&env, (symbol_short!("ACT"), game_id, action as u32), player);
         Ok(())
     }
 
@@ -1218,7 +4301,7 @@ impl PokerZkContract {
         if hole_cards.len() != 2 {
             return Err(Error::InvalidCards);
         }
-        if claimed_rank < 1 || claimed_rank > 10 {
+        if !(1..=10).contains(&claimed_rank) {
             return Err(Error::InvalidRank);
         }
 
@@ -1268,46 +4351,36 @@ impl PokerZkContract {
             game.hand_rank2 = Some(claimed_rank);
         }
 
-        if game.hand_rank1.is_some() && game.hand_rank2.is_some() {
-            let r1 = game.hand_rank1.unwrap();
-            let r2 = game.hand_rank2.unwrap();
+        if let (Some(r1), Some(r2)) = (game.hand_rank1, game.hand_rank2) {
             game.winner = Some(if r1 > r2 {
                 game.player1.clone()
             } else if r2 > r1 {
-                game.player2.clone().unwrap()
+                opponent(&game)?
             } else {
                 game.player1.clone()
             });
             game.state = GameState::Finished;
-            save_game(&env, game_id, &game);
-            payout_game(env.clone(), &game)?;
-            if game.player2.is_some() {
-                reset_game_for_next_hand(&env, game_id)?;
-                env.events()
-                    .publish((symbol_short!("REVEAL"), game_id, claimed_rank), player);
-                return Ok(());
-            }
-            let hub_addr: Address = env
-                .storage()
-                .instance()
-                .get(&DataKey::GameHubAddress)
-                .unwrap();
-            let hub = GameHubClient::new(&env, &hub_addr);
-            let player1_won = game.winner.as_ref() == Some(&game.player1);
-            hub.end_game(&(game_id as u32), &player1_won);
+            game.last_action_at = env.ledger().timestamp();
+            settle_hand(&env, game_id, game)?;
+            publish(&env, (symbol_short!("REVEAL"), game_id, claimed_rank), player);
+            return Ok(());
         }
         game.last_action_at = env.ledger().timestamp();
         save_game(&env, game_id, &game);
-        env.events()
-            .publish((symbol_short!("REVEAL"), game_id, claimed_rank), player);
+        publish(&env, (symbol_short!("REVEAL"), game_id, claimed_rank), player);
         Ok(())
     }
 
     /// Quando o tempo de resposta do jogador acaba, o jogo avança automaticamente:
     /// - Em apostas: se não há aposta a pagar → CHECK automático; senão → FOLD automático. O jogo continua.
-    /// - Em commit/reveal ou showdown: o jogador que não agiu perde a mão (outro ganha).
+    /// - Em commit/reveal: o jogo é cancelado e os stakes devolvidos (config.abandon_policy pode
+    ///   aplicar uma multa a quem abandonou). Em mesas de torneio quem abandonou perde o stack.
+    /// - Em showdown: o jogador que não agiu perde a mão (outro ganha).
     /// Qualquer um pode chamar (ex.: adversário ou frontend) para o jogo não ficar travado.
-    pub fn advance_timeout(env: Env, game_id: u64) -> Result<(), Error> {
+    /// Se houver KeeperBounty configurado, `caller` recebe o bounty, exceto se for um dos
+    /// endereços da mesa (verificação de melhor esforço: uma segunda conta recebe como keeper).
+    pub fn advance_timeout(env: Env, caller: Address, game_id: u64) -> Result<(), Error> {
+        caller.require_auth();
         let mut game = load_game(&env, game_id)?;
         if game.state == GameState::Finished {
             return Err(Error::GameAlreadyFinished);
@@ -1317,102 +4390,77 @@ impl PokerZkContract {
         }
         let config = load_config(&env)?;
         let now = env.ledger().timestamp();
-        let timeout = match game.state {
-            GameState::ShuffleCommit | GameState::ShuffleReveal => config.reveal_timeout,
-            GameState::PreFlop
-            | GameState::FlopBetting
-            | GameState::TurnBetting
-            | GameState::RiverBetting
-            | GameState::Showdown => config.bet_timeout,
-            _ => return Err(Error::NoTimeoutApplicable),
-        };
-        if now <= game.last_action_at + timeout {
+        // In betting rounds the player to act first burns through their timebank.
+        let timeout = phase_timeout(&env, &game, &config)?.saturating_add(actor_timebank(&game));
+        if now <= game.last_action_at.saturating_add(timeout) {
             return Err(Error::TimeoutNotReached);
         }
         let non_acting = determine_non_acting_player(&game)?;
+        pay_keeper_bounty(&env, &mut game, &caller, &non_acting)?;
 
         match game.state {
+            GameState::ShuffleCommit | GameState::ShuffleReveal
+                if load_tournament_game(&env, game_id).is_none() =>
+            {
+                // Nenhuma carta foi dada: o jogo é cancelado conforme config.abandon_policy.
+                abandon_game(&env, game, &non_acting)?;
+                publish(&env, (symbol_short!("TIMEOUT"), game_id), non_acting);
+            }
             GameState::ShuffleCommit | GameState::ShuffleReveal | GameState::Showdown => {
                 // Sem equivalente a "check"; quem não agiu perde a mão.
                 let winner = if non_acting == game.player1 {
-                    game.player2.clone().unwrap()
+                    opponent(&game)?
                 } else {
                     game.player1.clone()
                 };
-                game.winner = Some(winner.clone());
+                game.winner = Some(winner);
                 game.state = if game.state == GameState::Showdown {
                     GameState::Finished
                 } else {
                     GameState::Cancelled
                 };
                 game.last_action_at = now;
-                save_game(&env, game_id, &game);
-                payout_game(env.clone(), &game)?;
-                if game.player2.is_some() && game.state == GameState::Finished {
-                    reset_game_for_next_hand(&env, game_id)?;
-                } else {
-                    let hub_addr: Address = env
-                        .storage()
-                        .instance()
-                        .get(&DataKey::GameHubAddress)
-                        .unwrap();
-                    let hub = GameHubClient::new(&env, &hub_addr);
-                    hub.end_game(&(game_id as u32), &(winner == game.player1));
-                }
-                env.events()
-                    .publish((symbol_short!("TIMEOUT"), game_id), non_acting);
+                settle_hand(&env, game_id, game)?;
+                publish(&env, (symbol_short!("TIMEOUT"), game_id), non_acting);
             }
             GameState::PreFlop
             | GameState::FlopBetting
             | GameState::TurnBetting
             | GameState::RiverBetting => {
+                if non_acting == game.player1 {
+                    game.timebank1 = 0;
+                } else {
+                    game.timebank2 = 0;
+                }
                 let (current_bet_self, current_bet_other) = if non_acting == game.player1 {
                     (game.current_bet_p1, game.current_bet_p2)
                 } else {
                     (game.current_bet_p2, game.current_bet_p1)
                 };
-                let to_call = current_bet_other - current_bet_self;
+                let to_call = chips_sub(current_bet_other, current_bet_self)?;
 
                 if to_call == 0 {
                     // Auto-Check: passa a vez e avança a ronda se as apostas estiverem igualadas.
                     game.actor = 1u32 - game.actor;
                     game.last_action_at = now;
                     if game.current_bet_p1 == game.current_bet_p2 {
-                        game.current_bet_p1 = 0;
-                        game.current_bet_p2 = 0;
-                        game.min_raise = game.big_blind;
-                        game.last_raise_amount = game.big_blind;
-                        game.state = advance_betting_round(&mut game);
+                        close_betting_round(&mut game);
                     }
                     save_game(&env, game_id, &game);
-                    env.events()
-                        .publish((symbol_short!("TMOUT_CHK"), game_id), non_acting);
+                    publish(&env, (symbol_short!("TMOUT_CHK"), game_id), non_acting);
                 } else {
                     // Auto-Fold: quem não respondeu perde a mão.
                     game.folded = Some(non_acting.clone());
                     let winner = if non_acting == game.player1 {
-                        game.player2.clone().unwrap()
+                        opponent(&game)?
                     } else {
                         game.player1.clone()
                     };
-                    game.winner = Some(winner.clone());
+                    game.winner = Some(winner);
                     game.state = GameState::Finished;
                     game.last_action_at = now;
-                    save_game(&env, game_id, &game);
-                    payout_game(env.clone(), &game)?;
-                    if game.player2.is_some() {
-                        reset_game_for_next_hand(&env, game_id)?;
-                    } else {
-                        let hub_addr: Address = env
-                            .storage()
-                            .instance()
-                            .get(&DataKey::GameHubAddress)
-                            .unwrap();
-                        let hub = GameHubClient::new(&env, &hub_addr);
-                        hub.end_game(&(game_id as u32), &(winner == game.player1));
-                    }
-                    env.events()
-                        .publish((symbol_short!("TMOUT_F"), game_id), non_acting);
+                    settle_hand(&env, game_id, game)?;
+                    publish(&env, (symbol_short!("TMOUT_F"), game_id), non_acting);
                 }
             }
             _ => {}
@@ -1421,9 +4469,96 @@ impl PokerZkContract {
     }
 
     /// [Deprecated] Use advance_timeout. Reclamar timeout (jogador inativo perde). Mantido por compatibilidade.
-    pub fn claim_timeout(env: Env, _caller: Address, game_id: u64) -> Result<(), Error> {
-        _caller.require_auth();
-        Self::advance_timeout(env, game_id)
+    pub fn claim_timeout(env: Env, caller: Address, game_id: u64) -> Result<(), Error> {
+        Self::advance_timeout(env, caller, game_id)
+    }
+
+    /// Who must act now, their deadline and remaining timebank.
+    pub fn get_turn_clock(env: Env, game_id: u64) -> Result<TurnClock, Error> {
+        let game = load_game(&env, game_id)?;
+        let config = load_config(&env)?;
+        let base_deadline = game
+            .last_action_at
+            .saturating_add(phase_timeout(&env, &game, &config)?);
+        let player = determine_non_acting_player(&game)?;
+        let bank = actor_timebank(&game);
+        let used = env.ledger().timestamp().saturating_sub(base_deadline);
+        Ok(TurnClock {
+            player,
+            deadline: base_deadline.saturating_add(bank),
+            timebank: bank.saturating_sub(used),
+        })
+    }
+
+    /// Extend the storage of a game (and its archived summary) to the full game TTL. Anyone can
+    /// pay for it; active games are also extended whenever they are read or written.
+    pub fn bump_game(env: Env, game_id: u64) -> Result<(), Error> {
+        let storage = env.storage().temporary();
+        // A hand without its session (or the reverse) is a lost game, not a live one.
+        let split = [DataKey::GameHand(game_id), DataKey::GameSession(game_id)];
+        let mut keys = Vec::new(&env);
+        if split.iter().all(|key| storage.has(key)) {
+            keys.extend_from_array(split);
+        }
+        if let Some(key) = legacy_game_key(&env, game_id) {
+            keys.push_back(key);
+        }
+        let found = !keys.is_empty();
+        for key in keys.iter() {
+            storage.extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+        // Its lobby index entries are only written when the game starts; keep them alive too.
+        let persistent = env.storage().persistent();
+        let active_key = DataKey::ActiveGame(game_id);
+        if let Some((_, slot)) = persistent.get::<_, (u64, u32)>(&active_key) {
+            persistent.extend_ttl(&active_key, TTL_THRESHOLD, GAME_TTL_LEDGERS);
+            persistent.extend_ttl(&DataKey::ActiveSlot(slot), TTL_THRESHOLD, GAME_TTL_LEDGERS);
+        }
+        let archive_key = DataKey::Archive(game_id);
+        let has_archive = env.storage().persistent().has(&archive_key);
+        if !found && !has_archive {
+            return Err(missing_game_error(&env, game_id));
+        }
+        if has_archive {
+            env.storage()
+                .persistent()
+                .extend_ttl(&archive_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+        Ok(())
+    }
+
+    /// Close a finished or cancelled game: its archived summary stays and the full entry is
+    /// deleted, so no rent is paid on it. get_game keeps answering from the summary. Anyone
+    /// can close a settled game.
+    pub fn close_game(env: Env, game_id: u64) -> Result<(), Error> {
+        let game = stored_game(&env, game_id).ok_or_else(|| missing_game_error(&env, game_id))?;
+        if !is_over(game.state) {
+            return Err(Error::InvalidState);
+        }
+        if !env.storage().persistent().has(&DataKey::Archive(game_id)) {
+            archive_game(&env, &game);
+            unindex_game(&env, game_id, &game);
+        }
+        let storage = env.storage().temporary();
+        storage.remove(&DataKey::GameHand(game_id));
+        storage.remove(&DataKey::GameSession(game_id));
+        storage.remove(&DataKey::Game(game_id));
+        if let Some(legacy) = legacy_session_key(game_id) {
+            if storage.has(&legacy) {
+                storage.remove(&legacy);
+                storage.remove(&DataKey::Game(game_id - SESSION_ID_BASE));
+            }
+        }
+        publish(&env, (symbol_short!("CLOSE"), game_id), game.state);
+        Ok(())
+    }
+
+    /// Summary of a game: the archived record once it is over, otherwise built from the game.
+    pub fn get_game_summary(env: Env, game_id: u64) -> Result<GameSummary, Error> {
+        if let Some(summary) = env.storage().persistent().get(&DataKey::Archive(game_id)) {
+            return Ok(summary);
+        }
+        load_game(&env, game_id).map(|game| summarize(&game))
     }
 
     pub fn get_game(env: Env, game_id: u64) -> Result<Game, Error> {
@@ -1434,58 +4569,154 @@ impl PokerZkContract {
         load_config(&env)
     }
 
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+    /// The owner.
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        load_admin(&env)
+    }
+
+    /// Start handing the contract to `new_owner` (owner only); None withdraws the proposal.
+    /// Nothing changes until the new owner calls accept_owner.
+    pub fn propose_owner(env: Env, new_owner: Option<Address>) -> Result<(), Error> {
+        require_admin(&env)?;
+        match new_owner {
+            Some(ref o) => env.storage().instance().set(&DataKey::PendingOwner, o),
+            None => env.storage().instance().remove(&DataKey::PendingOwner),
+        }
+        publish(&env, (symbol_short!("OWN_PROP"),), new_owner);
+        Ok(())
     }
 
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
+    /// The proposed owner takes over.
+    pub fn accept_owner(env: Env) -> Result<(), Error> {
+        let pending: Address = env
             .storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+            .get(&DataKey::PendingOwner)
+            .ok_or(Error::NoPendingOwner)?;
+        pending.require_auth();
+        let previous = load_admin(&env)?;
+        env.storage().instance().set(&DataKey::Admin, &pending);
+        env.storage().instance().remove(&DataKey::PendingOwner);
+        publish(&env, (symbol_short!("OWN_XFER"),), (previous, pending));
+        Ok(())
     }
 
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub not set")
+    pub fn get_pending_owner(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingOwner)
     }
 
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+    /// Assign (Some) or clear (None) a role (owner only).
+    pub fn set_role(env: Env, role: Role, account: Option<Address>) -> Result<(), Error> {
+        require_admin(&env)?;
+        let key = DataKey::Role(role);
+        match account {
+            Some(ref a) => env.storage().instance().set(&key, a),
+            None => env.storage().instance().remove(&key),
+        }
+        publish(&env, (symbol_short!("ROLE"), role), account);
+        Ok(())
+    }
+
+    /// Who holds a role (the owner when unassigned).
+    pub fn get_role(env: Env, role: Role) -> Result<Address, Error> {
+        role_holder(&env, role)
+    }
+
+    pub fn get_hub(env: Env) -> Result<Address, Error> {
+        load_hub(&env)
+    }
+
+    pub fn set_hub(env: Env, new_hub: Address) -> Result<(), Error> {
+        require_admin(&env)?;
         env.storage()
             .instance()
             .set(&DataKey::GameHubAddress, &new_hub);
+        Ok(())
     }
 
-    /// Set or clear the payment controller (escrow). Admin only. Enables lock_funds on sit/create/join and payout_winner on game end.
-    pub fn set_payment_controller(env: Env, admin: Address, payment_controller: Option<Address>) {
+    /// Set or clear the payment controller (escrow). Owner only. Enables lock_funds on sit/create/join and payout_winner on game end.
+    pub fn set_payment_controller(
+        env: Env,
+        admin: Address,
+        payment_controller: Option<Address>,
+    ) -> Result<(), Error> {
+        if admin != load_admin(&env)? {
+            return Err(Error::Unauthorized);
+        }
         admin.require_auth();
-        let mut config = load_config(&env).expect("Config not set");
+        let mut config = load_config(&env)?;
         config.payment_controller = payment_controller;
         save_config(&env, &config);
+        Ok(())
     }
 
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+    /// Replace the contract code (owner only). If the new code has a higher SCHEMA_VERSION, call
+    /// migrate until version() reports it.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        require_admin(&env)?;
         env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
+    /// Storage layout in use: SCHEMA_VERSION once every entry has been migrated.
+    pub fn version(env: Env) -> u32 {
+        schema_version(&env)
+    }
+
+    /// Owner: rewrite entries stored in an older layout, handling at most `limit` table ids, game
+    /// ids and sessions per call (capped at 100) so each call fits in a transaction. The config
+    /// is rewritten by the first call. Sequential games are found by scanning their ids;
+    /// start_game sessions cannot be, so pass their session ids in `session_ids` (on any call
+    /// before the step ends; repeats are ignored). Returns how many ids and sessions are left;
+    /// the stored version moves up once that reaches 0. Until then old entries are still read
+    /// as they are, and any write to a game also moves it.
+    pub fn migrate(env: Env, limit: u32, session_ids: Vec<u64>) -> Result<u64, Error> {
+        require_admin(&env)?;
+        let mut version = schema_version(&env);
+        if version > SCHEMA_VERSION {
+            return Err(Error::SchemaTooNew);
+        }
+        if version == SCHEMA_VERSION {
+            return Ok(0);
+        }
+        let storage = env.storage().instance();
+        if config_is_v1(&env) {
+            if let Ok(config) = load_config(&env) {
+                save_config(&env, &config);
+            }
+        }
+        let mut progress = storage
+            .get(&DataKey::MigrationCursor)
+            .unwrap_or(MigrationProgress {
+                table_id: 0,
+                game_id: 0,
+                session_ids: Vec::new(&env),
+            });
+        for session_id in session_ids.iter() {
+            if !progress.session_ids.contains(session_id) {
+                progress.session_ids.push_back(session_id);
+            }
+        }
+        migrate_batch(&env, version, &mut progress, limit.min(MAX_MIGRATE_BATCH));
+        let table_count: u64 = storage.get(&DataKey::NextTableId).unwrap_or(0);
+        let game_count: u64 = storage.get(&DataKey::NextGameId).unwrap_or(0);
+        let left = table_count.saturating_sub(progress.table_id)
+            + game_count.saturating_sub(progress.game_id)
+            + progress.session_ids.len() as u64;
+        if left > 0 {
+            storage.set(&DataKey::MigrationCursor, &progress);
+            return Ok(left);
+        }
+        storage.remove(&DataKey::MigrationCursor);
+        version += 1;
+        storage.set(&DataKey::SchemaVersion, &version);
+        publish(&env, (symbol_short!("SCHEMA"),), version);
+        // The next step, if any, starts on the next call.
+        Ok(if version < SCHEMA_VERSION {
+            table_count + game_count
+        } else {
+            0
+        })
     }
 
     /// Send an on-chain chat message for a game.
@@ -1496,7 +4727,8 @@ impl PokerZkContract {
         if message.len() > 280 {
             return Err(Error::MessageTooLong);
         }
-        env.events().publish(
+        publish(
+            &env,
             (symbol_short!("CHAT"), game_id),
             (sender, message),
         );
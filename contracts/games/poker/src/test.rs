@@ -1,8 +1,15 @@
 #![cfg(test)]
+extern crate std;
 
-use crate::{Action, GameConfig, GameState, PokerZkContract, PokerZkContractClient};
+use crate::{
+    AbandonPolicy, Action, BlindLevel, BountySource, DataKey, Error, GameConfig, GameConfigV1, GameState, GameV1, Invite, KeeperBounty, GameSession, HandState, MatchFormat, PlayerGames, RematchOffer, Role, TableAccess, TableMeta, TableStatus, PokerZkContract, WaitingSession,
+    PokerZkContractClient, TimeoutConfig, TournamentConfig, TournamentState, SCHEMA_VERSION, SESSION_ID_BASE,
+    hub_session_id, HUB_SEQUENTIAL_BASE, MAX_ALLOWLIST, MAX_TIMEOUT,
+};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, vec, Address, Bytes, BytesN, Env, String, Vec,
+};
 
 #[contract]
 pub struct MockGameHub;
@@ -19,8 +26,52 @@ impl MockGameHub {
         _player2_points: i128,
     ) {
     }
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
+    /// Records the result so tests can check how a session ended.
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
+        env.storage()
+            .instance()
+            .set(&MockKey::Ended(session_id), &player1_won);
     }
+    pub fn ended(env: Env, session_id: u32) -> Option<bool> {
+        env.storage().instance().get(&MockKey::Ended(session_id))
+    }
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum MockKey {
+    Ended(u32),
+    Locked(Address),
+    Paid(Address),
+    Refunded(Address),
+    Rake,
+}
+
+/// Escrow stand-in: tracks what each player locked, was paid and was refunded.
+#[contract]
+pub struct MockPaymentController;
+
+#[contractimpl]
+impl MockPaymentController {
+    pub fn lock_funds(env: Env, player: Address, amount: i128) {
+        player.require_auth();
+        add(&env, MockKey::Locked(player), amount);
+    }
+    pub fn payout_winner(env: Env, winner: Address, payout: i128, rake: i128) {
+        add(&env, MockKey::Paid(winner), payout);
+        add(&env, MockKey::Rake, rake);
+    }
+    pub fn refund_on_timeout(env: Env, player: Address, amount: i128) {
+        add(&env, MockKey::Refunded(player), amount);
+    }
+    pub fn get(env: Env, key: MockKey) -> i128 {
+        env.storage().instance().get(&key).unwrap_or(0)
+    }
+}
+
+fn add(env: &Env, key: MockKey, amount: i128) {
+    let value: i128 = env.storage().instance().get(&key).unwrap_or(0);
+    env.storage().instance().set(&key, &(value + amount));
 }
 
 fn setup_test() -> (
@@ -67,12 +118,15 @@ fn setup_test() -> (
         reveal_timeout: 3600,
         bet_timeout: 300,
         waiting_timeout: 3600,
+        timebank: 60,
+        timebank_refill: 10,
+        abandon_policy: AbandonPolicy::Refund,
         treasury,
         game_hub: hub_addr.clone(),
         payment_controller: None,
         proof_verifier: None,
     };
-    client.initialize(&admin, &config);
+    client.set_config(&config);
     let player1 = Address::generate(&env);
     let player2 = Address::generate(&env);
     let hub_client = MockGameHubClient::new(&env, &hub_addr);
@@ -93,13 +147,15 @@ fn test_create_and_join_game() {
     assert_eq!(game.state, GameState::WaitingForPlayers);
     assert_eq!(game.player1, player1);
     assert!(game.player2.is_none());
-    assert_eq!(game.pot, buy_in);
+    assert_eq!(game.stack1, buy_in);
+    assert_eq!(game.pot, 0);
 
     client.join_game(&player2, &game_id);
     let game2 = client.get_game(&game_id);
     assert_eq!(game2.state, GameState::ShuffleCommit);
     assert_eq!(game2.player2, Some(player2));
-    assert_eq!(game2.pot, buy_in + buy_in);
+    assert_eq!(game2.stack2, buy_in);
+    assert_eq!(game2.pot, 0);
 }
 
 #[test]
@@ -147,14 +203,20 @@ fn test_post_blinds_and_fold() {
     assert!(game.current_bet_p1 > 0 || game.current_bet_p2 > 0);
 
     client.act(&player2, &game_id, &Action::Fold, &0, &None, &None);
+    // Heads-up session: the hand is settled and the next one is dealt with the button moved.
     let game2 = client.get_game(&game_id);
-    assert_eq!(game2.state, GameState::Finished);
-    assert_eq!(game2.winner, Some(player1));
+    assert_eq!(game2.state, GameState::ShuffleCommit);
+    assert_eq!(game2.dealer_position, 1);
+    assert!(game2.winner.is_none());
+    // Player1 posted the small blind (5) and won the 15 chip pot.
+    assert_eq!(game2.stack1, buy_in + 10);
+    assert_eq!(game2.stack2, buy_in - 10);
+    assert_eq!(game2.pot, 0);
 }
 
 #[test]
 fn test_sit_at_table_waiting_then_start() {
-    let (env, client, _hub, player1, player2) = setup_test();
+    let (_env, client, _hub, player1, player2) = setup_test();
     let buy_in = 1000i128;
     let table_id = 0u64;
 
@@ -168,13 +230,12 @@ fn test_sit_at_table_waiting_then_start() {
 
     let r2 = client.sit_at_table(&player2, &table_id, &buy_in);
     assert!(!r2.waiting);
-    let game_id = r2.game_id;
-
     let game = client.get_game(&r2.game_id);
     assert_eq!(game.state, GameState::ShuffleCommit);
     assert_eq!(game.player1, player1);
     assert_eq!(game.player2, Some(player2));
-    assert_eq!(game.pot, buy_in + buy_in);
+    assert_eq!(game.stack1, buy_in);
+    assert_eq!(game.stack2, buy_in);
 
     // After 2nd sit, waiting is cleared and game started; get_table_waiting would return NoWaitingSession
 }
@@ -246,3 +307,1820 @@ fn test_set_payment_controller() {
     let cfg_after = client.get_config();
     assert!(cfg_after.payment_controller.is_none());
 }
+
+fn set_timestamp(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+fn shuffle(env: &Env, client: &PokerZkContractClient, game_id: u64) {
+    let game = client.get_game(&game_id);
+    let player2 = game.player2.clone().unwrap();
+    let seed1 = BytesN::from_array(env, &[7u8; 32]);
+    let seed2 = BytesN::from_array(env, &[8u8; 32]);
+    client.commit_seed(&game.player1, &game_id, &seed_commit(env, &seed1));
+    client.commit_seed(&player2, &game_id, &seed_commit(env, &seed2));
+    client.reveal_seed(&game.player1, &game_id, &seed1);
+    client.reveal_seed(&player2, &game_id, &seed2);
+}
+
+/// Shuffle, post blinds and let the first actor (player2 when player1 has the button) fold.
+fn play_hand_to_fold(env: &Env, client: &PokerZkContractClient, game_id: u64) {
+    shuffle(env, client, game_id);
+    client.post_blinds(&game_id);
+    let game = client.get_game(&game_id);
+    let actor = if game.actor == 0 { game.player1 } else { game.player2.unwrap() };
+    client.act(&actor, &game_id, &Action::Fold, &0, &None, &None);
+}
+
+fn hole_cards_commit(env: &Env, cards: &Vec<u32>) -> BytesN<32> {
+    let mut arr = [0u8; 32];
+    arr[0] = cards.get(0).unwrap() as u8;
+    arr[1] = cards.get(1).unwrap() as u8;
+    env.crypto().sha256(&Bytes::from_slice(env, &arr)).to_bytes()
+}
+
+/// First actor shoves, the other calls, and `winner` shows the better hand at showdown.
+fn play_all_in_hand(env: &Env, client: &PokerZkContractClient, game_id: u64, winner: &Address) {
+    shuffle(env, client, game_id);
+    client.post_blinds(&game_id);
+    let game = client.get_game(&game_id);
+    let player2 = game.player2.clone().unwrap();
+    let (first, second, shove) = if game.actor == 0 {
+        (game.player1.clone(), player2, game.stack1 + game.current_bet_p1)
+    } else {
+        (player2, game.player1.clone(), game.stack2 + game.current_bet_p2)
+    };
+    let cards = vec![env, 3u32, 4u32];
+    let commitment = Some(hole_cards_commit(env, &cards));
+    client.act(&first, &game_id, &Action::Raise, &shove, &None, &commitment);
+    client.act(&second, &game_id, &Action::Call, &0, &None, &commitment);
+    assert_eq!(client.get_game(&game_id).state, GameState::Showdown);
+    let proof = BytesN::from_array(env, &[0u8; 256]);
+    for player in [&first, &second] {
+        let rank = if player == winner { 9u32 } else { 2u32 };
+        client.reveal_hand(player, &game_id, &cards, &rank, &proof);
+    }
+}
+
+fn with_payment_controller(
+    env: &Env,
+    client: &PokerZkContractClient,
+) -> MockPaymentControllerClient<'static> {
+    let pc_addr = env.register(MockPaymentController, ());
+    client.set_payment_controller(&client.get_admin(), &Some(pc_addr.clone()));
+    MockPaymentControllerClient::new(env, &pc_addr)
+}
+
+#[test]
+fn test_leave_game_pays_out_stacks_and_ends_session() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    client.sit_at_table(&player1, &0u64, &1000);
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+    play_hand_to_fold(&env, &client, game_id);
+
+    shuffle(&env, &client, game_id);
+    assert_eq!(
+        client.try_leave_game(&player2, &game_id),
+        Err(Ok(Error::HandInProgress))
+    );
+    // Button moved: player1 is the big blind and acts first; a min-raise takes the blinds.
+    client.post_blinds(&game_id);
+    let commitment = Some(hole_cards_commit(&env, &vec![&env, 3u32, 4u32]));
+    client.act(&player1, &game_id, &Action::Raise, &20, &None, &commitment);
+    client.act(&player2, &game_id, &Action::Fold, &0, &None, &None);
+
+    client.leave_game(&player2, &game_id);
+    let game = client.get_game(&game_id);
+    assert_eq!(game.state, GameState::Finished);
+    assert_eq!(game.winner, Some(player1.clone()));
+    assert_eq!((game.stack1, game.stack2), (0, 0));
+    assert_eq!(pc.get(&MockKey::Paid(player1)), 1015);
+    assert_eq!(pc.get(&MockKey::Paid(player2)), 985);
+    assert_eq!(hub.ended(&hub_session_id(game_id)), Some(true));
+}
+
+#[test]
+fn test_leave_before_anyone_joins_refunds() {
+    let (env, client, _hub, player1, _player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    let game_id = client.create_game(&player1, &0u64, &500);
+    client.leave_game(&player1, &game_id);
+    assert_eq!(client.get_game(&game_id).state, GameState::Cancelled);
+    assert_eq!(pc.get(&MockKey::Refunded(player1)), 500);
+}
+
+#[test]
+fn test_rebuy_and_top_up_between_hands() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    client.sit_at_table(&player1, &0u64, &1000);
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+    play_all_in_hand(&env, &client, game_id, &player1);
+
+    // 2% rake on the 2000 pot stays with the session until it settles.
+    let game = client.get_game(&game_id);
+    assert_eq!((game.stack1, game.stack2, game.rake), (1960, 0, 40));
+    let commit = seed_commit(&env, &BytesN::from_array(&env, &[1u8; 32]));
+    assert_eq!(
+        client.try_commit_seed(&player1, &game_id, &commit),
+        Err(Ok(Error::InsufficientChips))
+    );
+    assert_eq!(
+        client.try_rebuy(&player1, &game_id, &100),
+        Err(Ok(Error::StillHasChips))
+    );
+    assert_eq!(
+        client.try_rebuy(&player2, &game_id, &50),
+        Err(Ok(Error::BuyInTooLow))
+    );
+    client.rebuy(&player2, &game_id, &500);
+    assert_eq!(
+        client.try_top_up(&player1, &game_id, &1_000_000),
+        Err(Ok(Error::BuyInTooHigh))
+    );
+    client.top_up(&player1, &game_id, &40);
+
+    let game = client.get_game(&game_id);
+    assert_eq!((game.stack1, game.stack2), (2000, 500));
+    assert_eq!((game.bought_in1, game.bought_in2), (1040, 1500));
+    assert_eq!(pc.get(&MockKey::Locked(player1)), 1040);
+    assert_eq!(pc.get(&MockKey::Locked(player2.clone())), 1500);
+
+    client.leave_game(&player2, &game_id);
+    assert_eq!(pc.get(&MockKey::Paid(player2)), 500);
+    assert_eq!(pc.get(&MockKey::Rake), 40);
+}
+
+fn tournament_config(env: &Env, deadline: u64) -> TournamentConfig {
+    TournamentConfig {
+        table_id: 0,
+        entry_fee: 100,
+        starting_chips: 1_500,
+        registration_deadline: deadline,
+        max_entrants: 8,
+        blind_levels: vec![
+            env,
+            BlindLevel { small_blind: 10, big_blind: 20 },
+            BlindLevel { small_blind: 20, big_blind: 40 },
+        ],
+        level_duration: 600,
+        payouts: vec![env, 7_000u32, 3_000u32],
+    }
+}
+
+#[test]
+fn test_tournament_seats_balances_and_finishes() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    let start = env.ledger().timestamp();
+    let tid = client.create_tournament(&tournament_config(&env, start + 100));
+    for p in [&player1, &player2, &player3] {
+        client.register_tournament(p, &tid);
+    }
+    assert_eq!(
+        client.try_register_tournament(&player1, &tid),
+        Err(Ok(Error::AlreadyRegistered))
+    );
+    assert_eq!(client.try_start_tournament(&tid), Err(Ok(Error::RegistrationOpen)));
+
+    set_timestamp(&env, start + 101);
+    client.start_tournament(&tid);
+    let t = client.get_tournament(&tid);
+    assert_eq!(t.state, TournamentState::Running);
+    assert_eq!(t.remaining, 3);
+    assert_eq!(t.tables.len(), 2);
+    assert_eq!(t.tables.get(1).unwrap().players.len(), 1);
+    let game_id = t.tables.get(0).unwrap().game_id.unwrap();
+    assert_eq!(client.get_game(&game_id).big_blind, 20);
+
+    // Second level: tables seated from now on use 20/40.
+    set_timestamp(&env, start + 101 + 600);
+    assert_eq!(client.get_tournament_level(&tid).big_blind, 40);
+    let survivor = client.get_game(&game_id).player1;
+    play_all_in_hand(&env, &client, game_id, &survivor);
+    assert_eq!(client.get_game(&game_id).state, GameState::Finished);
+
+    // Lone survivors are merged onto the first table.
+    let t = client.get_tournament(&tid);
+    assert_eq!(t.remaining, 2);
+    assert_eq!(t.chips.get(survivor.clone()).unwrap(), 3_000);
+    assert!(t.tables.get(1).unwrap().players.is_empty());
+    let final_table = t.tables.get(0).unwrap();
+    assert_eq!(final_table.players.len(), 2);
+    let final_game = final_table.game_id.unwrap();
+    assert_eq!(client.get_game(&final_game).big_blind, 40);
+    assert_eq!(client.get_tournament_remaining(&tid).len(), 2);
+
+    // The chip leader wins the all-in, busting the other player.
+    let champion = survivor.clone();
+    play_all_in_hand(&env, &client, final_game, &champion);
+    let t = client.get_tournament(&tid);
+    assert_eq!(t.state, TournamentState::Finished);
+    assert_eq!(t.remaining, 1);
+    assert_eq!(t.eliminated.len(), 2);
+    assert_eq!(t.chips.get(champion.clone()).unwrap(), 4_500);
+    assert_eq!(client.get_tournament_remaining(&tid), vec![&env, champion]);
+}
+
+#[test]
+fn test_tournament_winner_takes_unclaimed_shares_and_dust() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    let start = env.ledger().timestamp();
+    let mut config = tournament_config(&env, start + 100);
+    config.entry_fee = 333;
+    config.payouts = vec![&env, 6_000u32, 3_000u32, 1_000u32];
+    let tid = client.create_tournament(&config);
+    client.register_tournament(&player1, &tid);
+    client.register_tournament(&player2, &tid);
+    set_timestamp(&env, start + 101);
+    client.start_tournament(&tid);
+    let game_id = client.get_tournament(&tid).tables.get(0).unwrap().game_id.unwrap();
+    let champion = client.get_game(&game_id).player1;
+    play_all_in_hand(&env, &client, game_id, &champion);
+    assert_eq!(client.get_tournament(&tid).state, TournamentState::Finished);
+
+    // Pool 666, rake 13, prizes from 653: second place 30% (195), nobody third, so the
+    // winner gets the rest, third place's share and the rounding dust included.
+    let runner_up = if champion == player1 { player2 } else { player1 };
+    assert_eq!(pc.get(&MockKey::Rake), 13);
+    assert_eq!(pc.get(&MockKey::Paid(runner_up)), 195);
+    assert_eq!(pc.get(&MockKey::Paid(champion)), 458);
+}
+
+#[test]
+fn test_tournament_table_restarts_after_its_game_expires() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let start = env.ledger().timestamp();
+    let tid = client.create_tournament(&tournament_config(&env, start + 100));
+    client.register_tournament(&player1, &tid);
+    client.register_tournament(&player2, &tid);
+    set_timestamp(&env, start + 101);
+    client.start_tournament(&tid);
+    let lost = client.get_tournament(&tid).tables.get(0).unwrap().game_id.unwrap();
+    assert_eq!(
+        client.try_restart_tournament_table(&tid, &0),
+        Err(Ok(Error::InvalidState))
+    );
+    play_hand_to_fold(&env, &client, lost);
+    let chips = client.get_tournament(&tid).chips;
+
+    // The game's temporary entries expire in the middle of the next hand.
+    env.as_contract(&client.address, || {
+        env.storage().temporary().remove(&DataKey::GameHand(lost));
+        env.storage().temporary().remove(&DataKey::GameSession(lost));
+    });
+    assert_eq!(client.try_get_game(&lost), Err(Ok(Error::GameExpired)));
+    let game_id = client.restart_tournament_table(&tid, &0);
+    assert_ne!(game_id, lost);
+    let game = client.get_game(&game_id);
+    assert_eq!(game.state, GameState::ShuffleCommit);
+    assert_eq!(game.stack1, chips.get(game.player1.clone()).unwrap());
+    assert_eq!(game.stack2, chips.get(game.player2.clone().unwrap()).unwrap());
+    assert!(hub.ended(&hub_session_id(lost)).is_some());
+    let t = client.get_tournament(&tid);
+    assert_eq!(t.tables.get(0).unwrap().game_id, Some(game_id));
+    assert_eq!(t.chips, chips);
+
+    // The new game plays on to the end of the tournament.
+    let champion = game.player1.clone();
+    play_all_in_hand(&env, &client, game_id, &champion);
+    assert_eq!(client.get_tournament(&tid).state, TournamentState::Finished);
+    assert_eq!(
+        client.try_restart_tournament_table(&tid, &0),
+        Err(Ok(Error::InvalidState))
+    );
+}
+
+#[test]
+fn test_tournament_registration_rules() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let start = env.ledger().timestamp();
+    let mut bad = tournament_config(&env, start + 100);
+    bad.payouts = vec![&env, 8_000u32, 3_000u32];
+    assert_eq!(
+        client.try_create_tournament(&bad),
+        Err(Ok(Error::InvalidTournamentConfig))
+    );
+    // Shares whose sum does not fit in a u32 are rejected too.
+    bad.payouts = vec![&env, u32::MAX, 10_001u32];
+    assert_eq!(
+        client.try_create_tournament(&bad),
+        Err(Ok(Error::InvalidTournamentConfig))
+    );
+
+    let tid = client.create_tournament(&tournament_config(&env, start + 100));
+    client.register_tournament(&player1, &tid);
+    set_timestamp(&env, start + 101);
+    assert_eq!(
+        client.try_register_tournament(&player2, &tid),
+        Err(Ok(Error::RegistrationClosed))
+    );
+    // A single entrant cannot play: the tournament is cancelled.
+    client.start_tournament(&tid);
+    assert_eq!(client.get_tournament(&tid).state, TournamentState::Cancelled);
+}
+
+#[test]
+fn test_freezeout_ends_when_one_player_has_all_chips() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    client.set_table_format(&0u64, &MatchFormat::Freezeout);
+    client.sit_at_table(&player1, &0u64, &1000);
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+    assert_eq!(
+        client.try_top_up(&player1, &game_id, &100),
+        Err(Ok(Error::RebuyNotAllowed))
+    );
+    play_all_in_hand(&env, &client, game_id, &player2);
+
+    let game = client.get_game(&game_id);
+    assert_eq!(game.state, GameState::Finished);
+    assert_eq!(game.winner, Some(player2.clone()));
+    assert_eq!(pc.get(&MockKey::Paid(player2)), 1960);
+    assert_eq!(pc.get(&MockKey::Rake), 40);
+    assert_eq!(hub.ended(&hub_session_id(game_id)), Some(false));
+}
+
+#[test]
+fn test_best_of_three_match() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    client.set_table_format(&0u64, &MatchFormat::BestOf(3));
+    let game_id = client.create_game(&player1, &0u64, &1000);
+    client.join_game(&player2, &game_id);
+
+    // The first actor folds each hand, so hands alternate: player1, player2, player1.
+    play_hand_to_fold(&env, &client, game_id);
+    play_hand_to_fold(&env, &client, game_id);
+    let game = client.get_game(&game_id);
+    assert_eq!((game.hands_won1, game.hands_won2), (1, 1));
+    assert_eq!(hub.ended(&hub_session_id(game_id)), None);
+    play_hand_to_fold(&env, &client, game_id);
+
+    let game = client.get_game(&game_id);
+    assert_eq!(game.state, GameState::Finished);
+    assert_eq!(game.winner, Some(player1.clone()));
+    assert_eq!(pc.get(&MockKey::Paid(player1)), 2000);
+    assert_eq!(hub.ended(&hub_session_id(game_id)), Some(true));
+}
+
+#[test]
+fn test_leaving_a_match_forfeits_it() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    client.set_table_format(&0u64, &MatchFormat::BestOf(5));
+    client.sit_at_table(&player1, &0u64, &1000);
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+    play_hand_to_fold(&env, &client, game_id);
+    client.leave_game(&player1, &game_id);
+    assert_eq!(client.get_game(&game_id).winner, Some(player2.clone()));
+    assert_eq!(pc.get(&MockKey::Paid(player2)), 2000);
+    assert_eq!(hub.ended(&hub_session_id(game_id)), Some(false));
+}
+
+#[test]
+fn test_timebank_delays_timeout_and_refills() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.sit_at_table(&player1, &0u64, &1000);
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+    shuffle(&env, &client, game_id);
+    client.post_blinds(&game_id);
+    let t0 = env.ledger().timestamp();
+
+    // bet_timeout is 300: 30s later the actor is 30s into a 60s timebank.
+    set_timestamp(&env, t0 + 330);
+    let clock = client.get_turn_clock(&game_id);
+    assert_eq!(clock.player, player2);
+    assert_eq!(clock.deadline, t0 + 360);
+    assert_eq!(clock.timebank, 30);
+    assert_eq!(
+        client.try_advance_timeout(&Address::generate(&env), &game_id),
+        Err(Ok(Error::TimeoutNotReached))
+    );
+
+    // Acting late draws the bank down; the next hand refills 10s.
+    let commitment = Some(hole_cards_commit(&env, &vec![&env, 3u32, 4u32]));
+    client.act(&player2, &game_id, &Action::Raise, &25, &None, &commitment);
+    assert_eq!(client.get_game(&game_id).timebank2, 30);
+    client.act(&player1, &game_id, &Action::Fold, &0, &None, &None);
+    let game = client.get_game(&game_id);
+    assert_eq!((game.timebank1, game.timebank2), (60, 40));
+}
+
+#[test]
+fn test_timeout_after_timebank_runs_out() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.sit_at_table(&player1, &0u64, &1000);
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+    shuffle(&env, &client, game_id);
+    client.post_blinds(&game_id);
+    let t0 = env.ledger().timestamp();
+
+    set_timestamp(&env, t0 + 361);
+    client.advance_timeout(&Address::generate(&env), &game_id);
+    // Player2 could not check, so the timeout folded their hand and spent the bank.
+    let game = client.get_game(&game_id);
+    assert_eq!(game.state, GameState::ShuffleCommit);
+    assert_eq!(game.stack1, 1010);
+    assert_eq!(game.timebank2, 10);
+}
+
+#[test]
+fn test_table_timeouts_override_global_config() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let global = client.get_table_timeouts(&0u64);
+    assert_eq!((global.seed_commit, global.betting, global.waiting), (3600, 300, 3600));
+
+    let turbo = TimeoutConfig {
+        seed_commit: 20,
+        seed_reveal: 20,
+        betting: 15,
+        showdown: 30,
+        proof_submission: 60,
+        waiting: 120,
+    };
+    let turbo_table = client.add_table(&client.get_admin(), &5, &10, &100, &10_000, &2);
+    client.set_table_timeouts(&turbo_table, &Some(turbo));
+    assert_eq!(client.get_table_timeouts(&turbo_table), turbo);
+    assert_eq!(client.get_table_timeouts(&0u64), global);
+
+    // Waiting sessions at the turbo table can be closed by anyone after 120s.
+    let t0 = env.ledger().timestamp();
+    client.sit_at_table(&player1, &turbo_table, &1000);
+    set_timestamp(&env, t0 + 121);
+    client.cancel_waiting(&player2, &turbo_table);
+
+    // Seed commit times out after 20s instead of the global 3600s.
+    client.sit_at_table(&player1, &turbo_table, &1000);
+    let game_id = client.sit_at_table(&player2, &turbo_table, &1000).game_id;
+    assert_eq!(client.get_turn_clock(&game_id).deadline, t0 + 121 + 20);
+    set_timestamp(&env, t0 + 121 + 21);
+    client.advance_timeout(&Address::generate(&env), &game_id);
+    assert_eq!(client.get_game(&game_id).state, GameState::Cancelled);
+
+    // Table timeouts are capped at a week.
+    let endless = TimeoutConfig {
+        betting: MAX_TIMEOUT + 1,
+        ..turbo
+    };
+    assert_eq!(
+        client.try_set_table_timeouts(&turbo_table, &Some(endless)),
+        Err(Ok(Error::InvalidAmount))
+    );
+    let slow = TimeoutConfig {
+        betting: MAX_TIMEOUT,
+        ..turbo
+    };
+    client.set_table_timeouts(&turbo_table, &Some(slow));
+    client.set_table_timeouts(&turbo_table, &None);
+    assert_eq!(client.get_table_timeouts(&turbo_table), global);
+
+    // Timeouts too long to add to a timestamp never expire instead of trapping, in the clock
+    // and in the timebank charged by act.
+    let mut config = client.get_config();
+    (config.reveal_timeout, config.bet_timeout) = (u64::MAX, u64::MAX);
+    client.set_config(&config);
+    client.sit_at_table(&player1, &turbo_table, &1000);
+    let game_id = client.sit_at_table(&player2, &turbo_table, &1000).game_id;
+    assert_eq!(client.get_turn_clock(&game_id).deadline, u64::MAX);
+    assert_eq!(
+        client.try_advance_timeout(&Address::generate(&env), &game_id),
+        Err(Ok(Error::TimeoutNotReached))
+    );
+    play_hand_to_fold(&env, &client, game_id);
+    assert_eq!(client.get_game(&game_id).timebank1, config.timebank);
+}
+
+#[test]
+fn test_shuffle_abandonment_refunds_both_players() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    client.sit_at_table(&player1, &0u64, &1000);
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+    let commit = seed_commit(&env, &BytesN::from_array(&env, &[1u8; 32]));
+    client.commit_seed(&player1, &game_id, &commit);
+
+    set_timestamp(&env, env.ledger().timestamp() + 3601);
+    client.advance_timeout(&Address::generate(&env), &game_id);
+    let game = client.get_game(&game_id);
+    assert_eq!(game.state, GameState::Cancelled);
+    assert!(game.winner.is_none());
+    assert_eq!(pc.get(&MockKey::Refunded(player1.clone())), 1000);
+    assert_eq!(pc.get(&MockKey::Refunded(player2.clone())), 1000);
+    assert_eq!(pc.get(&MockKey::Paid(player1)), 0);
+    assert_eq!(hub.ended(&hub_session_id(game_id)), Some(true));
+}
+
+#[test]
+fn test_shuffle_abandonment_penalty_policy() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    let mut config = client.get_config();
+    config.abandon_policy = AbandonPolicy::Penalty(50);
+    client.set_config(&config);
+    client.sit_at_table(&player1, &0u64, &1000);
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+    // Player1 wins 10 chips, then player2 stops responding in the next shuffle.
+    play_hand_to_fold(&env, &client, game_id);
+    let commit = seed_commit(&env, &BytesN::from_array(&env, &[1u8; 32]));
+    client.commit_seed(&player1, &game_id, &commit);
+
+    set_timestamp(&env, env.ledger().timestamp() + 3601);
+    client.advance_timeout(&Address::generate(&env), &game_id);
+    assert_eq!(client.get_game(&game_id).state, GameState::Cancelled);
+    // Player1: 1000 stake refunded, 10 won plus the 50 penalty paid out.
+    assert_eq!(pc.get(&MockKey::Refunded(player1.clone())), 1000);
+    assert_eq!(pc.get(&MockKey::Paid(player1)), 60);
+    assert_eq!(pc.get(&MockKey::Refunded(player2.clone())), 940);
+    assert_eq!(pc.get(&MockKey::Paid(player2)), 0);
+}
+
+#[test]
+fn test_mutual_cancel_returns_bets_and_refunds_stakes() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    client.sit_at_table(&player1, &0u64, &1000);
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+    // Player1 wins 10 chips, then both agree to stop with the blinds of hand 2 in the pot.
+    play_hand_to_fold(&env, &client, game_id);
+    shuffle(&env, &client, game_id);
+    client.post_blinds(&game_id);
+    assert!(client.get_game(&game_id).pot > 0);
+
+    assert_eq!(
+        client.try_mutual_cancel(&game_id, &0),
+        Err(Ok(Error::HandMismatch))
+    );
+    client.mutual_cancel(&game_id, &1);
+    let auths = env.auths();
+    assert!(auths.iter().any(|(a, _)| *a == player1));
+    assert!(auths.iter().any(|(a, _)| *a == player2));
+
+    let game = client.get_game(&game_id);
+    assert_eq!(game.state, GameState::Cancelled);
+    assert!(game.winner.is_none());
+    assert_eq!(game.pot, 0);
+    assert_eq!(pc.get(&MockKey::Refunded(player1.clone())), 1000);
+    assert_eq!(pc.get(&MockKey::Paid(player1)), 10);
+    assert_eq!(pc.get(&MockKey::Refunded(player2.clone())), 990);
+    assert_eq!(pc.get(&MockKey::Paid(player2)), 0);
+    assert_eq!(hub.ended(&hub_session_id(game_id)), Some(true));
+    assert_eq!(
+        client.try_mutual_cancel(&game_id, &1),
+        Err(Ok(Error::GameCancelled))
+    );
+}
+
+/// Shuffle, post blinds and let the betting clock run out on the first actor.
+fn time_out_next_hand(env: &Env, client: &PokerZkContractClient, game_id: u64, caller: &Address) {
+    shuffle(env, client, game_id);
+    client.post_blinds(&game_id);
+    set_timestamp(env, env.ledger().timestamp() + 361);
+    client.advance_timeout(caller, &game_id);
+}
+
+#[test]
+fn test_keeper_bounty_is_capped_and_not_paid_to_players() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    let mut bounty = KeeperBounty {
+        amount: 5,
+        cap: 4,
+        source: BountySource::Forfeit,
+    };
+    assert_eq!(
+        client.try_set_keeper_bounty(&Some(bounty)),
+        Err(Ok(Error::InvalidAmount))
+    );
+    bounty.cap = 8;
+    client.set_keeper_bounty(&Some(bounty));
+    assert_eq!(client.get_keeper_bounty(), Some(bounty));
+    client.sit_at_table(&player1, &0u64, &1000);
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+
+    // A player advancing their opponent's timeout earns nothing.
+    time_out_next_hand(&env, &client, game_id, &player1);
+    assert_eq!(client.get_game(&game_id).bounty_paid, 0);
+    assert_eq!(pc.get(&MockKey::Paid(player1.clone())), 0);
+
+    let keeper = Address::generate(&env);
+    time_out_next_hand(&env, &client, game_id, &keeper);
+    assert_eq!(pc.get(&MockKey::Paid(keeper.clone())), 5);
+    // Only 3 is left under the cap, then nothing.
+    time_out_next_hand(&env, &client, game_id, &keeper);
+    time_out_next_hand(&env, &client, game_id, &keeper);
+    assert_eq!(pc.get(&MockKey::Paid(keeper)), 8);
+    let game = client.get_game(&game_id);
+    assert_eq!(game.bounty_paid, 8);
+    assert_eq!(game.stack1 + game.stack2, 2000 - 8);
+}
+
+#[test]
+fn test_lobby_lists_tables_and_games() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let deep = client.add_table(&admin, &50i128, &100i128, &1000i128, &100_000i128, &2u32);
+    client.sit_at_table(&player1, &0u64, &1000);
+    let tables = client.get_tables(&0u64, &10u32);
+    assert_eq!(tables.len(), 2);
+    assert_eq!(tables.get(0).unwrap().waiting.get(0).unwrap().player1, player1);
+    assert_eq!(tables.get(1).unwrap().table_id, deep);
+
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+    let player3 = Address::generate(&env);
+    let open_id = client.create_game(&player3, &deep, &5000);
+    let info = client.get_tables(&0u64, &1u32).get(0).unwrap();
+    assert!(info.waiting.is_empty());
+    assert_eq!(info.active_games, 1);
+
+    let all = client.get_games(&None, &0u64, &10u32);
+    assert_eq!(all.games.len(), 2);
+    assert!(all.next.is_none());
+    let open = client.get_games(&Some(GameState::WaitingForPlayers), &0u64, &10u32);
+    assert_eq!(open.games.get(0).unwrap().id, open_id);
+    assert_eq!(open.games.len(), 1);
+    let page = client.get_games(&None, &0u64, &1u32);
+    assert_eq!(page.games.get(0).unwrap().id, game_id);
+    // `next` is a position in the index, not a game id.
+    assert_eq!(page.next, Some(1));
+    assert_eq!(client.get_games(&None, &1u64, &1u32).games.get(0).unwrap().id, open_id);
+
+    // Nothing expired yet; once the seed commit window passes the game is listed.
+    assert_eq!(client.get_expired_games(&0u64, &10u32).games.len(), 0);
+    set_timestamp(&env, env.ledger().timestamp() + 3601);
+    let expired = client.get_expired_games(&0u64, &10u32);
+    assert_eq!(expired.games.len(), 1);
+    assert_eq!(expired.games.get(0).unwrap().id, game_id);
+
+    // Settled games leave the index, and the last listed game takes their position.
+    client.advance_timeout(&Address::generate(&env), &game_id);
+    let page = client.get_games(&None, &0u64, &10u32);
+    assert_eq!(page.games.len(), 1);
+    assert_eq!(page.games.get(0).unwrap().id, open_id);
+    assert_eq!(client.get_tables(&0u64, &1u32).get(0).unwrap().active_games, 0);
+    assert_eq!(client.prune_games(&0u64, &10u32), 0);
+    env.as_contract(&client.address, || {
+        let persistent = env.storage().persistent();
+        assert!(!persistent.has(&DataKey::ActiveGame(game_id)));
+        assert!(!persistent.has(&DataKey::ActiveSlot(1)));
+        assert_eq!(persistent.get::<_, u64>(&DataKey::ActiveSlot(0)), Some(open_id));
+    });
+
+    // A game whose storage expired is pruned from the index.
+    env.as_contract(&client.address, || {
+        env.storage().temporary().remove(&DataKey::GameHand(open_id));
+        env.storage().temporary().remove(&DataKey::GameSession(open_id));
+    });
+    assert_eq!(client.try_get_game(&open_id), Err(Ok(Error::GameExpired)));
+    assert_eq!(client.prune_games(&0u64, &10u32), 1);
+    assert!(client.get_games(&None, &0u64, &10u32).games.is_empty());
+    assert_eq!(client.get_tables(&0u64, &2u32).get(1).unwrap().active_games, 0);
+}
+
+#[test]
+fn test_player_index_for_reconnect() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.sit_at_table(&player1, &0u64, &1000);
+    let seat = client.get_waiting_seat(&player1);
+    assert_eq!(seat.table_id, 0);
+    assert_eq!(seat.session.buy_in, 1000);
+
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+    assert_eq!(
+        client.try_get_waiting_seat(&player1),
+        Err(Ok(Error::NoWaitingSession))
+    );
+    let player3 = Address::generate(&env);
+    let other_id = client.create_game(&player3, &0u64, &500);
+    client.join_game(&player1, &other_id);
+    assert_eq!(client.get_active_games(&player1), vec![&env, game_id, other_id]);
+    assert_eq!(client.get_active_games(&player2), vec![&env, game_id]);
+
+    // Settlement moves the game to the recent list of both players.
+    client.leave_game(&player2, &game_id);
+    assert_eq!(client.get_active_games(&player1), vec![&env, other_id]);
+    assert_eq!(client.get_recent_games(&player1), vec![&env, game_id]);
+    assert!(client.get_active_games(&player2).is_empty());
+    assert_eq!(client.get_recent_games(&player2), vec![&env, game_id]);
+}
+
+#[test]
+fn test_waiting_queue_pairs_compatible_buy_ins() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    let [player3, player4, player5, player6] = [0; 4].map(|_| Address::generate(&env));
+    client.sit_at_table(&player1, &0u64, &1000);
+    // Only equal buy-ins pair by default: player2 queues behind player1.
+    assert!(client.sit_at_table(&player2, &0u64, &500).waiting);
+    assert_eq!(client.get_table_queue(&0u64).len(), 2);
+    assert_eq!(
+        client.try_sit_at_table(&player1, &0u64, &500),
+        Err(Ok(Error::CannotPlaySelf))
+    );
+    let small = client.sit_at_table(&player3, &0u64, &500).game_id;
+    assert_eq!(client.get_game(&small).player1, player2);
+
+    // With a 20% tolerance 1200 pairs with 1000 and both play the 1000 effective stack.
+    client.set_table_tolerance(&0u64, &2000u32);
+    let game_id = client.sit_at_table(&player4, &0u64, &1200).game_id;
+    let game = client.get_game(&game_id);
+    assert_eq!((game.player1, game.stack1, game.stack2), (player1, 1000, 1000));
+    assert_eq!(pc.get(&MockKey::Locked(player4)), 1000);
+
+    // A waiting player with the larger buy-in gets the excess back.
+    client.sit_at_table(&player5, &0u64, &1200);
+    client.sit_at_table(&player6, &0u64, &1000);
+    assert_eq!(pc.get(&MockKey::Refunded(player5)), 200);
+    assert!(client.get_table_queue(&0u64).is_empty());
+    assert_eq!(client.get_tables(&0u64, &1u32).get(0).unwrap().active_games, 3);
+}
+
+#[test]
+fn test_cancel_waiting_only_removes_expired_seats() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    let t0 = env.ledger().timestamp();
+    client.sit_at_table(&player1, &0u64, &1000);
+    set_timestamp(&env, t0 + 2000);
+    client.sit_at_table(&player2, &0u64, &500);
+
+    set_timestamp(&env, t0 + 3700);
+    client.cancel_waiting(&Address::generate(&env), &0u64);
+    assert_eq!(pc.get(&MockKey::Refunded(player1)), 1000);
+    assert_eq!(client.get_table_waiting(&0u64).player1, player2);
+    assert_eq!(
+        client.try_cancel_waiting(&Address::generate(&env), &0u64),
+        Err(Ok(Error::WaitingTimeoutNotReached))
+    );
+    client.cancel_waiting(&player2, &0u64);
+    assert_eq!(
+        client.try_get_waiting_seat(&player2),
+        Err(Ok(Error::NoWaitingSession))
+    );
+}
+
+#[test]
+fn test_private_tables_and_seats() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let stranger = Address::generate(&env);
+
+    let club = client.add_table(&admin, &5i128, &10i128, &100i128, &10_000i128, &2u32);
+    let members = vec![&env, player1.clone(), player2.clone()];
+    client.set_table_access(&club, &TableAccess::Allowlist(members));
+    assert_eq!(
+        client.try_sit_at_table(&stranger, &club, &1000),
+        Err(Ok(Error::NotInvited))
+    );
+    assert_eq!(
+        client.try_create_game(&stranger, &club, &1000),
+        Err(Ok(Error::NotInvited))
+    );
+    client.sit_at_table(&player1, &club, &1000);
+    assert!(!client.sit_at_table(&player2, &club, &1000).waiting);
+
+    let secret = Bytes::from_slice(&env, b"friday night");
+    let hash = env.crypto().sha256(&secret).to_bytes();
+    client.set_table_access(&0u64, &TableAccess::Password(hash));
+    let wrong = Some(Bytes::from_slice(&env, b"saturday"));
+    assert_eq!(
+        client.try_sit_at_table_with(&stranger, &0u64, &1000, &wrong, &TableAccess::Public),
+        Err(Ok(Error::NotInvited))
+    );
+    assert!(client
+        .sit_at_table_with(&player1, &0u64, &1000, &Some(secret), &TableAccess::Public)
+        .waiting);
+
+    // A private seat on a public table is only paired with the invited player.
+    let open = client.add_table(&admin, &5i128, &10i128, &100i128, &10_000i128, &2u32);
+    let [host, guest] = [0; 2].map(|_| Address::generate(&env));
+    let invite = TableAccess::Allowlist(vec![&env, guest.clone()]);
+    client.sit_at_table_with(&host, &open, &1000, &None, &invite);
+    assert!(client.sit_at_table(&stranger, &open, &1000).waiting);
+    let game_id = client.sit_at_table(&guest, &open, &1000).game_id;
+    assert_eq!(client.get_game(&game_id).player1, host);
+    assert_eq!(client.get_table_queue(&open).get(0).unwrap().player1, stranger);
+
+    // Seat access is per-player data, kept outside instance storage, and allowlists are capped.
+    let lounge = client.add_table(&admin, &5i128, &10i128, &100i128, &10_000i128, &2u32);
+    client.sit_at_table_with(&host, &lounge, &1000, &None, &invite);
+    env.as_contract(&client.address, || {
+        let key = DataKey::SeatAccess(lounge, host.clone());
+        assert!(env.storage().persistent().has(&key));
+        assert!(!env.storage().instance().has(&key));
+    });
+    let mut crowd = Vec::new(&env);
+    for _ in 0..=MAX_ALLOWLIST {
+        crowd.push_back(Address::generate(&env));
+    }
+    let crowd = TableAccess::Allowlist(crowd);
+    assert_eq!(
+        client.try_sit_at_table_with(&guest, &open, &1000, &None, &crowd),
+        Err(Ok(Error::AllowlistTooLong))
+    );
+    assert_eq!(
+        client.try_set_table_access(&club, &crowd),
+        Err(Ok(Error::AllowlistTooLong))
+    );
+}
+
+#[test]
+fn test_invites_are_bound_single_use_and_expire() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let stranger = Address::generate(&env);
+    let invite = Invite {
+        player1: player1.clone(),
+        opponent: Some(player2.clone()),
+        table_id: 0,
+        buy_in: 1000,
+        expires_ledger: 200,
+        nonce: 1,
+    };
+    assert_eq!(
+        client.try_start_game_with_invite(&7u64, &stranger, &invite),
+        Err(Ok(Error::NotInvited))
+    );
+    let game_id = client.start_game_with_invite(&7u64, &player2, &invite);
+    assert_eq!(client.get_game(&game_id).player2, Some(player2.clone()));
+    assert_eq!(
+        client.try_start_game_with_invite(&8u64, &player2, &invite),
+        Err(Ok(Error::InviteUsed))
+    );
+
+    // An open invite can be revoked before anyone accepts it.
+    let open = Invite {
+        opponent: None,
+        nonce: 2,
+        ..invite.clone()
+    };
+    client.revoke_invite(&open);
+    assert_eq!(
+        client.try_start_game_with_invite(&9u64, &stranger, &open),
+        Err(Ok(Error::InviteRevoked))
+    );
+
+    let short = Invite {
+        opponent: None,
+        expires_ledger: 150,
+        nonce: 3,
+        ..invite
+    };
+    env.ledger().with_mut(|li| li.sequence_number = 151);
+    assert_eq!(
+        client.try_start_game_with_invite(&10u64, &stranger, &short),
+        Err(Ok(Error::InviteExpired))
+    );
+}
+
+#[test]
+fn test_rematch_locks_both_buy_ins_and_moves_the_button() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    client.sit_at_table(&player1, &0u64, &1000);
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+    assert_eq!(
+        client.try_offer_rematch(&player1, &game_id),
+        Err(Ok(Error::InvalidState))
+    );
+    play_hand_to_fold(&env, &client, game_id);
+    client.leave_game(&player2, &game_id);
+    let dealer = client.get_game(&game_id).dealer_position;
+
+    let t0 = env.ledger().timestamp();
+    client.offer_rematch(&player2, &game_id);
+    assert_eq!(
+        client.get_rematch(&game_id),
+        RematchOffer {
+            player: player2.clone(),
+            buy_in: 1000,
+            created_at: t0,
+        }
+    );
+    assert_eq!(pc.get(&MockKey::Locked(player2.clone())), 2000);
+    assert_eq!(
+        client.try_accept_rematch(&player2, &game_id),
+        Err(Ok(Error::CannotPlaySelf))
+    );
+    assert_eq!(
+        client.try_cancel_rematch(&Address::generate(&env), &game_id),
+        Err(Ok(Error::WaitingTimeoutNotReached))
+    );
+
+    // The table is checked again on accept.
+    let table = client.get_table(&0u64);
+    client.update_table(&0u64, &crate::Table { min_buy_in: 2000, max_buy_in: 5000, ..table });
+    assert_eq!(
+        client.try_accept_rematch(&player1, &game_id),
+        Err(Ok(Error::BuyInTooLow))
+    );
+    client.update_table(&0u64, &crate::Table { max_buy_in: 500, min_buy_in: 100, ..table });
+    assert_eq!(
+        client.try_accept_rematch(&player1, &game_id),
+        Err(Ok(Error::BuyInTooHigh))
+    );
+    client.update_table(&0u64, &table);
+    client.pause_table(&0u64, &true);
+    assert_eq!(
+        client.try_accept_rematch(&player1, &game_id),
+        Err(Ok(Error::TablePaused))
+    );
+    client.pause_table(&0u64, &false);
+
+    let rematch_id = client.accept_rematch(&player1, &game_id);
+    let rematch = client.get_game(&rematch_id);
+    assert_eq!(rematch.state, GameState::ShuffleCommit);
+    assert_eq!((rematch.player1, rematch.player2), (player1.clone(), Some(player2.clone())));
+    assert_eq!((rematch.stack1, rematch.stack2, rematch.table_id), (1000, 1000, 0));
+    assert_eq!(rematch.dealer_position, 1 - dealer);
+    assert_eq!(pc.get(&MockKey::Locked(player1.clone())), 2000);
+    assert_eq!(pc.get(&MockKey::Locked(player2.clone())), 2000);
+    assert_eq!(client.get_active_games(&player1), vec![&env, rematch_id]);
+    assert_eq!(
+        client.try_offer_rematch(&player1, &game_id),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // An offer nobody takes can be closed by anyone after the waiting timeout, even once the
+    // game's temporary entries have expired; nor does expiry allow a second rematch.
+    client.leave_game(&player1, &rematch_id);
+    client.offer_rematch(&player1, &rematch_id);
+    env.as_contract(&client.address, || {
+        let temporary = env.storage().temporary();
+        for id in [game_id, rematch_id] {
+            temporary.remove(&DataKey::GameHand(id));
+            temporary.remove(&DataKey::GameSession(id));
+        }
+    });
+    assert_eq!(
+        client.try_offer_rematch(&player1, &game_id),
+        Err(Ok(Error::InvalidState))
+    );
+    set_timestamp(&env, t0 + 3600);
+    client.cancel_rematch(&Address::generate(&env), &rematch_id);
+    assert_eq!(pc.get(&MockKey::Refunded(player1)), 1000);
+    assert_eq!(
+        client.try_accept_rematch(&player2, &rematch_id),
+        Err(Ok(Error::NoRematchOffer))
+    );
+}
+
+/// A game as schema 1 stored it: no stacks, and both buy-ins in the pot.
+fn v1_game(
+    env: &Env,
+    id: u64,
+    state: GameState,
+    player1: &Address,
+    player2: Option<&Address>,
+    buy_in: i128,
+) -> GameV1 {
+    let seated = if player2.is_some() { 2 } else { 1 };
+    GameV1 {
+        id,
+        state,
+        player1: player1.clone(),
+        player2: player2.cloned(),
+        buy_in,
+        pot: seated * buy_in,
+        small_blind: 5,
+        big_blind: 10,
+        dealer_position: 0,
+        board: Vec::new(env),
+        board_revealed: 0,
+        current_bet_p1: 0,
+        current_bet_p2: 0,
+        total_bet_p1: 0,
+        total_bet_p2: 0,
+        min_raise: 10,
+        last_raise_amount: 10,
+        actor: 0,
+        folded: None,
+        seed_commitment1: None,
+        seed_commitment2: None,
+        seed_reveal1: None,
+        seed_reveal2: None,
+        final_seed: None,
+        hand_commitment1: None,
+        hand_commitment2: None,
+        hand_rank1: None,
+        hand_rank2: None,
+        winner: None,
+        created_at: env.ledger().timestamp(),
+        last_action_at: env.ledger().timestamp(),
+        table_id: 0,
+    }
+}
+
+#[test]
+fn test_session_ids_cannot_collide_with_game_ids() {
+    let (env, client, hub, player1, player2) = setup_test();
+    client.sit_at_table(&player1, &0u64, &1000);
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+    assert_eq!(game_id, 0);
+
+    // An old session 0 with other players, stored and indexed the way earlier versions did.
+    let (player3, player4) = (Address::generate(&env), Address::generate(&env));
+    let legacy = v1_game(&env, 0, GameState::ShuffleCommit, &player3, Some(&player4), 1000);
+    env.as_contract(&client.address, || {
+        env.storage().temporary().set(&DataKey::SessionGame(0), &legacy);
+        for player in [&player3, &player4] {
+            let games = PlayerGames {
+                active: vec![&env, 0u64],
+                recent: Vec::new(&env),
+            };
+            env.storage()
+                .persistent()
+                .set(&DataKey::PlayerGames(player.clone()), &games);
+        }
+    });
+    assert_eq!(client.get_game(&0u64).player1, player1);
+    assert_eq!(client.get_game(&SESSION_ID_BASE).player1, player3);
+    assert_eq!(client.get_game(&SESSION_ID_BASE).id, SESSION_ID_BASE);
+    assert_eq!(client.get_game(&SESSION_ID_BASE).stack2, 1000);
+    assert_eq!(
+        client.try_start_game(&0u64, &0u64, &player3, &player4, &1000),
+        Err(Ok(Error::GameFull))
+    );
+
+    // Its next write moves it to its new id.
+    let seed = BytesN::from_array(&env, &[7u8; 32]);
+    client.commit_seed(&player3, &SESSION_ID_BASE, &seed_commit(&env, &seed));
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().temporary().has(&DataKey::SessionGame(0)));
+        assert!(env.storage().temporary().has(&DataKey::GameHand(SESSION_ID_BASE)));
+    });
+    assert_eq!(client.get_active_games(&player3), vec![&env, SESSION_ID_BASE]);
+    assert_eq!(client.get_active_games(&player1), vec![&env, 0u64]);
+    let page = client.get_games(&None, &0u64, &10u32);
+    assert_eq!(page.games.len(), 2);
+
+    // New sessions live above every sequential id, whatever number the client picks.
+    client.start_game(&1u64, &0u64, &player3, &player4, &1000);
+    let session_game = client.get_session_game_id(&1u64);
+    assert_eq!(session_game, SESSION_ID_BASE + 1);
+    assert_eq!(client.get_game(&session_game).player1, player3);
+    assert_eq!(client.try_get_game(&1u64), Err(Ok(Error::GameNotFound)));
+    client.sit_at_table(&player3, &0u64, &1000);
+    let next_id = client.sit_at_table(&player4, &0u64, &1000).game_id;
+    assert_eq!(next_id, 1);
+    assert_eq!(client.get_game(&session_game).player1, player3);
+    assert_eq!(
+        client.try_start_game(&HUB_SEQUENTIAL_BASE, &0u64, &player3, &player4, &1000),
+        Err(Ok(Error::InvalidSessionId))
+    );
+
+    // The hub sees session 1 and sequential game 1 as different sessions.
+    client.leave_game(&player3, &next_id);
+    assert_eq!(hub.ended(&hub_session_id(next_id)), Some(true));
+    assert_eq!(hub.ended(&1u32), None);
+    client.leave_game(&player3, &session_game);
+    assert_eq!(hub.ended(&1u32), Some(true));
+
+    // Once sequential ids would reach the hub's session range, new games are refused.
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::NextGameId, &HUB_SEQUENTIAL_BASE);
+    });
+    client.sit_at_table(&player1, &0u64, &1000);
+    assert_eq!(
+        client.try_sit_at_table(&player2, &0u64, &1000),
+        Err(Ok(Error::GameIdsExhausted))
+    );
+}
+
+#[test]
+fn test_game_ttl_extension_and_archive() {
+    use soroban_sdk::testutils::storage::{Persistent as _, Temporary as _};
+    let (env, client, _hub, player1, player2) = setup_test();
+    env.ledger().with_mut(|li| {
+        li.min_temp_entry_ttl = 16;
+        li.min_persistent_entry_ttl = 16;
+        li.max_entry_ttl = 1_000_000;
+    });
+    let ttl = |key: &DataKey| {
+        env.as_contract(&client.address, || env.storage().temporary().get_ttl(key))
+    };
+    client.sit_at_table(&player1, &0u64, &1000);
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+    let key = DataKey::GameHand(game_id);
+    assert_eq!(ttl(&key), 518_400);
+
+    // Reading an active game extends it once half its TTL is gone.
+    env.ledger().with_mut(|li| li.sequence_number += 300_000);
+    client.get_game(&game_id);
+    assert_eq!(ttl(&key), 518_400);
+
+    play_hand_to_fold(&env, &client, game_id);
+    client.leave_game(&player2, &game_id);
+    let summary = client.get_game_summary(&game_id);
+    assert_eq!(summary.state, GameState::Finished);
+    assert_eq!(summary.winner, client.get_game(&game_id).winner);
+    assert_eq!((summary.hands_won1 + summary.hands_won2, summary.buy_in), (1, 1000));
+    env.as_contract(&client.address, || {
+        assert_eq!(env.storage().persistent().get_ttl(&DataKey::Archive(game_id)), 518_400);
+    });
+
+    // Settled games are only extended on request.
+    env.ledger().with_mut(|li| li.sequence_number += 300_000);
+    client.get_game(&game_id);
+    assert_eq!(ttl(&key), 218_400);
+    client.bump_game(&game_id);
+    assert_eq!(ttl(&key), 518_400);
+
+    // A game whose entry expired before it settled still has stakes in escrow.
+    let (player3, player4) = (Address::generate(&env), Address::generate(&env));
+    client.sit_at_table(&player3, &0u64, &1000);
+    let lost_id = client.sit_at_table(&player4, &0u64, &1000).game_id;
+    env.as_contract(&client.address, || {
+        env.storage().temporary().remove(&DataKey::GameHand(lost_id));
+        env.storage().temporary().remove(&DataKey::GameSession(lost_id));
+    });
+    assert_eq!(client.try_get_game(&lost_id), Err(Ok(Error::GameExpired)));
+    assert_eq!(client.try_bump_game(&lost_id), Err(Ok(Error::GameExpired)));
+    assert_eq!(
+        client.try_leave_game(&player3, &lost_id),
+        Err(Ok(Error::GameExpired))
+    );
+    assert_eq!(client.try_get_game(&99u64), Err(Ok(Error::GameNotFound)));
+}
+
+#[test]
+fn test_close_game_keeps_only_the_result() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.sit_at_table(&player1, &0u64, &1000);
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+    assert_eq!(client.try_close_game(&game_id), Err(Ok(Error::InvalidState)));
+    play_hand_to_fold(&env, &client, game_id);
+    client.leave_game(&player2, &game_id);
+    let settled = client.get_game(&game_id);
+
+    client.close_game(&game_id);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().temporary().has(&DataKey::GameHand(game_id)));
+        assert!(!env.storage().temporary().has(&DataKey::GameSession(game_id)));
+    });
+    let closed = client.get_game(&game_id);
+    assert_eq!(closed.state, GameState::Finished);
+    assert_eq!((closed.player1, closed.player2), (player1.clone(), Some(player2.clone())));
+    assert_eq!(closed.winner, settled.winner);
+    assert_eq!((closed.hands_won1, closed.hands_won2), (settled.hands_won1, settled.hands_won2));
+    assert_eq!((closed.stack1, closed.stack2, closed.pot), (0, 0, 0));
+    assert_eq!(client.get_game_summary(&game_id).ended_at, settled.last_action_at);
+    assert_eq!(client.try_close_game(&game_id), Err(Ok(Error::GameNotFound)));
+    assert_eq!(
+        client.try_leave_game(&player2, &game_id),
+        Err(Ok(Error::GameAlreadyFinished))
+    );
+
+    // The summary keeps the format the game was played in.
+    client.set_table_format(&0u64, &MatchFormat::Freezeout);
+    assert_eq!(client.get_game(&game_id).format, MatchFormat::Cash);
+    client.set_table_format(&0u64, &MatchFormat::Cash);
+
+    // A closed session's id stays taken.
+    client.start_game(&7u64, &0u64, &player1, &player2, &1000);
+    let session_game = client.get_session_game_id(&7u64);
+    play_hand_to_fold(&env, &client, session_game);
+    client.leave_game(&player2, &session_game);
+    client.close_game(&session_game);
+    assert_eq!(
+        client.try_start_game(&7u64, &0u64, &player1, &player2, &1000),
+        Err(Ok(Error::GameFull))
+    );
+    assert_eq!(client.get_game_summary(&session_game).state, GameState::Finished);
+}
+
+/// Resources of one full hand (shuffle, blinds, four betting rounds, showdown), summed over
+/// every call, and the stored size of a game.
+/// Fees leave out rent: with mocked auths it is almost all auth nonces.
+#[test]
+fn test_full_hand_resources() {
+    use soroban_sdk::xdr::ToXdr;
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.sit_at_table(&player1, &0u64, &1000);
+    let game_id = client.sit_at_table(&player2, &0u64, &1000).game_id;
+    // Bytes each call wrote, and the size of the whole Game after it: what the single-entry
+    // layout would have rewritten every time.
+    let (mut write_bytes, mut single_entry_bytes) = (0u32, 0u32);
+    let mut record = |env: &Env| {
+        write_bytes += env.cost_estimate().resources().write_bytes;
+        single_entry_bytes += client.get_game(&game_id).to_xdr(env).len();
+    };
+
+    let seeds = [[7u8; 32], [8u8; 32]];
+    for (player, seed) in [&player1, &player2].into_iter().zip(seeds) {
+        let seed = BytesN::from_array(&env, &seed);
+        client.commit_seed(player, &game_id, &seed_commit(&env, &seed));
+        record(&env);
+    }
+    for (player, seed) in [&player1, &player2].into_iter().zip(seeds) {
+        client.reveal_seed(player, &game_id, &BytesN::from_array(&env, &seed));
+        record(&env);
+    }
+    client.post_blinds(&game_id);
+    record(&env);
+    let cards = vec![&env, 3u32, 4u32];
+    let commitment = Some(hole_cards_commit(&env, &cards));
+    let game = client.get_game(&game_id);
+    let (first, second) = if game.actor == 0 {
+        (player1.clone(), player2.clone())
+    } else {
+        (player2.clone(), player1.clone())
+    };
+    client.act(&first, &game_id, &Action::Raise, &20, &None, &commitment);
+    record(&env);
+    client.act(&second, &game_id, &Action::Call, &0, &None, &commitment);
+    record(&env);
+    for _ in 0..3 {
+        let game = client.get_game(&game_id);
+        let actor = if game.actor == 0 { game.player1 } else { game.player2.unwrap() };
+        client.act(&actor, &game_id, &Action::Check, &0, &None, &None);
+        record(&env);
+    }
+    let game = client.get_game(&game_id);
+    assert_eq!(game.state, GameState::Showdown);
+    // What an action rewrites now (the hand) against the whole Game it used to rewrite.
+    let (hand, session) = env.as_contract(&client.address, || {
+        let storage = env.storage().temporary();
+        let hand: HandState = storage.get(&DataKey::GameHand(game_id)).unwrap();
+        let session: GameSession = storage.get(&DataKey::GameSession(game_id)).unwrap();
+        (hand.to_xdr(&env).len(), session.to_xdr(&env).len())
+    });
+    let full = game.to_xdr(&env).len();
+    assert!(hand * 2 < full);
+    assert!(session < full);
+
+    let proof = BytesN::from_array(&env, &[0u8; 256]);
+    for (player, rank) in [(&player1, 9u32), (&player2, 2u32)] {
+        client.reveal_hand(player, &game_id, &cards, &rank, &proof);
+        record(&env);
+    }
+    assert_eq!(client.get_game(&game_id).hands_won1, 1);
+
+    // Every write of this hand together (auth nonces included), against rewriting just the
+    // whole game each time.
+    assert!(write_bytes < single_entry_bytes);
+}
+
+#[test]
+fn test_missing_admin_hub_and_config_return_errors() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let config = client.get_config();
+    let game_id = client.create_game(&player1, &0u64, &1000);
+
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&DataKey::GameHubAddress);
+    });
+    assert_eq!(client.try_get_hub(), Err(Ok(Error::HubNotSet)));
+    assert_eq!(
+        client.try_join_game(&player2, &game_id),
+        Err(Ok(Error::HubNotSet))
+    );
+
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&DataKey::Admin);
+        env.storage().instance().remove(&DataKey::Config);
+    });
+    let other = Address::generate(&env);
+    assert_eq!(client.try_get_admin(), Err(Ok(Error::AdminNotSet)));
+    assert_eq!(client.try_propose_owner(&Some(other.clone())), Err(Ok(Error::AdminNotSet)));
+    assert_eq!(client.try_set_hub(&other), Err(Ok(Error::AdminNotSet)));
+    assert_eq!(
+        client.try_upgrade(&BytesN::from_array(&env, &[0u8; 32])),
+        Err(Ok(Error::AdminNotSet))
+    );
+    assert_eq!(
+        client.try_migrate(&10, &Vec::new(&env)),
+        Err(Ok(Error::AdminNotSet))
+    );
+    assert_eq!(
+        client.try_set_payment_controller(&admin, &Some(other.clone())),
+        Err(Ok(Error::AdminNotSet))
+    );
+
+    // Initializing again restores all three.
+    client.initialize(&admin, &config);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_hub(), config.game_hub);
+    client.join_game(&player2, &game_id);
+}
+
+#[test]
+fn test_inconsistent_game_state_returns_errors() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let game_id = client.create_game(&player1, &0u64, &1000);
+    client.join_game(&player2, &game_id);
+    let seed1 = BytesN::from_array(&env, &[1u8; 32]);
+    let seed2 = BytesN::from_array(&env, &[2u8; 32]);
+    client.commit_seed(&player1, &game_id, &seed_commit(&env, &seed1));
+    client.commit_seed(&player2, &game_id, &seed_commit(&env, &seed2));
+
+    // A reveal whose commitment is missing.
+    env.as_contract(&client.address, || {
+        let mut game = crate::load_game(&env, game_id).unwrap();
+        game.seed_commitment2 = None;
+        crate::save_game(&env, game_id, &game);
+    });
+    assert_eq!(
+        client.try_reveal_seed(&player2, &game_id, &seed2),
+        Err(Ok(Error::NotCommitted))
+    );
+
+    // A hand in play whose second seat is empty.
+    let game_id = client.create_game(&player1, &0u64, &1000);
+    client.join_game(&player2, &game_id);
+    client.commit_seed(&player1, &game_id, &seed_commit(&env, &seed1));
+    client.commit_seed(&player2, &game_id, &seed_commit(&env, &seed2));
+    client.reveal_seed(&player1, &game_id, &seed1);
+    client.reveal_seed(&player2, &game_id, &seed2);
+    client.post_blinds(&game_id);
+    let actor = client.get_game(&game_id).actor;
+    env.as_contract(&client.address, || {
+        let mut game = crate::load_game(&env, game_id).unwrap();
+        game.player2 = None;
+        crate::save_session(&env, game_id, &game);
+        crate::save_game(&env, game_id, &game);
+    });
+    let acting = if actor == 0 { &player1 } else { &player2 };
+    assert_eq!(
+        client.try_act(acting, &game_id, &Action::Fold, &0, &None, &None),
+        Err(Ok(Error::NoOpponent))
+    );
+    env.as_contract(&client.address, || {
+        let mut game = crate::load_game(&env, game_id).unwrap();
+        game.actor = 1;
+        crate::save_game(&env, game_id, &game);
+    });
+    assert_eq!(client.try_get_turn_clock(&game_id), Err(Ok(Error::NoOpponent)));
+}
+
+#[test]
+fn test_chip_overflow_is_an_error() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    let admin = client.get_admin();
+
+    // Any buy-in limit is allowed: rebuys grow pots past it anyway, and the rake is capped.
+    let table_id = client.add_table(&admin, &5, &10, &100, &i128::MAX, &2);
+
+    // Topping up past i128::MAX.
+    let game_id = client.create_game(&player1, &table_id, &1000);
+    client.join_game(&player2, &game_id);
+    assert_eq!(
+        client.try_top_up(&player1, &game_id, &i128::MAX),
+        Err(Ok(Error::ChipOverflow))
+    );
+    assert_eq!(client.get_game(&game_id).stack1, 1000);
+
+    // A pot far too large to multiply by the rake rate is still raked and settled.
+    let seed1 = BytesN::from_array(&env, &[1u8; 32]);
+    let seed2 = BytesN::from_array(&env, &[2u8; 32]);
+    client.commit_seed(&player1, &game_id, &seed_commit(&env, &seed1));
+    client.commit_seed(&player2, &game_id, &seed_commit(&env, &seed2));
+    client.reveal_seed(&player1, &game_id, &seed1);
+    client.reveal_seed(&player2, &game_id, &seed2);
+    client.post_blinds(&game_id);
+    let huge = i128::MAX / 4;
+    env.as_contract(&client.address, || {
+        let mut game = crate::load_game(&env, game_id).unwrap();
+        game.bought_in1 += huge;
+        game.bought_in2 += huge;
+        game.total_bet_p1 += huge;
+        game.total_bet_p2 += huge;
+        game.pot += 2 * huge;
+        crate::save_session(&env, game_id, &game);
+        crate::save_game(&env, game_id, &game);
+    });
+    let before = client.get_game(&game_id);
+    let (folder, winner_is_p1) = if before.actor == 0 {
+        (&player1, false)
+    } else {
+        (&player2, true)
+    };
+    client.act(folder, &game_id, &Action::Fold, &0, &None, &None);
+    let game = client.get_game(&game_id);
+    // 2% of the pot, rounded down.
+    let rake = before.pot / 50;
+    assert_eq!(game.state, GameState::ShuffleCommit);
+    assert_eq!(game.rake, rake);
+    let (winner_stack, before_stack) = if winner_is_p1 {
+        (game.stack1, before.stack1)
+    } else {
+        (game.stack2, before.stack2)
+    };
+    assert_eq!(winner_stack, before_stack + before.pot - rake);
+    assert_eq!(pc.get(&MockKey::Refunded(player1)), 0);
+    assert_eq!(hub.ended(&hub_session_id(game_id)), None);
+}
+
+#[test]
+#[should_panic(expected = "chips not conserved")]
+fn test_chip_conservation_is_checked_on_save() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let game_id = client.create_game(&player1, &0u64, &1000);
+    client.join_game(&player2, &game_id);
+    env.as_contract(&client.address, || {
+        let mut game = crate::load_game(&env, game_id).unwrap();
+        game.pot += 1;
+        crate::save_game(&env, game_id, &game);
+    });
+}
+
+#[test]
+fn test_table_validation_and_lifecycle() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    let admin = client.get_admin();
+    assert_eq!(
+        client.try_add_table(&admin, &20, &10, &100, &10_000, &2),
+        Err(Ok(Error::InvalidBlinds))
+    );
+    assert_eq!(
+        client.try_add_table(&admin, &5, &10, &10_000, &100, &2),
+        Err(Ok(Error::InvalidBuyInRange))
+    );
+    assert_eq!(
+        client.try_add_table(&admin, &5, &10, &100, &10_000, &0),
+        Err(Ok(Error::InvalidSeats))
+    );
+    let table_id = client.add_table(&admin, &5, &10, &100, &10_000, &2);
+
+    let mut stakes = client.get_table(&table_id);
+    stakes.small_blind = 50;
+    assert_eq!(
+        client.try_update_table(&table_id, &stakes),
+        Err(Ok(Error::InvalidBlinds))
+    );
+    stakes.big_blind = 100;
+    stakes.min_buy_in = 1000;
+    client.update_table(&table_id, &stakes);
+    assert_eq!(client.get_table(&table_id), stakes);
+    assert_eq!(
+        client.try_create_game(&player1, &table_id, &500),
+        Err(Ok(Error::BuyInTooLow))
+    );
+
+    let meta = TableMeta {
+        name: String::from_str(&env, "High Stakes"),
+        variant: symbol_short!("NLHE"),
+        currency: symbol_short!("XLM"),
+    };
+    assert_eq!(client.get_table_meta(&table_id).variant, symbol_short!("NLHE"));
+    client.set_table_meta(&table_id, &meta);
+    assert_eq!(client.get_table_meta(&table_id), meta);
+
+    // Pausing stops new seats and games; a game in progress plays on.
+    let game_id = client.create_game(&player1, &table_id, &1000);
+    client.join_game(&player2, &game_id);
+    let pending = client.create_game(&player1, &table_id, &1000);
+    client.pause_table(&table_id, &true);
+    assert_eq!(client.get_table_status(&table_id), TableStatus::Paused);
+    assert_eq!(
+        client.try_sit_at_table(&player2, &table_id, &1000),
+        Err(Ok(Error::TablePaused))
+    );
+    assert_eq!(
+        client.try_create_game(&player2, &table_id, &1000),
+        Err(Ok(Error::TablePaused))
+    );
+    assert_eq!(
+        client.try_join_game(&player2, &pending),
+        Err(Ok(Error::TablePaused))
+    );
+    let seed1 = BytesN::from_array(&env, &[1u8; 32]);
+    client.commit_seed(&player1, &game_id, &seed_commit(&env, &seed1));
+    client.pause_table(&table_id, &false);
+    client.sit_at_table(&player2, &table_id, &1000);
+
+    // Retiring refunds the waiting list and is final.
+    client.retire_table(&table_id);
+    assert_eq!(pc.get(&MockKey::Refunded(player2.clone())), 1000);
+    assert!(client.get_table_queue(&table_id).is_empty());
+    assert_eq!(
+        client.try_sit_at_table(&player2, &table_id, &1000),
+        Err(Ok(Error::TableRetired))
+    );
+    assert_eq!(
+        client.try_pause_table(&table_id, &false),
+        Err(Ok(Error::TableRetired))
+    );
+    assert_eq!(
+        client.try_update_table(&table_id, &stakes),
+        Err(Ok(Error::TableRetired))
+    );
+    let info = client.get_tables(&table_id, &1).get(0).unwrap();
+    assert_eq!(info.status, TableStatus::Retired);
+    assert_eq!(info.meta, meta);
+    assert_eq!(info.active_games, 2);
+    client.leave_game(&player1, &pending);
+    let seed2 = BytesN::from_array(&env, &[2u8; 32]);
+    client.commit_seed(&player2, &game_id, &seed_commit(&env, &seed2));
+    assert_eq!(client.get_game(&game_id).state, GameState::ShuffleReveal);
+
+    // Once its last game settles, the retired table is deleted.
+    set_timestamp(&env, env.ledger().timestamp() + 3601);
+    client.advance_timeout(&Address::generate(&env), &game_id);
+    assert_eq!(client.try_get_table(&table_id), Err(Ok(Error::TableNotFound)));
+    assert!(client.get_tables(&table_id, &1).is_empty());
+    env.as_contract(&client.address, || {
+        let persistent = env.storage().persistent();
+        assert!(!persistent.has(&DataKey::Table(table_id)));
+        assert!(!persistent.has(&DataKey::TableStatus(table_id)));
+        assert!(!persistent.has(&DataKey::TableMeta(table_id)));
+    });
+    assert!(client.get_game(&game_id).state == GameState::Cancelled);
+
+    // A retired table with no games is deleted at once.
+    let empty = client.add_table(&client.get_admin(), &5i128, &10i128, &100i128, &10_000i128, &2u32);
+    client.retire_table(&empty);
+    assert_eq!(client.try_get_table(&empty), Err(Ok(Error::TableNotFound)));
+}
+
+#[test]
+fn test_pause_blocks_new_games_and_allows_emergency_withdrawal() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let pc = with_payment_controller(&env, &client);
+    let guardian = Address::generate(&env);
+    let game_id = client.create_game(&player1, &0u64, &1000);
+    client.join_game(&player2, &game_id);
+    let seed1 = BytesN::from_array(&env, &[1u8; 32]);
+    let seed2 = BytesN::from_array(&env, &[2u8; 32]);
+    client.commit_seed(&player1, &game_id, &seed_commit(&env, &seed1));
+    client.commit_seed(&player2, &game_id, &seed_commit(&env, &seed2));
+    client.reveal_seed(&player1, &game_id, &seed1);
+    client.reveal_seed(&player2, &game_id, &seed2);
+    client.post_blinds(&game_id);
+    let waiting = client.create_game(&player1, &0u64, &500);
+
+    assert_eq!(client.try_pause(&guardian), Err(Ok(Error::Unauthorized)));
+    client.set_role(&Role::Guardian, &Some(guardian.clone()));
+    client.pause(&guardian);
+    assert_eq!(client.get_paused_at(), Some(env.ledger().timestamp()));
+    assert_eq!(
+        client.try_sit_at_table(&player1, &0u64, &1000),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_create_game(&player1, &0u64, &1000),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_start_game(&1u64, &0u64, &player1, &player2, &1000),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_join_game(&player2, &waiting),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    // The hand in progress can still be played.
+    let actor = if client.get_game(&game_id).actor == 0 { &player1 } else { &player2 };
+    let commitment = Some(BytesN::from_array(&env, &[7u8; 32]));
+    client.act(actor, &game_id, &Action::Raise, &30, &None, &commitment);
+
+    // Players can pull out only after the grace period.
+    assert_eq!(
+        client.try_emergency_withdraw(&player1, &game_id),
+        Err(Ok(Error::GracePeriodNotOver))
+    );
+    set_timestamp(&env, env.ledger().timestamp() + crate::EMERGENCY_GRACE);
+    client.emergency_withdraw(&player2, &game_id);
+    let game = client.get_game(&game_id);
+    assert_eq!(game.state, GameState::Cancelled);
+    assert_eq!(pc.get(&MockKey::Refunded(player1.clone())), 1000);
+    assert_eq!(pc.get(&MockKey::Refunded(player2.clone())), 1000);
+    assert_eq!(hub.ended(&hub_session_id(game_id)), Some(false));
+    client.emergency_withdraw(&player1, &waiting);
+    assert_eq!(pc.get(&MockKey::Refunded(player1.clone())), 1500);
+    assert_eq!(
+        client.try_emergency_withdraw(&player1, &waiting),
+        Err(Ok(Error::GameCancelled))
+    );
+
+    client.unpause();
+    assert_eq!(client.try_unpause(), Err(Ok(Error::NotPaused)));
+    assert_eq!(
+        client.try_emergency_withdraw(&player1, &game_id),
+        Err(Ok(Error::NotPaused))
+    );
+    client.create_game(&player1, &0u64, &1000);
+}
+
+#[test]
+fn test_roles_and_two_step_ownership() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let owner = client.get_admin();
+    let operator = Address::generate(&env);
+    let next_owner = Address::generate(&env);
+
+    // Unassigned roles fall to the owner.
+    assert_eq!(client.get_role(&Role::Operator), owner);
+    assert_eq!(client.get_role(&Role::Guardian), owner);
+
+    client.set_role(&Role::Operator, &Some(operator.clone()));
+    assert_eq!(client.get_role(&Role::Operator), operator);
+    assert_eq!(
+        client.try_add_table(&owner, &5, &10, &100, &1000, &2),
+        Err(Ok(Error::Unauthorized))
+    );
+    let table_id = client.add_table(&operator, &5, &10, &100, &1000, &2);
+    client.pause_table(&table_id, &true);
+    assert_eq!(env.auths()[0].0, operator);
+    // The operator runs tables but not the contract.
+    assert_eq!(
+        client.try_set_payment_controller(&operator, &None),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_initialize(&operator, &client.get_config()),
+        Err(Ok(Error::Unauthorized))
+    );
+    // Initialization happens once; the owner changes the config with set_config.
+    assert_eq!(
+        client.try_initialize(&owner, &client.get_config()),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+    let mut config = client.get_config();
+    config.rake_percentage = 300;
+    client.set_config(&config);
+    assert_eq!(env.auths()[0].0, owner);
+    assert_eq!(client.get_config().rake_percentage, 300);
+
+    // Ownership moves only once the new owner accepts.
+    assert_eq!(client.try_accept_owner(), Err(Ok(Error::NoPendingOwner)));
+    client.propose_owner(&Some(next_owner.clone()));
+    assert_eq!(client.get_pending_owner(), Some(next_owner.clone()));
+    assert_eq!(client.get_admin(), owner);
+    client.accept_owner();
+    assert_eq!(env.auths()[0].0, next_owner);
+    assert_eq!(client.get_admin(), next_owner);
+    assert_eq!(client.get_pending_owner(), None);
+
+    client.propose_owner(&Some(owner.clone()));
+    client.propose_owner(&None);
+    assert_eq!(client.try_accept_owner(), Err(Ok(Error::NoPendingOwner)));
+
+    // Clearing a role hands it back to the (new) owner.
+    client.set_role(&Role::Operator, &None);
+    assert_eq!(client.get_role(&Role::Operator), next_owner);
+    client.add_table(&next_owner, &5, &10, &100, &1000, &2);
+}
+
+#[test]
+fn test_upgrade_migrates_old_layout_in_batches() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let none = Vec::new(&env);
+    assert_eq!(client.version(), SCHEMA_VERSION);
+    assert_eq!(client.migrate(&10, &none), 0);
+
+    let player3 = Address::generate(&env);
+    let table_id = client.add_table(&client.get_admin(), &5, &10, &100, &1000, &2);
+    client.sit_at_table(&player3, &table_id, &200);
+    let playing = client.create_game(&player1, &0u64, &1000);
+    client.join_game(&player2, &playing);
+    let waiting = client.create_game(&player1, &0u64, &500);
+    let config = client.get_config();
+
+    // Put everything back the way version 1 stored it, in its own types: a hand in progress
+    // (player1 raised to 20 over player2's 10; the pot holds both buy-ins and the bets), a
+    // waiting game, a session under SessionGame, a config without the later settings and one
+    // waiting player per table, all tables in instance storage. Session 2 was also written under Game(2) on its first move, as
+    // version 1 did, while game 2 is the next sequential id.
+    let mut in_hand = v1_game(&env, playing, GameState::PreFlop, &player1, Some(&player2), 1000);
+    in_hand.pot = 2030;
+    (in_hand.current_bet_p1, in_hand.total_bet_p1) = (20, 20);
+    (in_hand.current_bet_p2, in_hand.total_bet_p2) = (10, 10);
+    in_hand.actor = 1;
+    let session = v1_game(&env, 7, GameState::ShuffleCommit, &player2, Some(&player3), 300);
+    let shadowed = v1_game(&env, 2, GameState::ShuffleCommit, &player1, Some(&player3), 400);
+    let mut shadow = shadowed.clone();
+    shadow.seed_commitment1 = Some(BytesN::from_array(&env, &[1u8; 32]));
+    env.as_contract(&client.address, || {
+        let temporary = env.storage().temporary();
+        for game_id in [playing, waiting] {
+            temporary.remove(&DataKey::GameHand(game_id));
+            temporary.remove(&DataKey::GameSession(game_id));
+        }
+        temporary.set(&DataKey::Game(playing), &in_hand);
+        let open = v1_game(&env, waiting, GameState::WaitingForPlayers, &player1, None, 500);
+        temporary.set(&DataKey::Game(waiting), &open);
+        temporary.set(&DataKey::SessionGame(7), &session);
+        temporary.set(&DataKey::SessionGame(2), &shadowed);
+        temporary.set(&DataKey::Game(2), &shadow);
+        let instance = env.storage().instance();
+        let persistent = env.storage().persistent();
+        instance.set(&DataKey::NextGameId, &3u64);
+        for id in [0, table_id] {
+            let table: crate::Table = persistent.get(&DataKey::Table(id)).unwrap();
+            persistent.remove(&DataKey::Table(id));
+            instance.set(&DataKey::Table(id), &table);
+        }
+        let queue: Vec<WaitingSession> = persistent.get(&DataKey::TableQueue(table_id)).unwrap();
+        persistent.remove(&DataKey::TableQueue(table_id));
+        instance.set(&DataKey::TableWaiting(table_id), &queue.get(0).unwrap());
+        let old_config = GameConfigV1 {
+            min_buy_in: config.min_buy_in,
+            max_buy_in: config.max_buy_in,
+            small_blind: config.small_blind,
+            big_blind: config.big_blind,
+            rake_percentage: config.rake_percentage,
+            reveal_timeout: config.reveal_timeout,
+            bet_timeout: config.bet_timeout,
+            waiting_timeout: config.waiting_timeout,
+            treasury: config.treasury.clone(),
+            game_hub: config.game_hub.clone(),
+            payment_controller: config.payment_controller.clone(),
+            proof_verifier: config.proof_verifier.clone(),
+        };
+        instance.set(&DataKey::Config, &old_config);
+        instance.remove(&DataKey::SchemaVersion);
+    });
+    assert_eq!(client.version(), 1);
+
+    // Old entries are converted as they are read: the later settings take their defaults, and
+    // each player's stack is the buy-in less what they have bet.
+    let upgraded = client.get_config();
+    assert_eq!((upgraded.timebank, upgraded.timebank_refill), (60, 10));
+    assert_eq!(upgraded.abandon_policy, AbandonPolicy::Refund);
+    assert_eq!(upgraded.rake_percentage, config.rake_percentage);
+    let game = client.get_game(&playing);
+    assert_eq!((game.pot, game.stack1, game.stack2), (30, 980, 990));
+    assert_eq!((game.bought_in1, game.bought_in2), (1000, 1000));
+    assert_eq!((game.format, game.timebank1), (MatchFormat::Cash, 60));
+    let open = client.get_game(&waiting);
+    assert_eq!((open.pot, open.stack1, open.stack2, open.bought_in2), (0, 500, 0, 0));
+    let moved = client.get_game(&(SESSION_ID_BASE + 7));
+    assert_eq!((moved.id, moved.stack1, moved.stack2), (SESSION_ID_BASE + 7, 300, 300));
+    assert_eq!(client.get_table_waiting(&table_id).player1, player3);
+    assert_eq!(client.get_table(&table_id).max_buy_in, 1000);
+    // Session 2 is one game, under its session id; Game(2) is not a second one.
+    assert_eq!(client.try_get_game(&2u64), Err(Ok(Error::GameNotFound)));
+    let both = client.get_game(&(SESSION_ID_BASE + 2));
+    assert_eq!((both.player1.clone(), both.seed_commitment1.clone()), (player1.clone(), None));
+    let before = (game, open, moved);
+
+    // The config first, then two tables, three games (the third moves session 2) and session 7.
+    assert_eq!(client.migrate(&2, &vec![&env, 7u64]), 4);
+    assert_eq!(client.version(), 1);
+    env.as_contract(&client.address, || {
+        let instance = env.storage().instance();
+        let stored: GameConfig = instance.get(&DataKey::Config).unwrap();
+        assert_eq!(stored, upgraded);
+        let persistent = env.storage().persistent();
+        assert!(!instance.has(&DataKey::TableWaiting(table_id)));
+        assert!(persistent.has(&DataKey::TableQueue(table_id)));
+        assert!(!instance.has(&DataKey::Table(table_id)));
+        assert!(persistent.has(&DataKey::Table(table_id)));
+        assert!(env.storage().temporary().has(&DataKey::Game(playing)));
+    });
+    assert_eq!(client.get_config(), upgraded);
+    assert_eq!(client.migrate(&3, &none), 1);
+    env.as_contract(&client.address, || {
+        let temporary = env.storage().temporary();
+        assert!(!temporary.has(&DataKey::Game(2)));
+        assert!(!temporary.has(&DataKey::SessionGame(2)));
+        assert!(!temporary.has(&DataKey::GameHand(2)));
+        assert!(temporary.has(&DataKey::GameHand(SESSION_ID_BASE + 2)));
+    });
+    assert_eq!(client.get_game(&(SESSION_ID_BASE + 2)), both);
+    assert_eq!(client.try_get_game(&2u64), Err(Ok(Error::GameNotFound)));
+    // Repeated session ids are only moved once.
+    assert_eq!(client.migrate(&1, &vec![&env, 7u64]), 0);
+    assert_eq!(client.version(), SCHEMA_VERSION);
+    env.as_contract(&client.address, || {
+        let temporary = env.storage().temporary();
+        for game_id in [playing, waiting, SESSION_ID_BASE + 7] {
+            assert!(!temporary.has(&DataKey::Game(game_id)));
+            assert!(temporary.has(&DataKey::GameHand(game_id)));
+            assert!(temporary.has(&DataKey::GameSession(game_id)));
+        }
+        assert!(!temporary.has(&DataKey::SessionGame(7)));
+        assert!(!env.storage().instance().has(&DataKey::MigrationCursor));
+    });
+    let after = (
+        client.get_game(&playing),
+        client.get_game(&waiting),
+        client.get_game(&(SESSION_ID_BASE + 7)),
+    );
+    assert_eq!(after, before);
+    assert_eq!(client.migrate(&10, &none), 0);
+
+    // The migrated contract plays on: the converted hand settles from its stacks.
+    client.act(&player2, &playing, &Action::Fold, &0, &None, &None);
+    let game = client.get_game(&playing);
+    assert_eq!((game.stack1, game.stack2), (1010, 990));
+    let r = client.sit_at_table(&player1, &table_id, &200);
+    assert!(!r.waiting);
+    assert_eq!(client.get_game(&r.game_id).player1, player3);
+
+    // Code older than the stored layout refuses to touch it.
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &(SCHEMA_VERSION + 1));
+    });
+    assert_eq!(client.try_migrate(&10, &none), Err(Ok(Error::SchemaTooNew)));
+}
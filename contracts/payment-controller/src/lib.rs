@@ -2,19 +2,233 @@
 
 //! PaymentController: XLM escrow and payouts for the poker game.
 //! Only the configured game-manager contract may call payout_winner and refund_on_timeout.
+//! The owner (set at deploy) configures the contract once and assigns the guardian, who can
+//! pause it, and the treasurer, who withdraws the collected rake.
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, token, Address, Env, symbol_short,
+    contract, contracterror, contractevent, contractimpl, contracttype, token, Address, Env,
 };
 
+/// Accounts with a single duty besides the owner. A role nobody holds falls to the owner.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Role {
+    /// Can pause the contract.
+    Guardian = 0,
+    /// Withdraws the collected rake.
+    Treasurer = 1,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
+    /// The owner.
     Admin,
     Token,
-    Treasury,
     GameManager,
+    /// Treasury set by initialize in earlier versions, read as the Treasurer until set_role.
+    Treasury,
+    /// Escrowed balance of a player (persistent; instance storage in earlier versions).
     Escrow(Address),
+    /// Guardian of earlier versions, read as the Guardian until set_role.
+    Guardian,
+    /// Set while lock_funds is paused.
+    Paused,
+    Role(Role),
+    /// Owner proposed by propose_owner, until they accept.
+    PendingOwner,
+    /// Rake collected by payout_winner and not yet withdrawn.
+    Rake,
+    /// Payouts and refunds held for a player who keeps them here (persistent). lock_funds
+    /// draws on it before the player's wallet.
+    Balance(Address),
+    /// Set (persistent) for players who keep their payouts and refunds here.
+    KeepBalance(Address),
+}
+
+const ESCROW_TTL_LEDGERS: u32 = 518_400;
+const TTL_THRESHOLD: u32 = ESCROW_TTL_LEDGERS / 2;
+
+fn load_escrow(env: &Env, player: &Address) -> i128 {
+    let key = DataKey::Escrow(player.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .or_else(|| env.storage().instance().get(&key))
+        .unwrap_or(0)
+}
+
+/// Write a player's balance to persistent storage (dropping any instance-storage copy), so the
+/// contract instance does not grow with every player.
+fn save_escrow(env: &Env, player: &Address, balance: i128) {
+    let key = DataKey::Escrow(player.clone());
+    env.storage().instance().remove(&key);
+    if balance == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, &balance);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, ESCROW_TTL_LEDGERS);
+    env.storage()
+        .instance()
+        .extend_ttl(TTL_THRESHOLD, ESCROW_TTL_LEDGERS);
+}
+
+fn load_balance(env: &Env, player: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Balance(player.clone()))
+        .unwrap_or(0)
+}
+
+fn save_balance(env: &Env, player: &Address, balance: i128) {
+    let key = DataKey::Balance(player.clone());
+    if balance == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, &balance);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, ESCROW_TTL_LEDGERS);
+}
+
+/// Send `amount` to a player: into their balance here if they keep it, else to their wallet.
+fn pay_player(
+    env: &Env,
+    token: &token::Client,
+    player: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    if env.storage().persistent().has(&DataKey::KeepBalance(player.clone())) {
+        let balance = load_balance(env, player)
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        save_balance(env, player, balance);
+    } else {
+        token.transfer(&env.current_contract_address(), player, &amount);
+    }
+    Ok(())
+}
+
+// Events keep the layout clients already decode: a short symbol, the #[topic] fields, then the
+// data as a single value (or a vec when there are several).
+
+#[contractevent(topics = ["OWN_PROP"], data_format = "single-value")]
+pub struct OwnerProposed {
+    pub new_owner: Option<Address>,
+}
+
+#[contractevent(topics = ["OWN_XFER"], data_format = "vec")]
+pub struct OwnerTransferred {
+    pub previous: Address,
+    pub owner: Address,
+}
+
+#[contractevent(topics = ["ROLE"], data_format = "single-value")]
+pub struct RoleSet {
+    #[topic]
+    pub role: Role,
+    pub account: Option<Address>,
+}
+
+#[contractevent(topics = ["PAUSE"], data_format = "single-value")]
+pub struct ContractPaused {
+    pub by: Address,
+}
+
+#[contractevent(topics = ["UNPAUSE"], data_format = "single-value")]
+pub struct ContractUnpaused {
+    pub by: Address,
+}
+
+#[contractevent(topics = ["LOCK"], data_format = "single-value")]
+pub struct FundsLocked {
+    #[topic]
+    pub amount: i128,
+    pub player: Address,
+}
+
+#[contractevent(topics = ["PAYOUT"], data_format = "single-value")]
+pub struct WinnerPaid {
+    #[topic]
+    pub payout: i128,
+    #[topic]
+    pub rake: i128,
+    pub winner: Address,
+}
+
+#[contractevent(topics = ["REFUND"], data_format = "single-value")]
+pub struct Refunded {
+    #[topic]
+    pub amount: i128,
+    pub player: Address,
+}
+
+#[contractevent(topics = ["KEEP"], data_format = "single-value")]
+pub struct KeepBalanceSet {
+    #[topic]
+    pub keep: bool,
+    pub player: Address,
+}
+
+#[contractevent(topics = ["WITHDRAW"], data_format = "single-value")]
+pub struct Withdrawn {
+    #[topic]
+    pub amount: i128,
+    pub player: Address,
+}
+
+#[contractevent(topics = ["RAKE_OUT"], data_format = "single-value")]
+pub struct RakeWithdrawn {
+    #[topic]
+    pub amount: i128,
+    pub to: Address,
+}
+
+fn load_owner(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(Error::ConfigNotSet)
+}
+
+fn require_owner(env: &Env) -> Result<Address, Error> {
+    let owner = load_owner(env)?;
+    owner.require_auth();
+    Ok(owner)
+}
+
+/// Key under which earlier versions kept the account now holding `role`.
+fn legacy_role_key(role: Role) -> DataKey {
+    match role {
+        Role::Guardian => DataKey::Guardian,
+        Role::Treasurer => DataKey::Treasury,
+    }
+}
+
+/// Holder of `role`: as assigned, else as set by an earlier version, else the owner.
+fn role_holder(env: &Env, role: Role) -> Result<Address, Error> {
+    let storage = env.storage().instance();
+    match storage
+        .get(&DataKey::Role(role))
+        .or_else(|| storage.get(&legacy_role_key(role)))
+    {
+        Some(holder) => Ok(holder),
+        None => load_owner(env),
+    }
+}
+
+fn load_token(env: &Env) -> Result<token::Client<'_>, Error> {
+    let token_addr: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Token)
+        .ok_or(Error::ConfigNotSet)?;
+    Ok(token::Client::new(env, &token_addr))
 }
 
 #[contracterror]
@@ -25,6 +239,10 @@ pub enum Error {
     ConfigNotSet = 2,
     InsufficientBalance = 3,
     InvalidAmount = 4,
+    Paused = 5,
+    NotPaused = 6,
+    AlreadyInitialized = 7,
+    NoPendingOwner = 8,
 }
 
 #[contract]
@@ -32,7 +250,13 @@ pub struct PaymentController;
 
 #[contractimpl]
 impl PaymentController {
-    /// Initialize: token (native XLM address), treasury, and game-manager (authorized caller for payout/refund).
+    /// Deploy with the owner, so nobody else can claim the contract before it is initialized.
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    /// Initialize once (owner only): token (native XLM address), treasurer, and game-manager
+    /// (authorized caller for payout/refund). `admin` must be the owner set at deploy.
     pub fn initialize(
         env: Env,
         admin: Address,
@@ -40,37 +264,133 @@ impl PaymentController {
         treasury: Address,
         game_manager: Address,
     ) -> Result<(), Error> {
-        if env.storage().instance().has(&DataKey::Admin) {
-            admin.require_auth();
+        let owner = require_owner(&env)?;
+        if admin != owner {
+            return Err(Error::Unauthorized);
+        }
+        if env.storage().instance().has(&DataKey::Token) {
+            return Err(Error::AlreadyInitialized);
         }
-        env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Token, &token);
-        env.storage().instance().set(&DataKey::Treasury, &treasury);
+        env.storage()
+            .instance()
+            .set(&DataKey::Role(Role::Treasurer), &treasury);
         env.storage().instance().set(&DataKey::GameManager, &game_manager);
         Ok(())
     }
 
-    /// Lock funds in escrow. Caller must be the player (require_auth).
+    pub fn get_owner(env: Env) -> Result<Address, Error> {
+        load_owner(&env)
+    }
+
+    /// Start handing the contract to `new_owner` (owner only); None withdraws the proposal.
+    /// Nothing changes until the new owner calls accept_owner.
+    pub fn propose_owner(env: Env, new_owner: Option<Address>) -> Result<(), Error> {
+        require_owner(&env)?;
+        match new_owner {
+            Some(ref o) => env.storage().instance().set(&DataKey::PendingOwner, o),
+            None => env.storage().instance().remove(&DataKey::PendingOwner),
+        }
+        OwnerProposed { new_owner }.publish(&env);
+        Ok(())
+    }
+
+    /// The proposed owner takes over.
+    pub fn accept_owner(env: Env) -> Result<(), Error> {
+        let pending: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingOwner)
+            .ok_or(Error::NoPendingOwner)?;
+        pending.require_auth();
+        let previous = load_owner(&env)?;
+        env.storage().instance().set(&DataKey::Admin, &pending);
+        env.storage().instance().remove(&DataKey::PendingOwner);
+        OwnerTransferred {
+            previous,
+            owner: pending,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_pending_owner(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingOwner)
+    }
+
+    /// Assign (Some) or clear (None) a role (owner only).
+    pub fn set_role(env: Env, role: Role, account: Option<Address>) -> Result<(), Error> {
+        require_owner(&env)?;
+        let key = DataKey::Role(role);
+        // Replaces the account an earlier version set, so unassigning falls to the owner.
+        env.storage().instance().remove(&legacy_role_key(role));
+        match account {
+            Some(ref a) => env.storage().instance().set(&key, a),
+            None => env.storage().instance().remove(&key),
+        }
+        RoleSet { role, account }.publish(&env);
+        Ok(())
+    }
+
+    /// Who holds a role (the owner when unassigned).
+    pub fn get_role(env: Env, role: Role) -> Result<Address, Error> {
+        role_holder(&env, role)
+    }
+
+    /// Emergency stop (owner or guardian): lock_funds is refused. Payouts and refunds keep
+    /// working, so games in progress can settle and players can get their stakes back.
+    pub fn pause(env: Env, caller: Address) -> Result<(), Error> {
+        caller.require_auth();
+        if caller != load_owner(&env)? && caller != role_holder(&env, Role::Guardian)? {
+            return Err(Error::Unauthorized);
+        }
+        if env.storage().instance().has(&DataKey::Paused) {
+            return Err(Error::Paused);
+        }
+        env.storage().instance().set(&DataKey::Paused, &true);
+        ContractPaused { by: caller }.publish(&env);
+        Ok(())
+    }
+
+    /// Lift the emergency stop (owner only).
+    pub fn unpause(env: Env) -> Result<(), Error> {
+        let owner = require_owner(&env)?;
+        if !env.storage().instance().has(&DataKey::Paused) {
+            return Err(Error::NotPaused);
+        }
+        env.storage().instance().remove(&DataKey::Paused);
+        ContractUnpaused { by: owner }.publish(&env);
+        Ok(())
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        env.storage().instance().has(&DataKey::Paused)
+    }
+
+    /// Lock funds in escrow. Caller must be the player (require_auth). The player's balance
+    /// held here is used first; only the rest comes from their wallet. Refused while paused.
     pub fn lock_funds(env: Env, player: Address, amount: i128) -> Result<(), Error> {
         player.require_auth();
+        if env.storage().instance().has(&DataKey::Paused) {
+            return Err(Error::Paused);
+        }
         if amount <= 0 {
             return Err(Error::InvalidAmount);
         }
-        let token_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Token)
-            .ok_or(Error::ConfigNotSet)?;
-        let token_client = token::Client::new(&env, &token_addr);
-        token_client.transfer(&player, &env.current_contract_address(), &amount);
-        let key = DataKey::Escrow(player.clone());
-        let balance: i128 = env.storage().instance().get(&key).unwrap_or(0);
-        env.storage().instance().set(&key, &(balance + amount));
-        env.events().publish((symbol_short!("LOCK"), amount), player);
+        let balance = load_balance(&env, &player);
+        let reused = balance.min(amount);
+        if reused < amount {
+            let token_client = load_token(&env)?;
+            token_client.transfer(&player, env.current_contract_address(), &(amount - reused));
+        }
+        save_balance(&env, &player, balance - reused);
+        save_escrow(&env, &player, load_escrow(&env, &player) + amount);
+        FundsLocked { amount, player }.publish(&env);
         Ok(())
     }
 
-    /// Payout winner and rake. Only game-manager may call.
+    /// Pay the winner (see set_keep_balance) and collect the rake, which stays here until the
+    /// treasurer withdraws it. Only game-manager may call.
     pub fn payout_winner(
         env: Env,
         winner: Address,
@@ -86,28 +406,25 @@ impl PaymentController {
         if payout < 0 || rake < 0 {
             return Err(Error::InvalidAmount);
         }
-        let token_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Token)
-            .ok_or(Error::ConfigNotSet)?;
-        let token_client = token::Client::new(&env, &token_addr);
+        let token_client = load_token(&env)?;
         if payout > 0 {
-            token_client.transfer(&env.current_contract_address(), &winner, &payout);
+            pay_player(&env, &token_client, &winner, payout)?;
         }
         if rake > 0 {
-            let treasury: Address = env
-                .storage()
-                .instance()
-                .get(&DataKey::Treasury)
-                .ok_or(Error::ConfigNotSet)?;
-            token_client.transfer(&env.current_contract_address(), &treasury, &rake);
-        }
-        env.events().publish((symbol_short!("PAYOUT"), payout, rake), winner);
+            let collected: i128 = env.storage().instance().get(&DataKey::Rake).unwrap_or(0);
+            let collected = collected.checked_add(rake).ok_or(Error::InvalidAmount)?;
+            env.storage().instance().set(&DataKey::Rake, &collected);
+        }
+        WinnerPaid {
+            payout,
+            rake,
+            winner,
+        }
+        .publish(&env);
         Ok(())
     }
 
-    /// Refund player on timeout/cancel. Only game-manager may call.
+    /// Refund player on timeout/cancel (see set_keep_balance). Only game-manager may call.
     pub fn refund_on_timeout(env: Env, player: Address, amount: i128) -> Result<(), Error> {
         let game_manager: Address = env
             .storage()
@@ -118,26 +435,77 @@ impl PaymentController {
         if amount <= 0 {
             return Err(Error::InvalidAmount);
         }
-        let key = DataKey::Escrow(player.clone());
-        let balance: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        let balance = load_escrow(&env, &player);
         if balance < amount {
             return Err(Error::InsufficientBalance);
         }
-        let token_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Token)
-            .ok_or(Error::ConfigNotSet)?;
-        let token_client = token::Client::new(&env, &token_addr);
-        token_client.transfer(&env.current_contract_address(), &player, &amount);
-        env.storage().instance().set(&key, &(balance - amount));
-        env.events().publish((symbol_short!("REFUND"), amount), player);
+        let token_client = load_token(&env)?;
+        pay_player(&env, &token_client, &player, amount)?;
+        save_escrow(&env, &player, balance - amount);
+        Refunded { amount, player }.publish(&env);
         Ok(())
     }
 
     pub fn get_escrow_balance(env: Env, player: Address) -> Result<i128, Error> {
-        let key = DataKey::Escrow(player);
-        Ok(env.storage().instance().get(&key).unwrap_or(0))
+        Ok(load_escrow(&env, &player))
+    }
+
+    /// Choose whether the player's payouts and refunds stay here as their balance (reused by
+    /// their next lock_funds, e.g. a rematch) instead of going to their wallet.
+    pub fn set_keep_balance(env: Env, player: Address, keep: bool) {
+        player.require_auth();
+        let key = DataKey::KeepBalance(player.clone());
+        if keep {
+            env.storage().persistent().set(&key, &true);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, TTL_THRESHOLD, ESCROW_TTL_LEDGERS);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        KeepBalanceSet { keep, player }.publish(&env);
+    }
+
+    /// Payouts and refunds held for the player.
+    pub fn get_balance(env: Env, player: Address) -> i128 {
+        load_balance(&env, &player)
+    }
+
+    /// Send part of the player's held balance to their wallet. Works while paused.
+    pub fn withdraw(env: Env, player: Address, amount: i128) -> Result<(), Error> {
+        player.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let balance = load_balance(&env, &player);
+        if balance < amount {
+            return Err(Error::InsufficientBalance);
+        }
+        load_token(&env)?.transfer(&env.current_contract_address(), &player, &amount);
+        save_balance(&env, &player, balance - amount);
+        Withdrawn { amount, player }.publish(&env);
+        Ok(())
+    }
+
+    /// Send collected rake to `to` (treasurer only).
+    pub fn withdraw_rake(env: Env, to: Address, amount: i128) -> Result<(), Error> {
+        let treasurer = role_holder(&env, Role::Treasurer)?;
+        treasurer.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let collected: i128 = env.storage().instance().get(&DataKey::Rake).unwrap_or(0);
+        if collected < amount {
+            return Err(Error::InsufficientBalance);
+        }
+        load_token(&env)?.transfer(&env.current_contract_address(), &to, &amount);
+        env.storage().instance().set(&DataKey::Rake, &(collected - amount));
+        RakeWithdrawn { amount, to }.publish(&env);
+        Ok(())
+    }
+
+    pub fn get_rake_balance(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey::Rake).unwrap_or(0)
     }
 }
 
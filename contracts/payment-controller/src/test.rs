@@ -1,8 +1,10 @@
 #![cfg(test)]
 
-use crate::{PaymentController, PaymentControllerClient};
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, MuxedAddress};
+use crate::{DataKey, Error, PaymentController, PaymentControllerClient, Role};
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, vec, Address, Env, IntoVal, MuxedAddress,
+};
 
 /// Minimal mock token for tests: balance and transfer only.
 #[contract]
@@ -66,7 +68,7 @@ fn setup(
     let mock_token_client = MockTokenClient::new(env, &token_id);
     mock_token_client.mint(&player, &10_000);
 
-    let pc_id = env.register(PaymentController, ());
+    let pc_id = env.register(PaymentController, (&admin,));
     let pc_client = PaymentControllerClient::new(env, &pc_id);
     pc_client.initialize(&admin, &token_id, &treasury, &game_manager);
 
@@ -101,6 +103,40 @@ fn test_payout_winner() {
     client.payout_winner(&player, &1_800, &200);
     // Escrow balance is not decremented by payout_winner (contract only transfers token)
     assert_eq!(client.get_escrow_balance(&player), 2_000);
+    assert_eq!(client.get_rake_balance(), 200);
+}
+
+#[test]
+fn test_events_keep_their_tuple_layout() {
+    let env = Env::default();
+    let (client, admin, _treasury, _gm, player, _token) = setup(&env);
+    client.lock_funds(&player, &2_000);
+    client.payout_winner(&player, &1_800, &200);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("PAYOUT"), 1_800i128, 200i128).into_val(&env),
+                player.into_val(&env),
+            ),
+        ]
+    );
+    let new_owner = Address::generate(&env);
+    client.propose_owner(&Some(new_owner.clone()));
+    client.accept_owner();
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("OWN_XFER"),).into_val(&env),
+                (admin, new_owner).into_val(&env),
+            ),
+        ]
+    );
 }
 
 #[test]
@@ -112,3 +148,163 @@ fn test_refund_on_timeout() {
     assert_eq!(client.get_escrow_balance(&player), 0);
 }
 
+
+#[test]
+fn test_escrow_moves_out_of_instance_storage() {
+    let env = Env::default();
+    let (client, _admin, _treasury, _gm, player, token) = setup(&env);
+    let key = DataKey::Escrow(player.clone());
+    // Balance written to instance storage by an earlier version.
+    MockTokenClient::new(&env, &token).mint(&client.address, &700);
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&key, &700i128);
+    });
+    assert_eq!(client.get_escrow_balance(&player), 700);
+    client.lock_funds(&player, &300);
+    assert_eq!(client.get_escrow_balance(&player), 1_000);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().instance().has(&key));
+        assert_eq!(env.storage().persistent().get(&key), Some(1_000i128));
+    });
+    client.refund_on_timeout(&player, &1_000);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().persistent().has(&key));
+    });
+}
+
+#[test]
+fn test_pause_stops_locks_but_not_refunds() {
+    let env = Env::default();
+    let (client, _admin, _treasury, _gm, player, token) = setup(&env);
+    let guardian = Address::generate(&env);
+    client.lock_funds(&player, &500);
+
+    assert_eq!(client.try_pause(&guardian), Err(Ok(Error::Unauthorized)));
+    client.set_role(&Role::Guardian, &Some(guardian.clone()));
+    client.pause(&guardian);
+    assert!(client.is_paused());
+    assert_eq!(client.try_pause(&guardian), Err(Ok(Error::Paused)));
+    assert_eq!(client.try_lock_funds(&player, &100), Err(Ok(Error::Paused)));
+
+    client.refund_on_timeout(&player, &200);
+    client.payout_winner(&player, &100, &0);
+    assert_eq!(client.get_escrow_balance(&player), 300);
+    assert_eq!(MockTokenClient::new(&env, &token).balance(&player), 9_800);
+
+    client.unpause();
+    assert_eq!(client.try_unpause(), Err(Ok(Error::NotPaused)));
+    client.lock_funds(&player, &100);
+    assert_eq!(client.get_escrow_balance(&player), 400);
+}
+
+#[test]
+fn test_initialize_once_and_two_step_ownership() {
+    let env = Env::default();
+    let (client, admin, treasury, game_manager, _player, token) = setup(&env);
+    assert_eq!(
+        client.try_initialize(&admin, &token, &treasury, &game_manager),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_initialize(&stranger, &token, &stranger, &stranger),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let new_owner = Address::generate(&env);
+    assert_eq!(client.try_accept_owner(), Err(Ok(Error::NoPendingOwner)));
+    client.propose_owner(&Some(new_owner.clone()));
+    assert_eq!(client.get_owner(), admin);
+    assert_eq!(client.get_pending_owner(), Some(new_owner.clone()));
+    client.accept_owner();
+    assert_eq!(client.get_owner(), new_owner);
+    assert_eq!(client.get_pending_owner(), None);
+    // From now on it is the new owner who authorizes.
+    client.propose_owner(&None);
+    assert_eq!(env.auths()[0].0, new_owner);
+}
+
+#[test]
+fn test_treasurer_withdraws_rake() {
+    let env = Env::default();
+    let (client, admin, treasury, _gm, player, token) = setup(&env);
+    assert_eq!(client.get_role(&Role::Treasurer), treasury);
+    assert_eq!(client.get_role(&Role::Guardian), admin);
+    client.lock_funds(&player, &1_000);
+    client.payout_winner(&player, &900, &100);
+    assert_eq!(
+        client.try_withdraw_rake(&treasury, &101),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    let vault = Address::generate(&env);
+    client.withdraw_rake(&vault, &60);
+    assert_eq!(env.auths()[0].0, treasury);
+    assert_eq!(client.get_rake_balance(), 40);
+    assert_eq!(MockTokenClient::new(&env, &token).balance(&vault), 60);
+}
+
+#[test]
+fn test_roles_set_by_earlier_versions_are_kept() {
+    let env = Env::default();
+    let (client, admin, treasury, _gm, player, _token) = setup(&env);
+    let guardian = Address::generate(&env);
+    // Before roles, initialize stored the treasury and set_guardian the guardian directly.
+    env.as_contract(&client.address, || {
+        let storage = env.storage().instance();
+        storage.remove(&DataKey::Role(Role::Treasurer));
+        storage.set(&DataKey::Treasury, &treasury);
+        storage.set(&DataKey::Guardian, &guardian);
+    });
+    assert_eq!(client.get_role(&Role::Treasurer), treasury);
+    assert_eq!(client.get_role(&Role::Guardian), guardian);
+    client.lock_funds(&player, &1_000);
+    client.payout_winner(&player, &900, &100);
+    client.withdraw_rake(&treasury, &100);
+    assert_eq!(env.auths()[0].0, treasury);
+    client.pause(&guardian);
+
+    client.set_role(&Role::Guardian, &None);
+    assert_eq!(client.get_role(&Role::Guardian), admin);
+    let vault = Address::generate(&env);
+    client.set_role(&Role::Treasurer, &Some(vault.clone()));
+    assert_eq!(client.get_role(&Role::Treasurer), vault);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().instance().has(&DataKey::Treasury));
+        assert!(!env.storage().instance().has(&DataKey::Guardian));
+    });
+}
+
+#[test]
+fn test_kept_balance_is_reused_by_the_next_lock() {
+    let env = Env::default();
+    let (client, _admin, _treasury, _gm, player, token) = setup(&env);
+    let token = MockTokenClient::new(&env, &token);
+    client.lock_funds(&player, &1_000);
+    client.set_keep_balance(&player, &true);
+    client.payout_winner(&player, &1_500, &0);
+    client.refund_on_timeout(&player, &200);
+    assert_eq!(client.get_balance(&player), 1_700);
+    assert_eq!(token.balance(&player), 9_000);
+
+    // A rematch buy-in comes out of the balance before the wallet.
+    client.lock_funds(&player, &1_000);
+    assert_eq!(client.get_balance(&player), 700);
+    assert_eq!(token.balance(&player), 9_000);
+    client.lock_funds(&player, &1_000);
+    assert_eq!(client.get_balance(&player), 0);
+    assert_eq!(token.balance(&player), 8_700);
+    assert_eq!(client.get_escrow_balance(&player), 2_800);
+
+    client.set_keep_balance(&player, &false);
+    client.refund_on_timeout(&player, &500);
+    assert_eq!(token.balance(&player), 9_200);
+    client.set_keep_balance(&player, &true);
+    client.refund_on_timeout(&player, &300);
+    assert_eq!(
+        client.try_withdraw(&player, &301),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    client.withdraw(&player, &300);
+    assert_eq!(client.get_balance(&player), 0);
+    assert_eq!(token.balance(&player), 9_500);
+}
@@ -1,6 +1,7 @@
 /**
- * Initialize the poker-game-manager contract with admin + GameConfig.
- * Required after deploy so that sit_at_table / create_game work (ConfigNotSet is cleared).
+ * Set the GameConfig of the poker-game-manager contract (owner only). The constructor sets the
+ * owner and a default config at deploy; initialize only works once, so later changes go
+ * through set_config.
  *
  * Usage (from poker-zk-stellar root):
  *   DEPLOYER_SECRET="S..." bun run frontend/scripts/init-game-manager.ts
@@ -106,12 +107,12 @@ async function main() {
 
   console.log('Contract:', contractId);
   console.log('Admin:', adminAddress);
-  console.log('Calling initialize(admin, config)...');
+  console.log('Calling set_config(config)...');
 
-  const tx = await client.initialize({ admin: adminAddress, config });
+  const tx = await client.set_config({ config });
   const simulated = await tx.simulate();
   await simulated.signAndSend();
-  console.log('Done. initialize(admin, config) was sent. Recarrega o frontend e tenta "Sentar" de novo.');
+  console.log('Done. set_config(config) was sent. Recarrega o frontend e tenta "Sentar" de novo.');
 }
 
 main().catch((e) => {
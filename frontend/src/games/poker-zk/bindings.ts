@@ -32,41 +32,6 @@ if (typeof window !== "undefined") {
 
 
 
-export interface Game {
-  actor: u32;
-  big_blind: i128;
-  board: Array<u32>;
-  board_revealed: u32;
-  buy_in: i128;
-  created_at: u64;
-  current_bet_p1: i128;
-  current_bet_p2: i128;
-  dealer_position: u32;
-  final_seed: Option<Buffer>;
-  folded: Option<string>;
-  hand_commitment1: Option<Buffer>;
-  hand_commitment2: Option<Buffer>;
-  hand_rank1: Option<u32>;
-  hand_rank2: Option<u32>;
-  id: u64;
-  last_action_at: u64;
-  last_raise_amount: i128;
-  min_raise: i128;
-  player1: string;
-  player2: Option<string>;
-  pot: i128;
-  seed_commitment1: Option<Buffer>;
-  seed_commitment2: Option<Buffer>;
-  seed_reveal1: Option<Buffer>;
-  seed_reveal2: Option<Buffer>;
-  small_blind: i128;
-  state: GameState;
-  table_id: u64;
-  total_bet_p1: i128;
-  total_bet_p2: i128;
-  winner: Option<string>;
-}
-
 export const Errors = {
   1: {message:"GameNotFound"},
   2: {message:"NotPlayer"},
@@ -95,12 +60,50 @@ export const Errors = {
   25: {message:"TableNotFound"},
   26: {message:"NoWaitingSession"},
   27: {message:"WaitingTimeoutNotReached"},
-  28: {message:"MessageTooLong"}
+  28: {message:"MessageTooLong"},
+  29: {message:"TournamentNotFound"},
+  30: {message:"InvalidTournamentConfig"},
+  31: {message:"RegistrationClosed"},
+  32: {message:"RegistrationOpen"},
+  33: {message:"AlreadyRegistered"},
+  34: {message:"TournamentFull"},
+  35: {message:"HandInProgress"},
+  36: {message:"InsufficientChips"},
+  37: {message:"StillHasChips"},
+  38: {message:"RebuyNotAllowed"},
+  39: {message:"HandMismatch"},
+  40: {message:"WaitingListFull"},
+  41: {message:"NotInvited"},
+  42: {message:"InviteExpired"},
+  43: {message:"InviteUsed"},
+  44: {message:"InviteRevoked"},
+  45: {message:"NoRematchOffer"},
+  46: {message:"InvalidSessionId"},
+  47: {message:"GameExpired"},
+  48: {message:"AdminNotSet"},
+  49: {message:"HubNotSet"},
+  50: {message:"NoOpponent"},
+  51: {message:"NotCommitted"},
+  52: {message:"ChipOverflow"},
+  53: {message:"InvalidBlinds"},
+  54: {message:"InvalidBuyInRange"},
+  55: {message:"InvalidSeats"},
+  56: {message:"TablePaused"},
+  57: {message:"TableRetired"},
+  58: {message:"ContractPaused"},
+  59: {message:"NotPaused"},
+  60: {message:"GracePeriodNotOver"},
+  61: {message:"Unauthorized"},
+  62: {message:"NoPendingOwner"},
+  63: {message:"SchemaTooNew"},
+  64: {message:"GameIdsExhausted"},
+  65: {message:"AlreadyInitialized"},
+  66: {message:"AllowlistTooLong"}
 }
 
 
 /**
- * A table (mesa) defines blinds, buy-in limits, and max seats. First table in constructor; more via add_table (admin only).
+ * A table (mesa) defines blinds, buy-in limits, and max seats. First table in constructor; more via add_table (operator only).
  */
 export interface Table {
   big_blind: i128;
@@ -113,14 +116,205 @@ max_seats: u32;
   small_blind: i128;
 }
 
-export enum Action {
-  Fold = 0,
-  Check = 1,
-  Call = 2,
-  Raise = 3,
+/**
+ * Accounts with a single duty besides the owner (the admin). A role nobody holds falls to the
+ * owner.
+ */
+export enum Role {
+  /**
+ * Adds, edits, pauses and retires tables and creates tournaments.
+ */
+Operator = 0,
+  /**
+ * Can pause the contract.
+ */
+Guardian = 1,
+}
+
+/**
+ * Whether a table takes new seats. A paused table can be reopened, a retired one cannot.
+ * Games already running at the table play on either way.
+ */
+export enum TableStatus {
+  Open = 0,
+  Paused = 1,
+  Retired = 2,
 }
 
-export type DataKey = {tag: "Admin", values: void} | {tag: "Config", values: void} | {tag: "GameHubAddress", values: void} | {tag: "NextGameId", values: void} | {tag: "NextTableId", values: void} | {tag: "Table", values: readonly [u64]} | {tag: "TableWaiting", values: readonly [u64]} | {tag: "Game", values: readonly [u64]} | {tag: "SessionGame", values: readonly [u64]};
+
+/**
+ * Lobby metadata for a table: display name, game variant (e.g. "NLHE") and the currency its
+ * chips are counted in (e.g. "XLM"). Tables without metadata have an empty name and currency
+ * and variant NLHE.
+ */
+export interface TableMeta {
+  currency: string;
+  name: string;
+  variant: string;
+}
+
+/**
+ * How a heads-up session ends. Cash sessions run until a player leaves; a freezeout runs
+ * until one player has all the chips; BestOf(n) ends when a player has won a majority of n
+ * hands (or the other is out of chips). Match winners take both stacks.
+ */
+export type MatchFormat = {tag: "Cash", values: void} | {tag: "Freezeout", values: void} | {tag: "BestOf", values: readonly [u32]};
+
+/**
+ * What happens when a player stops responding during the shuffle (no cards dealt yet).
+ * Refund: both players get their stacks back. Penalty(n): the abandoning player forfeits
+ * up to n chips to the opponent and the rest is refunded. The game is Cancelled, with no winner.
+ */
+export type AbandonPolicy = {tag: "Refund", values: void} | {tag: "Penalty", values: readonly [i128]};
+
+/**
+ * Where a keeper bounty is taken from. Forfeit: the stack of the player who timed out.
+ * Treasury: the rake the game has collected so far (the treasury's share).
+ */
+export enum BountySource {
+  Forfeit = 0,
+  Treasury = 1,
+}
+
+
+/**
+ * Reward for calling advance_timeout on a stalled cash game. Off until the admin sets it.
+ */
+export interface KeeperBounty {
+  /**
+ * Paid to the caller per successful advance_timeout.
+ */
+amount: i128;
+  /**
+ * Most one game pays out in bounties over its whole session.
+ */
+cap: i128;
+  source: BountySource;
+}
+
+/**
+ * Who may play at a table, or be paired with a waiting player.
+ * Allowlist: only the named addresses. Password: players must reveal a secret whose sha256
+ * is the stored hash (sit_at_table_with).
+ */
+export type TableAccess = {tag: "Public", values: void} | {tag: "Allowlist", values: readonly [Array<string>]} | {tag: "Password", values: readonly [Buffer]};
+
+
+/**
+ * Player1's signed offer to start a session with start_game_with_invite. `opponent` None
+ * means anyone may accept. Valid up to ledger `expires_ledger`; `nonce` makes it single-use
+ * and lets player1 revoke it with revoke_invite.
+ */
+export interface Invite {
+  buy_in: i128;
+  expires_ledger: u32;
+  nonce: u64;
+  opponent: Option<string>;
+  player1: string;
+  table_id: u64;
+}
+
+
+/**
+ * A player's offer to play the same opponent again after a session ends. The offered buy-in
+ * is locked in escrow until the offer is accepted or cancelled.
+ */
+export interface RematchOffer {
+  buy_in: i128;
+  created_at: u64;
+  player: string;
+}
+
+
+/**
+ * One player waiting at a table. When a compatible opponent sits, a game is created.
+ * If nobody joins within waiting_timeout, anyone can cancel and player1 is refunded.
+ */
+export interface WaitingSession {
+  buy_in: i128;
+  created_at: u64;
+  player1: string;
+}
+
+
+/**
+ * Lobby view of a table: its definition and current occupancy.
+ */
+export interface TableInfo {
+  /**
+ * Games at this table that are not finished or cancelled.
+ */
+active_games: u32;
+  meta: TableMeta;
+  status: TableStatus;
+  table: Table;
+  table_id: u64;
+  /**
+ * Players waiting for an opponent at this table.
+ */
+waiting: Array<WaitingSession>;
+}
+
+
+/**
+ * One page of games. `next` is the cursor (game id) for the following page, None at the end.
+ */
+export interface GamePage {
+  games: Array<Game>;
+  next: Option<u64>;
+}
+
+
+/**
+ * Response times (seconds) per phase. A table can override the global GameConfig values so
+ * turbo and deep-stack tables can coexist.
+ */
+export interface TimeoutConfig {
+  betting: u64;
+  /**
+ * Time for the remaining player to submit their proof once the opponent has revealed.
+ */
+proof_submission: u64;
+  seed_commit: u64;
+  seed_reveal: u64;
+  /**
+ * Time for the first hand reveal at showdown.
+ */
+showdown: u64;
+  /**
+ * Seconds after which a waiting session can be closed by anyone. 0 = no timeout.
+ */
+waiting: u64;
+}
+
+
+/**
+ * Who is on the clock and until when. In betting rounds `deadline` includes the player's
+ * remaining timebank; `timebank` is what they have left right now.
+ */
+export interface TurnClock {
+  deadline: u64;
+  player: string;
+  timebank: u64;
+}
+
+
+/**
+ * A player's seat in a table's waiting list.
+ */
+export interface WaitingSeat {
+  session: WaitingSession;
+  table_id: u64;
+}
+
+
+/**
+ * Result of sit_at_table: waiting (1/2) or game started. If !waiting then game_id is set.
+ */
+export interface SitResult {
+  game_id: u64;
+  waiting: boolean;
+}
 
 export enum GameState {
   WaitingForPlayers = 0,
@@ -136,17 +330,19 @@ export enum GameState {
   Cancelled = 10,
 }
 
-
-/**
- * Result of sit_at_table: waiting (1/2) or game started. If !waiting then game_id is set.
- */
-export interface SitResult {
-  game_id: u64;
-  waiting: boolean;
+export enum Action {
+  Fold = 0,
+  Check = 1,
+  Call = 2,
+  Raise = 3,
 }
 
 
 export interface GameConfig {
+  /**
+ * Settlement when a cash game is abandoned during the shuffle.
+ */
+abandon_policy: AbandonPolicy;
   bet_timeout: u64;
   big_blind: i128;
   game_hub: string;
@@ -163,6 +359,14 @@ proof_verifier: Option<string>;
   rake_percentage: u32;
   reveal_timeout: u64;
   small_blind: i128;
+  /**
+ * Per-player timebank (seconds) on top of bet_timeout in betting rounds; also its cap.
+ */
+timebank: u64;
+  /**
+ * Seconds added back to each player's timebank at every new hand.
+ */
+timebank_refill: u64;
   treasury: string;
   /**
  * Seconds after which a waiting session (1/2) can be closed; player1 refunded. 0 = no timeout.
@@ -171,14 +375,168 @@ waiting_timeout: u64;
 }
 
 
+export interface Game {
+  actor: u32;
+  big_blind: i128;
+  board: Array<u32>;
+  board_revealed: u32;
+  /**
+ * Total each player has brought to the session (buy-in plus rebuys and top-ups).
+ */
+bought_in1: i128;
+  bought_in2: i128;
+  /**
+ * Keeper bounties paid out of this game so far (bounded by KeeperBounty.cap).
+ */
+bounty_paid: i128;
+  buy_in: i128;
+  created_at: u64;
+  current_bet_p1: i128;
+  current_bet_p2: i128;
+  dealer_position: u32;
+  final_seed: Option<Buffer>;
+  folded: Option<string>;
+  format: MatchFormat;
+  hand_commitment1: Option<Buffer>;
+  hand_commitment2: Option<Buffer>;
+  hand_rank1: Option<u32>;
+  hand_rank2: Option<u32>;
+  /**
+ * Hands won by each player this session.
+ */
+hands_won1: u32;
+  hands_won2: u32;
+  id: u64;
+  last_action_at: u64;
+  last_raise_amount: i128;
+  min_raise: i128;
+  player1: string;
+  player2: Option<string>;
+  /**
+ * Chips in the middle for the current hand.
+ */
+pot: i128;
+  /**
+ * Rake taken from pots this session; paid to the treasury when the session settles.
+ */
+rake: i128;
+  seed_commitment1: Option<Buffer>;
+  seed_commitment2: Option<Buffer>;
+  seed_reveal1: Option<Buffer>;
+  seed_reveal2: Option<Buffer>;
+  small_blind: i128;
+  /**
+ * Chips behind for each player (not yet in the pot).
+ */
+stack1: i128;
+  stack2: i128;
+  state: GameState;
+  table_id: u64;
+  /**
+ * Seconds of timebank left for each player.
+ */
+timebank1: u64;
+  timebank2: u64;
+  total_bet_p1: i128;
+  total_bet_p2: i128;
+  winner: Option<string>;
+}
+
+
 /**
- * One player waiting at a table. When 2nd sits, game is created.
- * If nobody joins within waiting_timeout, anyone can cancel and player1 is refunded.
+ * Compact record of a game, archived in persistent storage once it is finished or cancelled
+ * so the result outlives the full Game entry.
  */
-export interface WaitingSession {
+export interface GameSummary {
+  bought_in1: i128;
+  bought_in2: i128;
   buy_in: i128;
   created_at: u64;
+  ended_at: u64;
+  format: MatchFormat;
+  hands_won1: u32;
+  hands_won2: u32;
+  id: u64;
   player1: string;
+  player2: Option<string>;
+  state: GameState;
+  table_id: u64;
+  winner: Option<string>;
+}
+
+export enum TournamentState {
+  Registering = 0,
+  Running = 1,
+  Finished = 2,
+  Cancelled = 3,
+}
+
+
+/**
+ * One step of a tournament blind schedule.
+ */
+export interface BlindLevel {
+  big_blind: i128;
+  small_blind: i128;
+}
+
+
+/**
+ * A tournament table. Games are heads-up, so a table seats at most two players; a lone
+ * survivor waits here until balancing brings an opponent from another table.
+ */
+export interface TournamentTable {
+  /**
+ * Game currently being played at this table (None while fewer than 2 are seated).
+ */
+game_id: Option<u64>;
+  players: Array<string>;
+}
+
+
+/**
+ * Tournament parameters, fixed at creation.
+ */
+export interface TournamentConfig {
+  blind_levels: Array<BlindLevel>;
+  entry_fee: i128;
+  /**
+ * Seconds per blind level (0 = first level for the whole tournament). Tables pick up
+ * the level in force when their game starts.
+ */
+level_duration: u64;
+  max_entrants: u32;
+  /**
+ * Prize share per finishing position in basis points; index 0 is the winner.
+ */
+payouts: Array<u32>;
+  registration_deadline: u64;
+  starting_chips: i128;
+  /**
+ * Table definition the tournament is played on (variant/stakes template).
+ */
+table_id: u64;
+}
+
+
+/**
+ * Multi-table freezeout. Players register until the deadline, are seated at random when it
+ * starts, and are eliminated when they lose their chips. Tables are merged as players bust
+ * until one final table decides the winner; prizes are paid by finishing position.
+ */
+export interface Tournament {
+  chips: Map<string, i128>;
+  config: TournamentConfig;
+  /**
+ * Busted players in elimination order (first out first).
+ */
+eliminated: Array<string>;
+  entrants: Array<string>;
+  id: u64;
+  remaining: u32;
+  started_at: u64;
+  state: TournamentState;
+  tables: Array<TournamentTable>;
 }
 
 export interface Client {
@@ -187,36 +545,112 @@ export interface Client {
    */
   act: ({player, game_id, action, raise_amount, proof, commitment}: {player: string, game_id: u64, action: Action, raise_amount: i128, proof: Option<Buffer>, commitment: Option<Buffer>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Emergency stop (owner or guardian): no new seats, games, joins, rematches, rebuys or
+   * tournament entries, so no new funds are locked. Games in progress play on and can
+   * still be cancelled and refunded.
+   */
+  pause: ({caller}: {caller: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a rebuy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Rebuy after busting: between hands, a player with no chips buys back in for an amount
+   * within the table's buy-in limits. Funds are locked in escrow.
+   */
+  rebuy: ({player, game_id, amount}: {player: string, game_id: u64, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a top_up transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Top up a stack between hands, up to the table's max_buy_in. Funds are locked in escrow.
+   */
+  top_up: ({player, game_id, amount}: {player: string, game_id: u64, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Owner: rewrite entries stored in an older layout, handling at most `limit` table ids, game
+   * ids and sessions per call (capped at 100) so each call fits in a transaction. The config
+   * is rewritten by the first call. Sequential games are found by scanning their ids;
+   * start_game sessions cannot be, so pass their session ids in `session_ids` (on any call
+   * before the step ends; repeats are ignored). Returns how many ids and sessions are left;
+   * the stored version moves up once that reaches 0. Until then old entries are still read
+   * as they are, and any write to a game also moves it.
+   */
+  migrate: ({limit, session_ids}: {limit: u32, session_ids: Array<u64>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
   /**
    * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lift the emergency stop (owner only).
+   */
+  unpause: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replace the contract code (owner only). If the new code has a higher SCHEMA_VERSION, call
+   * migrate until version() reports it.
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Storage layout in use: SCHEMA_VERSION once every entry has been migrated.
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
 
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   get_game: ({game_id}: {game_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
+  /**
+   * Construct and simulate a get_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Who holds a role (the owner when unassigned).
+   */
+  get_role: ({role}: {role: Role}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a set_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Assign (Some) or clear (None) a role (owner only).
+   */
+  set_role: ({role, account}: {role: Role, account: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a add_table transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Add a new table (mesa). Only admin. max_seats e.g. 2 (MVP) or 4.
+   * Add a new table (mesa). Operator only (the owner until an operator is assigned). max_seats e.g. 2 (MVP) or 4.
    */
   add_table: ({admin, small_blind, big_blind, min_buy_in, max_buy_in, max_seats}: {admin: string, small_blind: i128, big_blind: i128, min_buy_in: i128, max_buy_in: i128, max_seats: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
+  /**
+   * Construct and simulate a bump_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Extend the storage of a game (and its archived summary) to the full game TTL. Anyone can
+   * pay for it; active games are also extended whenever they are read or written.
+   */
+  bump_game: ({game_id}: {game_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The owner.
+   */
+  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a get_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lobby: active games (not finished or cancelled), optionally only those in `state`, from
+   * position `cursor` of the active index (0 for the first page). Continue from `next` for
+   * the following page. A game that settles hands its position to the last listed game, so
+   * a walk through the pages can miss a game that moved meanwhile.
    */
-  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  get_games: ({state, cursor, limit}: {state: Option<GameState>, cursor: u64, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<GamePage>>
 
   /**
    * Construct and simulate a get_table transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -237,26 +671,54 @@ export interface Client {
   send_chat: ({sender, game_id, message}: {sender: string, game_id: u64, message: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a close_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Close a finished or cancelled game: its archived summary stays and the full entry is
+   * deleted, so no rent is paid on it. get_game keeps answering from the summary. Anyone
+   * can close a settled game.
    */
-  set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  close_game: ({game_id}: {game_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a get_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   get_config: (options?: MethodOptions) => Promise<AssembledTransaction<Result<GameConfig>>>
 
+  /**
+   * Construct and simulate a get_tables transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lobby: tables `start..start+limit` (limit capped at MAX_PAGE) with their waiting seat
+   * and number of active games.
+   */
+  get_tables: ({start, limit}: {start: u64, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<TableInfo>>>
+
   /**
    * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Initialize with admin and config (or use __constructor(admin, game_hub) for minimal).
+   * Set the admin and config of a contract that has neither (__constructor sets both, so
+   * deployed contracts change their config with set_config). Fails with AlreadyInitialized
+   * once both are set. It cannot change the owner (see propose_owner).
    */
   initialize: ({admin, config}: {admin: string, config: GameConfig}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a leave_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Leave a cash session between hands (or a game nobody has joined yet). Both stacks and
+   * the session rake are paid out of escrow, the game ends and the hub session is closed;
+   * the player who came out ahead (stack minus chips bought) is recorded as the winner.
+   * Leaving a freezeout or best-of-N match forfeits it to the opponent.
+   */
+  leave_game: ({player, game_id}: {player: string, game_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replace the config (owner only). The hub follows config.game_hub.
+   */
+  set_config: ({config}: {config: GameConfig}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * [Deprecated] Use start_game_with_invite: this auth has no expiry and is not bound to player2.
    * Start a game with a client-provided session ID (XDR / Game Studio flow).
    * P1 signs auth for (session_id, table_id, buy_in); P2 submits the tx with P1's auth injected.
-   * Game is stored under SessionGame(session_id); get_game(session_id) returns it.
+   * `session_id` must be below HUB_SEQUENTIAL_BASE; the game id is get_session_game_id(session_id).
    */
   start_game: ({session_id, table_id, player1, player2, buy_in}: {session_id: u64, table_id: u64, player1: string, player2: string, buy_in: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
@@ -270,11 +732,31 @@ export interface Client {
    */
   create_game: ({player, table_id, buy_in}: {player: string, table_id: u64, buy_in: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
+  /**
+   * Construct and simulate a get_rematch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pending rematch offer on a game.
+   */
+  get_rematch: ({game_id}: {game_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<RematchOffer>>>
+
+  /**
+   * Construct and simulate a pause_table transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stop (paused = true) or resume new seats at a table (operator only). Waiting players stay
+   * queued and games in progress play on.
+   */
+  pause_table: ({table_id, paused}: {table_id: u64, paused: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a post_blinds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   post_blinds: ({game_id}: {game_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a prune_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Drop index entries for games whose storage has expired, checking up to `limit` entries
+   * from position `cursor`. Anyone may call. Returns how many were removed.
+   */
+  prune_games: ({cursor, limit}: {cursor: u64, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
   /**
    * Construct and simulate a reveal_hand transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Reveal hand at showdown. Calls ProofVerifier when configured.
@@ -286,35 +768,141 @@ export interface Client {
    */
   reveal_seed: ({player, game_id, seed}: {player: string, game_id: u64, seed: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a accept_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The proposed owner takes over.
+   */
+  accept_owner: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a retire_table transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Close a table for good (operator only). It takes no new seats, its waiting list is
+   * refunded and games in progress play on to the end. The table is then deleted: at once
+   * if it has no games in progress, otherwise when the last one settles.
+   */
+  retire_table: ({table_id}: {table_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a sit_at_table transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Sit at a table. If nobody waiting → you're 1/2 (waiting). If one waiting → game starts (2/2), returns game_id.
+   * Sit at a table. You are paired with the waiting player whose buy-in is closest (oldest first on
+   * ties), if it is within the table's tolerance, and a game starts with the effective stack (the
+   * smaller buy-in), returning game_id. Otherwise you join the table's waiting list. Many pairs can
+   * play at the same table.
    * Conforme vão entrando usuários, montam-se as mesas virtuais; a mesa inicia quando tem 2 jogadores.
    */
   sit_at_table: ({player, table_id, buy_in}: {player: string, table_id: u64, buy_in: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<SitResult>>>
 
+  /**
+   * Construct and simulate a update_table transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Change a table's blinds, buy-in limits or seats (operator only). Games already started
+   * keep the stakes they started with; players already waiting keep their buy-in.
+   */
+  update_table: ({table_id, table}: {table_id: u64, table: Table}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a claim_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * [Deprecated] Use advance_timeout. Reclamar timeout (jogador inativo perde). Mantido por compatibilidade.
    */
   claim_timeout: ({caller, game_id}: {caller: string, game_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a get_paused_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * When the contract was paused, None while it runs.
+   */
+  get_paused_at: (options?: MethodOptions) => Promise<AssembledTransaction<Option<u64>>>
+
+  /**
+   * Construct and simulate a mutual_cancel transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel a cash game by agreement. Both players authorize `(game_id, hand)`, where `hand`
+   * is the number of hands already settled (`hands_won1 + hands_won2`), so an agreement
+   * cannot be replayed on a later hand. Bets in the current hand go back to their owners,
+   * each stake is refunded from escrow and the game ends Cancelled with no winner.
+   */
+  mutual_cancel: ({game_id, hand}: {game_id: u64, hand: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a offer_rematch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Offer the opponent of a settled cash game a rematch at the same table and stakes. The
+   * game's buy-in is locked now, so the opponent alone starts the rematch with
+   * accept_rematch. A game can be rematched once.
+   */
+  offer_rematch: ({player, game_id}: {player: string, game_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a propose_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start handing the contract to `new_owner` (owner only); None withdraws the proposal.
+   * Nothing changes until the new owner calls accept_owner.
+   */
+  propose_owner: ({new_owner}: {new_owner: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a revoke_invite transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revoke an unused invite (player1 only). Pass the invite so the revocation lasts until it
+   * would have expired.
+   */
+  revoke_invite: ({invite}: {invite: Invite}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a accept_rematch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept the opponent's rematch offer: a new game starts with the same players, table and
+   * stakes, and the dealer button moves on as if it were the next hand. The offering
+   * player's buy-in was locked with the offer, the accepting player's is locked now; each
+   * lock reuses what the PaymentController still holds for the player (set_keep_balance).
+   * The table must still be open and take the offer's buy-in; otherwise the offer can only
+   * be cancelled. Returns the new game id.
+   */
+  accept_rematch: ({player, game_id}: {player: string, game_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a cancel_rematch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw a rematch offer with a refund. The offering player can do so anytime; anyone
+   * else once the table's waiting timeout has passed since the offer.
+   */
+  cancel_rematch: ({caller, game_id}: {caller: string, game_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a cancel_waiting transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Cancel a waiting session (1/2). Frees the table for new players.
-   * - If caller is the waiting player (player1): can cancel anytime → refund and clear.
-   * - Else: only after waiting_timeout seconds → refund player1 and clear (so the mesa can be "encerrada" after a period).
+   * Leave a table's waiting list, with a refund.
+   * - If caller is waiting at the table: removes their own seat, anytime.
+   * - Else: removes every seat older than the table's waiting timeout (so the mesa can be "encerrada" after a period).
    */
   cancel_waiting: ({caller, table_id}: {caller: string, table_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a get_table_meta transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_table_meta: ({table_id}: {table_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<TableMeta>>>
+
+  /**
+   * Construct and simulate a get_tournament transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Tournament state: entrants, chip counts, tables, eliminations and remaining count.
+   */
+  get_tournament: ({tournament_id}: {tournament_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Tournament>>>
+
+  /**
+   * Construct and simulate a get_turn_clock transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Who must act now, their deadline and remaining timebank.
+   */
+  get_turn_clock: ({game_id}: {game_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<TurnClock>>>
+
+  /**
+   * Construct and simulate a set_table_meta transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a table's lobby metadata (operator only). Names are at most 64 characters.
+   */
+  set_table_meta: ({table_id, meta}: {table_id: u64, meta: TableMeta}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a advance_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Quando o tempo de resposta do jogador acaba, o jogo avança automaticamente:
    * - Em apostas: se não há aposta a pagar → CHECK automático; senão → FOLD automático. O jogo continua.
-   * - Em commit/reveal ou showdown: o jogador que não agiu perde a mão (outro ganha).
+   * - Em commit/reveal: o jogo é cancelado e os stakes devolvidos (config.abandon_policy pode
+   * aplicar uma multa a quem abandonou). Em mesas de torneio quem abandonou perde o stack.
+   * - Em showdown: o jogador que não agiu perde a mão (outro ganha).
    * Qualquer um pode chamar (ex.: adversário ou frontend) para o jogo não ficar travado.
+   * Se houver KeeperBounty configurado, `caller` recebe o bounty, exceto se for um dos
+   * endereços da mesa (verificação de melhor esforço: uma segunda conta recebe como keeper).
    */
-  advance_timeout: ({game_id}: {game_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  advance_timeout: ({caller, game_id}: {caller: string, game_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a get_table_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -322,17 +910,199 @@ export interface Client {
    */
   get_table_count: (options?: MethodOptions) => Promise<AssembledTransaction<u64>>
 
+  /**
+   * Construct and simulate a get_table_queue transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * All players waiting at a table, oldest first.
+   */
+  get_table_queue: ({table_id}: {table_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<WaitingSession>>>>
+
+  /**
+   * Construct and simulate a get_active_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Games the player is in (not finished or cancelled), so a client can resume after a reload.
+   */
+  get_active_games: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a get_game_summary transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Summary of a game: the archived record once it is over, otherwise built from the game.
+   */
+  get_game_summary: ({game_id}: {game_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<GameSummary>>>
+
+  /**
+   * Construct and simulate a get_recent_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The player's last finished or cancelled games, newest first.
+   */
+  get_recent_games: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a get_table_access transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_table_access: ({table_id}: {table_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<TableAccess>>>
+
+  /**
+   * Construct and simulate a get_table_format transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_table_format: ({table_id}: {table_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<MatchFormat>>>
+
+  /**
+   * Construct and simulate a get_table_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_table_status: ({table_id}: {table_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<TableStatus>>>
+
+  /**
+   * Construct and simulate a get_waiting_seat transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Where the player is waiting for an opponent (their earliest seat if they wait at
+   * several tables). Returns error when not seated.
+   */
+  get_waiting_seat: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<WaitingSeat>>>
+
+  /**
+   * Construct and simulate a set_table_access transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Make a table private (Allowlist or Password) or public again (operator only). Seats and
+   * games already started are not affected.
+   */
+  set_table_access: ({table_id, access}: {table_id: u64, access: TableAccess}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_table_format transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the match format for new games at a table (operator only). Games keep the format
+   * they started with.
+   */
+  set_table_format: ({table_id, format}: {table_id: u64, format: MatchFormat}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_tournament transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a tournament once registration has closed (anyone may call). Entrants are seated
+   * at random, two per table. With fewer than 2 entrants it is cancelled and fees refunded.
+   */
+  start_tournament: ({tournament_id}: {tournament_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a create_tournament transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Create a tournament (operator only). Registration stays open until registration_deadline.
+   */
+  create_tournament: ({config}: {config: TournamentConfig}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a get_expired_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lobby: active games whose player on the clock is out of time (advance_timeout applies).
+   * Paged like get_games.
+   */
+  get_expired_games: ({cursor, limit}: {cursor: u64, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<GamePage>>>
+
+  /**
+   * Construct and simulate a get_keeper_bounty transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_keeper_bounty: (options?: MethodOptions) => Promise<AssembledTransaction<Option<KeeperBounty>>>
+
+  /**
+   * Construct and simulate a get_pending_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_pending_owner: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
   /**
    * Construct and simulate a get_table_waiting transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get current waiting session for a table (1/2). Returns error when 0/2.
+   * Oldest player waiting at a table. Returns error when nobody waits.
    */
   get_table_waiting: ({table_id}: {table_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<WaitingSession>>>
 
+  /**
+   * Construct and simulate a set_keeper_bounty transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set or clear the keeper bounty paid by advance_timeout (admin only).
+   */
+  set_keeper_bounty: ({bounty}: {bounty: Option<KeeperBounty>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a sit_at_table_with transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * sit_at_table for private play: `secret` is revealed to pass a Password table or seat, and
+   * `seat_access` restricts who may be paired with you if you end up waiting. A private table
+   * rejects anyone it does not admit with NotInvited; a private seat is only paired with players
+   * it admits (others queue as usual).
+   */
+  sit_at_table_with: ({player, table_id, buy_in, secret, seat_access}: {player: string, table_id: u64, buy_in: i128, secret: Option<Buffer>, seat_access: TableAccess}, options?: MethodOptions) => Promise<AssembledTransaction<Result<SitResult>>>
+
+  /**
+   * Construct and simulate a emergency_withdraw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Once the contract has been paused for EMERGENCY_GRACE, either player can end a cash
+   * game on their own: bets in the current hand go back, each stake is refunded from escrow
+   * (chips won beyond it are paid out) and the game is Cancelled. A game still waiting for
+   * an opponent refunds its creator. Tournament games are not covered: their chips are the
+   * tournament's prize pool.
+   */
+  emergency_withdraw: ({player, game_id}: {player: string, game_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_table_timeouts transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Timeouts in force at a table (its override or the global config).
+   */
+  get_table_timeouts: ({table_id}: {table_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<TimeoutConfig>>>
+
+  /**
+   * Construct and simulate a set_table_timeouts transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Override (Some) or clear (None) a table's timeouts. Operator only. Phase timeouts must be
+   * non-zero; waiting = 0 keeps waiting sessions open until cancelled by their player.
+   */
+  set_table_timeouts: ({table_id, timeouts}: {table_id: u64, timeouts: Option<TimeoutConfig>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_session_game_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Game id of the session started with `session_id` (SESSION_ID_BASE + session_id).
+   */
+  get_session_game_id: ({session_id}: {session_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a get_table_tolerance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_table_tolerance: ({table_id}: {table_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a register_tournament transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register for a tournament before its deadline. Locks the entry fee in escrow.
+   */
+  register_tournament: ({player, tournament_id}: {player: string, tournament_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_table_tolerance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set how far apart two buy-ins may be (bps of the larger one) to be paired at a table
+   * (operator only). 0 pairs only equal buy-ins.
+   */
+  set_table_tolerance: ({table_id, tolerance_bps}: {table_id: u64, tolerance_bps: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_tournament_level transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Blind level in force now (the first level until the tournament starts).
+   */
+  get_tournament_level: ({tournament_id}: {tournament_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<BlindLevel>>>
+
   /**
    * Construct and simulate a set_payment_controller transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set or clear the payment controller (escrow). Admin only. Enables lock_funds on sit/create/join and payout_winner on game end.
+   * Set or clear the payment controller (escrow). Owner only. Enables lock_funds on sit/create/join and payout_winner on game end.
    */
-  set_payment_controller: ({admin, payment_controller}: {admin: string, payment_controller: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  set_payment_controller: ({admin, payment_controller}: {admin: string, payment_controller: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_game_with_invite transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a session from player1's invite (XDR flow): player1 signs (session_id, invite) and
+   * player2 submits. Fails with NotInvited if the invite names another opponent, InviteExpired
+   * after `expires_ledger`, and InviteUsed / InviteRevoked once consumed or revoked.
+   * Returns the game id.
+   */
+  start_game_with_invite: ({session_id, player2, invite}: {session_id: u64, player2: string, invite: Invite}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a get_tournament_remaining transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Players still in the tournament (chips > 0).
+   */
+  get_tournament_remaining: ({tournament_id}: {tournament_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<string>>>>
+
+  /**
+   * Construct and simulate a restart_tournament_table transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deal a new game at a tournament table whose game was lost because its storage expired
+   * (anyone may call). The players go on with the chips they had after their last settled
+   * hand, so the hand in progress is void; its hub session is closed for the chip leader.
+   * Returns the new game id.
+   */
+  restart_tournament_table: ({tournament_id, table_idx}: {tournament_id: u64, table_idx: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
 }
 export class Client extends ContractClient {
@@ -354,71 +1124,207 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAgAAAAAAAAAAVhY3RvcgAAAAAAAAQAAAAAAAAACWJpZ19ibGluZAAAAAAAAAsAAAAAAAAABWJvYXJkAAAAAAAD6gAAAAQAAAAAAAAADmJvYXJkX3JldmVhbGVkAAAAAAAEAAAAAAAAAAZidXlfaW4AAAAAAAsAAAAAAAAACmNyZWF0ZWRfYXQAAAAAAAYAAAAAAAAADmN1cnJlbnRfYmV0X3AxAAAAAAALAAAAAAAAAA5jdXJyZW50X2JldF9wMgAAAAAACwAAAAAAAAAPZGVhbGVyX3Bvc2l0aW9uAAAAAAQAAAAAAAAACmZpbmFsX3NlZWQAAAAAA+gAAAPuAAAAIAAAAAAAAAAGZm9sZGVkAAAAAAPoAAAAEwAAAAAAAAAQaGFuZF9jb21taXRtZW50MQAAA+gAAAPuAAAAIAAAAAAAAAAQaGFuZF9jb21taXRtZW50MgAAA+gAAAPuAAAAIAAAAAAAAAAKaGFuZF9yYW5rMQAAAAAD6AAAAAQAAAAAAAAACmhhbmRfcmFuazIAAAAAA+gAAAAEAAAAAAAAAAJpZAAAAAAABgAAAAAAAAAObGFzdF9hY3Rpb25fYXQAAAAAAAYAAAAAAAAAEWxhc3RfcmFpc2VfYW1vdW50AAAAAAAACwAAAAAAAAAJbWluX3JhaXNlAAAAAAAACwAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAA+gAAAATAAAAAAAAAANwb3QAAAAACwAAAAAAAAAQc2VlZF9jb21taXRtZW50MQAAA+gAAAPuAAAAIAAAAAAAAAAQc2VlZF9jb21taXRtZW50MgAAA+gAAAPuAAAAIAAAAAAAAAAMc2VlZF9yZXZlYWwxAAAD6AAAA+4AAAAgAAAAAAAAAAxzZWVkX3JldmVhbDIAAAPoAAAD7gAAACAAAAAAAAAAC3NtYWxsX2JsaW5kAAAAAAsAAAAAAAAABXN0YXRlAAAAAAAH0AAAAAlHYW1lU3RhdGUAAAAAAAAAAAAACHRhYmxlX2lkAAAABgAAAAAAAAAMdG90YWxfYmV0X3AxAAAACwAAAAAAAAAMdG90YWxfYmV0X3AyAAAACwAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAHAAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMSW52YWxpZFN0YXRlAAAAAwAAAAAAAAAOQ2Fubm90UGxheVNlbGYAAAAAAAQAAAAAAAAACEdhbWVGdWxsAAAABQAAAAAAAAALQnV5SW5Ub29Mb3cAAAAABgAAAAAAAAAMQnV5SW5Ub29IaWdoAAAABwAAAAAAAAAQQWxyZWFkeUNvbW1pdHRlZAAAAAgAAAAAAAAAD0FscmVhZHlSZXZlYWxlZAAAAAAJAAAAAAAAAAtJbnZhbGlkU2VlZAAAAAAKAAAAAAAAAA9NdXN0Q2FsbE9yUmFpc2UAAAAACwAAAAAAAAANSW52YWxpZEFtb3VudAAAAAAAAAwAAAAAAAAADVJhaXNlVG9vU21hbGwAAAAAAAANAAAAAAAAABJHYW1lQWxyZWFkeURlY2lkZWQAAAAAAA4AAAAAAAAAC05vdFlvdXJUdXJuAAAAAA8AAAAAAAAADEludmFsaWRDYXJkcwAAABAAAAAAAAAAC0ludmFsaWRSYW5rAAAAABEAAAAAAAAAEUludmFsaWRDb21taXRtZW50AAAAAAAAEgAAAAAAAAAMSW52YWxpZFByb29mAAAAEwAAAAAAAAATR2FtZUFscmVhZHlGaW5pc2hlZAAAAAAUAAAAAAAAAA1HYW1lQ2FuY2VsbGVkAAAAAAAAFQAAAAAAAAARVGltZW91dE5vdFJlYWNoZWQAAAAAAAAWAAAAAAAAABNOb1RpbWVvdXRBcHBsaWNhYmxlAAAAABcAAAAAAAAADENvbmZpZ05vdFNldAAAABgAAAAAAAAADVRhYmxlTm90Rm91bmQAAAAAAAAZAAAAAAAAABBOb1dhaXRpbmdTZXNzaW9uAAAAGgAAAAAAAAAYV2FpdGluZ1RpbWVvdXROb3RSZWFjaGVkAAAAGwAAAAAAAAAOTWVzc2FnZVRvb0xvbmcAAAAAABw=",
-        "AAAAAQAAAHlBIHRhYmxlIChtZXNhKSBkZWZpbmVzIGJsaW5kcywgYnV5LWluIGxpbWl0cywgYW5kIG1heCBzZWF0cy4gRmlyc3QgdGFibGUgaW4gY29uc3RydWN0b3I7IG1vcmUgdmlhIGFkZF90YWJsZSAoYWRtaW4gb25seSkuAAAAAAAAAAAAAAVUYWJsZQAAAAAAAAUAAAAAAAAACWJpZ19ibGluZAAAAAAAAAsAAAAAAAAACm1heF9idXlfaW4AAAAAAAsAAABPTWF4IHNlYXRzIHBlciB0YWJsZSAoZS5nLiAyIGZvciBNVlAsIDQgbGF0ZXIpLiBHYW1lIHN0YXJ0cyB3aGVuIG1pbiAyIGhhdmUgc2F0LgAAAAAJbWF4X3NlYXRzAAAAAAAABAAAAAAAAAAKbWluX2J1eV9pbgAAAAAACwAAAAAAAAALc21hbGxfYmxpbmQAAAAACw==",
-        "AAAAAwAAAAAAAAAAAAAABkFjdGlvbgAAAAAABAAAAAAAAAAERm9sZAAAAAAAAAAAAAAABUNoZWNrAAAAAAAAAQAAAAAAAAAEQ2FsbAAAAAIAAAAAAAAABVJhaXNlAAAAAAAAAw==",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAACQAAAAAAAAAAAAAABUFkbWluAAAAAAAAAAAAAAAAAAAGQ29uZmlnAAAAAAAAAAAAAAAAAA5HYW1lSHViQWRkcmVzcwAAAAAAAAAAAAAAAAAKTmV4dEdhbWVJZAAAAAAAAAAAAAAAAAALTmV4dFRhYmxlSWQAAAAAAQAAAAAAAAAFVGFibGUAAAAAAAABAAAABgAAAAEAAABST25lIHdhaXRpbmcgc2Vzc2lvbiBwZXIgdGFibGUgKDEvMikuIENsZWFyZWQgd2hlbiAybmQgcGxheWVyIHNpdHMgYW5kIGdhbWUgc3RhcnRzLgAAAAAADFRhYmxlV2FpdGluZwAAAAEAAAAGAAAAAQAAAAAAAAAER2FtZQAAAAEAAAAGAAAAAQAAAFxHYW1lIGNyZWF0ZWQgdmlhIHN0YXJ0X2dhbWUoc2Vzc2lvbl9pZCwgLi4uKSDigJQgY2xpZW50LXByb3ZpZGVkIHNlc3Npb24gSUQgKFhEUiBqb2luIGZsb3cpLgAAAAtTZXNzaW9uR2FtZQAAAAABAAAABg==",
-        "AAAAAwAAAAAAAAAAAAAACUdhbWVTdGF0ZQAAAAAAAAsAAAAAAAAAEVdhaXRpbmdGb3JQbGF5ZXJzAAAAAAAAAAAAAAAAAAANU2h1ZmZsZUNvbW1pdAAAAAAAAAEAAAAAAAAADVNodWZmbGVSZXZlYWwAAAAAAAACAAAAAAAAAAlEZWFsQ2FyZHMAAAAAAAADAAAAAAAAAAdQcmVGbG9wAAAAAAQAAAAAAAAAC0Zsb3BCZXR0aW5nAAAAAAUAAAAAAAAAC1R1cm5CZXR0aW5nAAAAAAYAAAAAAAAADFJpdmVyQmV0dGluZwAAAAcAAAAAAAAACFNob3dkb3duAAAACAAAAAAAAAAIRmluaXNoZWQAAAAJAAAAAAAAAAlDYW5jZWxsZWQAAAAAAAAK",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAQgAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMSW52YWxpZFN0YXRlAAAAAwAAAAAAAAAOQ2Fubm90UGxheVNlbGYAAAAAAAQAAAAAAAAACEdhbWVGdWxsAAAABQAAAAAAAAALQnV5SW5Ub29Mb3cAAAAABgAAAAAAAAAMQnV5SW5Ub29IaWdoAAAABwAAAAAAAAAQQWxyZWFkeUNvbW1pdHRlZAAAAAgAAAAAAAAAD0FscmVhZHlSZXZlYWxlZAAAAAAJAAAAAAAAAAtJbnZhbGlkU2VlZAAAAAAKAAAAAAAAAA9NdXN0Q2FsbE9yUmFpc2UAAAAACwAAAAAAAAANSW52YWxpZEFtb3VudAAAAAAAAAwAAAAAAAAADVJhaXNlVG9vU21hbGwAAAAAAAANAAAAAAAAABJHYW1lQWxyZWFkeURlY2lkZWQAAAAAAA4AAAAAAAAAC05vdFlvdXJUdXJuAAAAAA8AAAAAAAAADEludmFsaWRDYXJkcwAAABAAAAAAAAAAC0ludmFsaWRSYW5rAAAAABEAAAAAAAAAEUludmFsaWRDb21taXRtZW50AAAAAAAAEgAAAAAAAAAMSW52YWxpZFByb29mAAAAEwAAAAAAAAATR2FtZUFscmVhZHlGaW5pc2hlZAAAAAAUAAAAAAAAAA1HYW1lQ2FuY2VsbGVkAAAAAAAAFQAAAAAAAAARVGltZW91dE5vdFJlYWNoZWQAAAAAAAAWAAAAAAAAABNOb1RpbWVvdXRBcHBsaWNhYmxlAAAAABcAAAAAAAAADENvbmZpZ05vdFNldAAAABgAAAAAAAAADVRhYmxlTm90Rm91bmQAAAAAAAAZAAAAAAAAABBOb1dhaXRpbmdTZXNzaW9uAAAAGgAAAAAAAAAYV2FpdGluZ1RpbWVvdXROb3RSZWFjaGVkAAAAGwAAAAAAAAAOTWVzc2FnZVRvb0xvbmcAAAAAABwAAAAAAAAAElRvdXJuYW1lbnROb3RGb3VuZAAAAAAAHQAAAAAAAAAXSW52YWxpZFRvdXJuYW1lbnRDb25maWcAAAAAHgAAAAAAAAASUmVnaXN0cmF0aW9uQ2xvc2VkAAAAAAAfAAAAAAAAABBSZWdpc3RyYXRpb25PcGVuAAAAIAAAAAAAAAARQWxyZWFkeVJlZ2lzdGVyZWQAAAAAAAAhAAAAAAAAAA5Ub3VybmFtZW50RnVsbAAAAAAAIgAAAAAAAAAOSGFuZEluUHJvZ3Jlc3MAAAAAACMAAAAAAAAAEUluc3VmZmljaWVudENoaXBzAAAAAAAAJAAAAAAAAAANU3RpbGxIYXNDaGlwcwAAAAAAACUAAAAAAAAAD1JlYnV5Tm90QWxsb3dlZAAAAAAmAAAAAAAAAAxIYW5kTWlzbWF0Y2gAAAAnAAAAAAAAAA9XYWl0aW5nTGlzdEZ1bGwAAAAAKAAAAAAAAAAKTm90SW52aXRlZAAAAAAAKQAAAAAAAAANSW52aXRlRXhwaXJlZAAAAAAAACoAAAAAAAAACkludml0ZVVzZWQAAAAAACsAAAAAAAAADUludml0ZVJldm9rZWQAAAAAAAAsAAAAAAAAAA5Ob1JlbWF0Y2hPZmZlcgAAAAAALQAAAAAAAAAQSW52YWxpZFNlc3Npb25JZAAAAC4AAABNVGhlIGdhbWUncyBzdG9yYWdlIGV4cGlyZWQgYmVmb3JlIGl0IHNldHRsZWQ7IGl0cyBzdGFrZXMgYXJlIHN0aWxsIGluIGVzY3Jvdy4AAAAAAAALR2FtZUV4cGlyZWQAAAAALwAAAAAAAAALQWRtaW5Ob3RTZXQAAAAAMAAAAAAAAAAJSHViTm90U2V0AAAAAAAAMQAAACJUaGUgZ2FtZSBoYXMgbm8gc2Vjb25kIHBsYXllciB5ZXQuAAAAAAAKTm9PcHBvbmVudAAAAAAAMgAAADVBIHNlZWQgd2FzIHJldmVhbGVkIGJlZm9yZSBpdHMgY29tbWl0bWVudCB3YXMgc3RvcmVkLgAAAAAAAAxOb3RDb21taXR0ZWQAAAAzAAAAKkEgY2hpcCBhbW91bnQgb3ZlcmZsb3dlZCBvciB3ZW50IG5lZ2F0aXZlLgAAAAAADENoaXBPdmVyZmxvdwAAADQAAAAwU21hbGwgYmxpbmQgbm90IHBvc2l0aXZlIG9yIGFib3ZlIHRoZSBiaWcgYmxpbmQuAAAADUludmFsaWRCbGluZHMAAAAAAAA1AAAAMU1pbmltdW0gYnV5LWluIG5vdCBwb3NpdGl2ZSBvciBhYm92ZSB0aGUgbWF4aW11bS4AAAAAAAARSW52YWxpZEJ1eUluUmFuZ2UAAAAAAAA2AAAAFUZld2VyIHRoYW4gdHdvIHNlYXRzLgAAAAAAAAxJbnZhbGlkU2VhdHMAAAA3AAAAAAAAAAtUYWJsZVBhdXNlZAAAAAA4AAAAAAAAAAxUYWJsZVJldGlyZWQAAAA5AAAAJE5ldyBnYW1lcyBhbmQgbmV3IHN0YWtlcyBhcmUgcGF1c2VkLgAAAA5Db250cmFjdFBhdXNlZAAAAAAAOgAAAAAAAAAJTm90UGF1c2VkAAAAAAAAOwAAADlUaGUgY29udHJhY3QgaGFzIG5vdCBiZWVuIHBhdXNlZCBmb3IgRU1FUkdFTkNZX0dSQUNFIHlldC4AAAAAAAASR3JhY2VQZXJpb2ROb3RPdmVyAAAAAAA8AAAALUNhbGxlciBkb2VzIG5vdCBob2xkIHRoZSByb2xlIHRoZSBjYWxsIG5lZWRzLgAAAAAAAAxVbmF1dGhvcml6ZWQAAAA9AAAAAAAAAA5Ob1BlbmRpbmdPd25lcgAAAAAAPgAAAExTdG9yYWdlIHdhcyB3cml0dGVuIGJ5IGEgbmV3ZXIgdmVyc2lvbiBvZiB0aGUgY29udHJhY3QgdGhhbiB0aGUgb25lIHJ1bm5pbmcuAAAADFNjaGVtYVRvb05ldwAAAD8AAABIRXZlcnkgc2VxdWVudGlhbCBnYW1lIGlkIHRoZSBodWIgY2FuIHRlbGwgYXBhcnQgZnJvbSBhIHNlc3Npb24gaXMgdGFrZW4uAAAAEEdhbWVJZHNFeGhhdXN0ZWQAAABAAAAASmluaXRpYWxpemUgd2FzIGNhbGxlZCBvbiBhIGNvbnRyYWN0IHRoYXQgYWxyZWFkeSBoYXMgaXRzIG93bmVyIGFuZCBjb25maWcuAAAAAAASQWxyZWFkeUluaXRpYWxpemVkAAAAAABBAAAAQkEgdGFibGUgb3Igc2VhdCBhbGxvd2xpc3QgbmFtZXMgbW9yZSB0aGFuIE1BWF9BTExPV0xJU1QgYWRkcmVzc2VzLgAAAAAAEEFsbG93bGlzdFRvb0xvbmcAAABC",
+        "AAAAAQAAAHxBIHRhYmxlIChtZXNhKSBkZWZpbmVzIGJsaW5kcywgYnV5LWluIGxpbWl0cywgYW5kIG1heCBzZWF0cy4gRmlyc3QgdGFibGUgaW4gY29uc3RydWN0b3I7IG1vcmUgdmlhIGFkZF90YWJsZSAob3BlcmF0b3Igb25seSkuAAAAAAAAAAVUYWJsZQAAAAAAAAUAAAAAAAAACWJpZ19ibGluZAAAAAAAAAsAAAAAAAAACm1heF9idXlfaW4AAAAAAAsAAABPTWF4IHNlYXRzIHBlciB0YWJsZSAoZS5nLiAyIGZvciBNVlAsIDQgbGF0ZXIpLiBHYW1lIHN0YXJ0cyB3aGVuIG1pbiAyIGhhdmUgc2F0LgAAAAAJbWF4X3NlYXRzAAAAAAAABAAAAAAAAAAKbWluX2J1eV9pbgAAAAAACwAAAAAAAAALc21hbGxfYmxpbmQAAAAACw==",
+        "AAAAAwAAAGJBY2NvdW50cyB3aXRoIGEgc2luZ2xlIGR1dHkgYmVzaWRlcyB0aGUgb3duZXIgKHRoZSBhZG1pbikuIEEgcm9sZSBub2JvZHkgaG9sZHMgZmFsbHMgdG8gdGhlCm93bmVyLgAAAAAAAAAAAARSb2xlAAAAAgAAAD9BZGRzLCBlZGl0cywgcGF1c2VzIGFuZCByZXRpcmVzIHRhYmxlcyBhbmQgY3JlYXRlcyB0b3VybmFtZW50cy4AAAAACE9wZXJhdG9yAAAAAAAAABdDYW4gcGF1c2UgdGhlIGNvbnRyYWN0LgAAAAAIR3VhcmRpYW4AAAAB",
+        "AAAAAwAAAI1XaGV0aGVyIGEgdGFibGUgdGFrZXMgbmV3IHNlYXRzLiBBIHBhdXNlZCB0YWJsZSBjYW4gYmUgcmVvcGVuZWQsIGEgcmV0aXJlZCBvbmUgY2Fubm90LgpHYW1lcyBhbHJlYWR5IHJ1bm5pbmcgYXQgdGhlIHRhYmxlIHBsYXkgb24gZWl0aGVyIHdheS4AAAAAAAAAAAAAC1RhYmxlU3RhdHVzAAAAAAMAAAAAAAAABE9wZW4AAAAAAAAAAAAAAAZQYXVzZWQAAAAAAAEAAAAAAAAAB1JldGlyZWQAAAAAAg==",
+        "AAAAAQAAAMZMb2JieSBtZXRhZGF0YSBmb3IgYSB0YWJsZTogZGlzcGxheSBuYW1lLCBnYW1lIHZhcmlhbnQgKGUuZy4gIk5MSEUiKSBhbmQgdGhlIGN1cnJlbmN5IGl0cwpjaGlwcyBhcmUgY291bnRlZCBpbiAoZS5nLiAiWExNIikuIFRhYmxlcyB3aXRob3V0IG1ldGFkYXRhIGhhdmUgYW4gZW1wdHkgbmFtZSBhbmQgY3VycmVuY3kKYW5kIHZhcmlhbnQgTkxIRS4AAAAAAAAAAAAJVGFibGVNZXRhAAAAAAAAAwAAAAAAAAAIY3VycmVuY3kAAAARAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAHdmFyaWFudAAAAAAR",
+        "AAAAAgAAAPVIb3cgYSBoZWFkcy11cCBzZXNzaW9uIGVuZHMuIENhc2ggc2Vzc2lvbnMgcnVuIHVudGlsIGEgcGxheWVyIGxlYXZlczsgYSBmcmVlemVvdXQgcnVucwp1bnRpbCBvbmUgcGxheWVyIGhhcyBhbGwgdGhlIGNoaXBzOyBCZXN0T2YobikgZW5kcyB3aGVuIGEgcGxheWVyIGhhcyB3b24gYSBtYWpvcml0eSBvZiBuCmhhbmRzIChvciB0aGUgb3RoZXIgaXMgb3V0IG9mIGNoaXBzKS4gTWF0Y2ggd2lubmVycyB0YWtlIGJvdGggc3RhY2tzLgAAAAAAAAAAAAALTWF0Y2hGb3JtYXQAAAAAAwAAAAAAAAAAAAAABENhc2gAAAAAAAAAAAAAAAlGcmVlemVvdXQAAAAAAAABAAAAAAAAAAZCZXN0T2YAAAAAAAEAAAAE",
+        "AAAAAgAAAQpXaGF0IGhhcHBlbnMgd2hlbiBhIHBsYXllciBzdG9wcyByZXNwb25kaW5nIGR1cmluZyB0aGUgc2h1ZmZsZSAobm8gY2FyZHMgZGVhbHQgeWV0KS4KUmVmdW5kOiBib3RoIHBsYXllcnMgZ2V0IHRoZWlyIHN0YWNrcyBiYWNrLiBQZW5hbHR5KG4pOiB0aGUgYWJhbmRvbmluZyBwbGF5ZXIgZm9yZmVpdHMKdXAgdG8gbiBjaGlwcyB0byB0aGUgb3Bwb25lbnQgYW5kIHRoZSByZXN0IGlzIHJlZnVuZGVkLiBUaGUgZ2FtZSBpcyBDYW5jZWxsZWQsIHdpdGggbm8gd2lubmVyLgAAAAAAAAAAAA1BYmFuZG9uUG9saWN5AAAAAAAAAgAAAAAAAAAAAAAABlJlZnVuZAAAAAAAAQAAAAAAAAAHUGVuYWx0eQAAAAABAAAACw==",
+        "AAAAAwAAAJ1XaGVyZSBhIGtlZXBlciBib3VudHkgaXMgdGFrZW4gZnJvbS4gRm9yZmVpdDogdGhlIHN0YWNrIG9mIHRoZSBwbGF5ZXIgd2hvIHRpbWVkIG91dC4KVHJlYXN1cnk6IHRoZSByYWtlIHRoZSBnYW1lIGhhcyBjb2xsZWN0ZWQgc28gZmFyICh0aGUgdHJlYXN1cnkncyBzaGFyZSkuAAAAAAAAAAAAAAxCb3VudHlTb3VyY2UAAAACAAAAAAAAAAdGb3JmZWl0AAAAAAAAAAAAAAAACFRyZWFzdXJ5AAAAAQ==",
+        "AAAAAQAAAFdSZXdhcmQgZm9yIGNhbGxpbmcgYWR2YW5jZV90aW1lb3V0IG9uIGEgc3RhbGxlZCBjYXNoIGdhbWUuIE9mZiB1bnRpbCB0aGUgYWRtaW4gc2V0cyBpdC4AAAAAAAAAAAxLZWVwZXJCb3VudHkAAAADAAAAMlBhaWQgdG8gdGhlIGNhbGxlciBwZXIgc3VjY2Vzc2Z1bCBhZHZhbmNlX3RpbWVvdXQuAAAAAAAGYW1vdW50AAAAAAALAAAAOk1vc3Qgb25lIGdhbWUgcGF5cyBvdXQgaW4gYm91bnRpZXMgb3ZlciBpdHMgd2hvbGUgc2Vzc2lvbi4AAAAAAANjYXAAAAAACwAAAAAAAAAGc291cmNlAAAAAAfQAAAADEJvdW50eVNvdXJjZQ==",
+        "AAAAAgAAAL1XaG8gbWF5IHBsYXkgYXQgYSB0YWJsZSwgb3IgYmUgcGFpcmVkIHdpdGggYSB3YWl0aW5nIHBsYXllci4KQWxsb3dsaXN0OiBvbmx5IHRoZSBuYW1lZCBhZGRyZXNzZXMuIFBhc3N3b3JkOiBwbGF5ZXJzIG11c3QgcmV2ZWFsIGEgc2VjcmV0IHdob3NlIHNoYTI1NgppcyB0aGUgc3RvcmVkIGhhc2ggKHNpdF9hdF90YWJsZV93aXRoKS4AAAAAAAAAAAAAC1RhYmxlQWNjZXNzAAAAAAMAAAAAAAAAAAAAAAZQdWJsaWMAAAAAAAEAAAAAAAAACUFsbG93bGlzdAAAAAAAAAEAAAPqAAAAEwAAAAEAAAAAAAAACFBhc3N3b3JkAAAAAQAAA+4AAAAg",
+        "AAAAAQAAAN9QbGF5ZXIxJ3Mgc2lnbmVkIG9mZmVyIHRvIHN0YXJ0IGEgc2Vzc2lvbiB3aXRoIHN0YXJ0X2dhbWVfd2l0aF9pbnZpdGUuIGBvcHBvbmVudGAgTm9uZQptZWFucyBhbnlvbmUgbWF5IGFjY2VwdC4gVmFsaWQgdXAgdG8gbGVkZ2VyIGBleHBpcmVzX2xlZGdlcmA7IGBub25jZWAgbWFrZXMgaXQgc2luZ2xlLXVzZQphbmQgbGV0cyBwbGF5ZXIxIHJldm9rZSBpdCB3aXRoIHJldm9rZV9pbnZpdGUuAAAAAAAAAAAGSW52aXRlAAAAAAAGAAAAAAAAAAZidXlfaW4AAAAAAAsAAAAAAAAADmV4cGlyZXNfbGVkZ2VyAAAAAAAEAAAAAAAAAAVub25jZQAAAAAAAAYAAAAAAAAACG9wcG9uZW50AAAD6AAAABMAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAIdGFibGVfaWQAAAAG",
+        "AAAAAQAAAJdBIHBsYXllcidzIG9mZmVyIHRvIHBsYXkgdGhlIHNhbWUgb3Bwb25lbnQgYWdhaW4gYWZ0ZXIgYSBzZXNzaW9uIGVuZHMuIFRoZSBvZmZlcmVkIGJ1eS1pbgppcyBsb2NrZWQgaW4gZXNjcm93IHVudGlsIHRoZSBvZmZlciBpcyBhY2NlcHRlZCBvciBjYW5jZWxsZWQuAAAAAAAAAAAMUmVtYXRjaE9mZmVyAAAAAwAAAAAAAAAGYnV5X2luAAAAAAALAAAAAAAAAApjcmVhdGVkX2F0AAAAAAAGAAAAAAAAAAZwbGF5ZXIAAAAAABM=",
+        "AAAAAQAAAKVPbmUgcGxheWVyIHdhaXRpbmcgYXQgYSB0YWJsZS4gV2hlbiBhIGNvbXBhdGlibGUgb3Bwb25lbnQgc2l0cywgYSBnYW1lIGlzIGNyZWF0ZWQuCklmIG5vYm9keSBqb2lucyB3aXRoaW4gd2FpdGluZ190aW1lb3V0LCBhbnlvbmUgY2FuIGNhbmNlbCBhbmQgcGxheWVyMSBpcyByZWZ1bmRlZC4AAAAAAAAAAAAADldhaXRpbmdTZXNzaW9uAAAAAAADAAAAAAAAAAZidXlfaW4AAAAAAAsAAAAAAAAACmNyZWF0ZWRfYXQAAAAAAAYAAAAAAAAAB3BsYXllcjEAAAAAEw==",
+        "AAAAAQAAADxMb2JieSB2aWV3IG9mIGEgdGFibGU6IGl0cyBkZWZpbml0aW9uIGFuZCBjdXJyZW50IG9jY3VwYW5jeS4AAAAAAAAACVRhYmxlSW5mbwAAAAAAAAYAAAA3R2FtZXMgYXQgdGhpcyB0YWJsZSB0aGF0IGFyZSBub3QgZmluaXNoZWQgb3IgY2FuY2VsbGVkLgAAAAAMYWN0aXZlX2dhbWVzAAAABAAAAAAAAAAEbWV0YQAAB9AAAAAJVGFibGVNZXRhAAAAAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAALVGFibGVTdGF0dXMAAAAAAAAAAAV0YWJsZQAAAAAAB9AAAAAFVGFibGUAAAAAAAAAAAAACHRhYmxlX2lkAAAABgAAAC5QbGF5ZXJzIHdhaXRpbmcgZm9yIGFuIG9wcG9uZW50IGF0IHRoaXMgdGFibGUuAAAAAAAHd2FpdGluZwAAAAPqAAAH0AAAAA5XYWl0aW5nU2Vzc2lvbgAA",
+        "AAAAAQAAAFpPbmUgcGFnZSBvZiBnYW1lcy4gYG5leHRgIGlzIHRoZSBjdXJzb3IgKGdhbWUgaWQpIGZvciB0aGUgZm9sbG93aW5nIHBhZ2UsIE5vbmUgYXQgdGhlIGVuZC4AAAAAAAAAAAAIR2FtZVBhZ2UAAAACAAAAAAAAAAVnYW1lcwAAAAAAA+oAAAfQAAAABEdhbWUAAAAAAAAABG5leHQAAAPoAAAABg==",
+        "AAAAAQAAAIFSZXNwb25zZSB0aW1lcyAoc2Vjb25kcykgcGVyIHBoYXNlLiBBIHRhYmxlIGNhbiBvdmVycmlkZSB0aGUgZ2xvYmFsIEdhbWVDb25maWcgdmFsdWVzIHNvCnR1cmJvIGFuZCBkZWVwLXN0YWNrIHRhYmxlcyBjYW4gY29leGlzdC4AAAAAAAAAAAAADVRpbWVvdXRDb25maWcAAAAAAAAGAAAAAAAAAAdiZXR0aW5nAAAAAAYAAABTVGltZSBmb3IgdGhlIHJlbWFpbmluZyBwbGF5ZXIgdG8gc3VibWl0IHRoZWlyIHByb29mIG9uY2UgdGhlIG9wcG9uZW50IGhhcyByZXZlYWxlZC4AAAAAEHByb29mX3N1Ym1pc3Npb24AAAAGAAAAAAAAAAtzZWVkX2NvbW1pdAAAAAAGAAAAAAAAAAtzZWVkX3JldmVhbAAAAAAGAAAAK1RpbWUgZm9yIHRoZSBmaXJzdCBoYW5kIHJldmVhbCBhdCBzaG93ZG93bi4AAAAACHNob3dkb3duAAAABgAAAE5TZWNvbmRzIGFmdGVyIHdoaWNoIGEgd2FpdGluZyBzZXNzaW9uIGNhbiBiZSBjbG9zZWQgYnkgYW55b25lLiAwID0gbm8gdGltZW91dC4AAAAAAAd3YWl0aW5nAAAAAAY=",
+        "AAAAAQAAAJdXaG8gaXMgb24gdGhlIGNsb2NrIGFuZCB1bnRpbCB3aGVuLiBJbiBiZXR0aW5nIHJvdW5kcyBgZGVhZGxpbmVgIGluY2x1ZGVzIHRoZSBwbGF5ZXIncwpyZW1haW5pbmcgdGltZWJhbms7IGB0aW1lYmFua2AgaXMgd2hhdCB0aGV5IGhhdmUgbGVmdCByaWdodCBub3cuAAAAAAAAAAAJVHVybkNsb2NrAAAAAAAAAwAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACHRpbWViYW5rAAAABg==",
+        "AAAAAQAAACpBIHBsYXllcidzIHNlYXQgaW4gYSB0YWJsZSdzIHdhaXRpbmcgbGlzdC4AAAAAAAAAAAALV2FpdGluZ1NlYXQAAAAAAgAAAAAAAAAHc2Vzc2lvbgAAAAfQAAAADldhaXRpbmdTZXNzaW9uAAAAAAAAAAAACHRhYmxlX2lkAAAABg==",
         "AAAAAQAAAFdSZXN1bHQgb2Ygc2l0X2F0X3RhYmxlOiB3YWl0aW5nICgxLzIpIG9yIGdhbWUgc3RhcnRlZC4gSWYgIXdhaXRpbmcgdGhlbiBnYW1lX2lkIGlzIHNldC4AAAAAAAAAAAlTaXRSZXN1bHQAAAAAAAACAAAAAAAAAAdnYW1lX2lkAAAAAAYAAAAAAAAAB3dhaXRpbmcAAAAAAQ==",
-        "AAAAAQAAAAAAAAAAAAAACkdhbWVDb25maWcAAAAAAAwAAAAAAAAAC2JldF90aW1lb3V0AAAAAAYAAAAAAAAACWJpZ19ibGluZAAAAAAAAAsAAAAAAAAACGdhbWVfaHViAAAAEwAAAAAAAAAKbWF4X2J1eV9pbgAAAAAACwAAAAAAAAAKbWluX2J1eV9pbgAAAAAACwAAAExPcHRpb25hbDogd2hlbiBzZXQsIGxvY2tfZnVuZHMgb24gY3JlYXRlL2pvaW4gYW5kIHBheW91dF93aW5uZXIgb24gZ2FtZSBlbmQuAAAAEnBheW1lbnRfY29udHJvbGxlcgAAAAAD6AAAABMAAAA0T3B0aW9uYWw6IHdoZW4gc2V0LCB2ZXJpZnlfaGFuZF9yYW5rIGluIHJldmVhbF9oYW5kLgAAAA5wcm9vZl92ZXJpZmllcgAAAAAD6AAAABMAAAAAAAAAD3Jha2VfcGVyY2VudGFnZQAAAAAEAAAAAAAAAA5yZXZlYWxfdGltZW91dAAAAAAABgAAAAAAAAALc21hbGxfYmxpbmQAAAAACwAAAAAAAAAIdHJlYXN1cnkAAAATAAAAXFNlY29uZHMgYWZ0ZXIgd2hpY2ggYSB3YWl0aW5nIHNlc3Npb24gKDEvMikgY2FuIGJlIGNsb3NlZDsgcGxheWVyMSByZWZ1bmRlZC4gMCA9IG5vIHRpbWVvdXQuAAAAD3dhaXRpbmdfdGltZW91dAAAAAAG",
+        "AAAAAwAAAAAAAAAAAAAACUdhbWVTdGF0ZQAAAAAAAAsAAAAAAAAAEVdhaXRpbmdGb3JQbGF5ZXJzAAAAAAAAAAAAAAAAAAANU2h1ZmZsZUNvbW1pdAAAAAAAAAEAAAAAAAAADVNodWZmbGVSZXZlYWwAAAAAAAACAAAAAAAAAAlEZWFsQ2FyZHMAAAAAAAADAAAAAAAAAAdQcmVGbG9wAAAAAAQAAAAAAAAAC0Zsb3BCZXR0aW5nAAAAAAUAAAAAAAAAC1R1cm5CZXR0aW5nAAAAAAYAAAAAAAAADFJpdmVyQmV0dGluZwAAAAcAAAAAAAAACFNob3dkb3duAAAACAAAAAAAAAAIRmluaXNoZWQAAAAJAAAAAAAAAAlDYW5jZWxsZWQAAAAAAAAK",
+        "AAAAAwAAAAAAAAAAAAAABkFjdGlvbgAAAAAABAAAAAAAAAAERm9sZAAAAAAAAAAAAAAABUNoZWNrAAAAAAAAAQAAAAAAAAAEQ2FsbAAAAAIAAAAAAAAABVJhaXNlAAAAAAAAAw==",
+        "AAAAAQAAAAAAAAAAAAAACkdhbWVDb25maWcAAAAAAA8AAAA8U2V0dGxlbWVudCB3aGVuIGEgY2FzaCBnYW1lIGlzIGFiYW5kb25lZCBkdXJpbmcgdGhlIHNodWZmbGUuAAAADmFiYW5kb25fcG9saWN5AAAAAAfQAAAADUFiYW5kb25Qb2xpY3kAAAAAAAAAAAAAC2JldF90aW1lb3V0AAAAAAYAAAAAAAAACWJpZ19ibGluZAAAAAAAAAsAAAAAAAAACGdhbWVfaHViAAAAEwAAAAAAAAAKbWF4X2J1eV9pbgAAAAAACwAAAAAAAAAKbWluX2J1eV9pbgAAAAAACwAAAExPcHRpb25hbDogd2hlbiBzZXQsIGxvY2tfZnVuZHMgb24gY3JlYXRlL2pvaW4gYW5kIHBheW91dF93aW5uZXIgb24gZ2FtZSBlbmQuAAAAEnBheW1lbnRfY29udHJvbGxlcgAAAAAD6AAAABMAAAA0T3B0aW9uYWw6IHdoZW4gc2V0LCB2ZXJpZnlfaGFuZF9yYW5rIGluIHJldmVhbF9oYW5kLgAAAA5wcm9vZl92ZXJpZmllcgAAAAAD6AAAABMAAAAAAAAAD3Jha2VfcGVyY2VudGFnZQAAAAAEAAAAAAAAAA5yZXZlYWxfdGltZW91dAAAAAAABgAAAAAAAAALc21hbGxfYmxpbmQAAAAACwAAAFRQZXItcGxheWVyIHRpbWViYW5rIChzZWNvbmRzKSBvbiB0b3Agb2YgYmV0X3RpbWVvdXQgaW4gYmV0dGluZyByb3VuZHM7IGFsc28gaXRzIGNhcC4AAAAIdGltZWJhbmsAAAAGAAAAP1NlY29uZHMgYWRkZWQgYmFjayB0byBlYWNoIHBsYXllcidzIHRpbWViYW5rIGF0IGV2ZXJ5IG5ldyBoYW5kLgAAAAAPdGltZWJhbmtfcmVmaWxsAAAAAAYAAAAAAAAACHRyZWFzdXJ5AAAAEwAAAFxTZWNvbmRzIGFmdGVyIHdoaWNoIGEgd2FpdGluZyBzZXNzaW9uICgxLzIpIGNhbiBiZSBjbG9zZWQ7IHBsYXllcjEgcmVmdW5kZWQuIDAgPSBubyB0aW1lb3V0LgAAAA93YWl0aW5nX3RpbWVvdXQAAAAABg==",
+        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAArAAAAAAAAAAVhY3RvcgAAAAAAAAQAAAAAAAAACWJpZ19ibGluZAAAAAAAAAsAAAAAAAAABWJvYXJkAAAAAAAD6gAAAAQAAAAAAAAADmJvYXJkX3JldmVhbGVkAAAAAAAEAAAATlRvdGFsIGVhY2ggcGxheWVyIGhhcyBicm91Z2h0IHRvIHRoZSBzZXNzaW9uIChidXktaW4gcGx1cyByZWJ1eXMgYW5kIHRvcC11cHMpLgAAAAAACmJvdWdodF9pbjEAAAAAAAsAAAAAAAAACmJvdWdodF9pbjIAAAAAAAsAAABLS2VlcGVyIGJvdW50aWVzIHBhaWQgb3V0IG9mIHRoaXMgZ2FtZSBzbyBmYXIgKGJvdW5kZWQgYnkgS2VlcGVyQm91bnR5LmNhcCkuAAAAAAtib3VudHlfcGFpZAAAAAALAAAAAAAAAAZidXlfaW4AAAAAAAsAAAAAAAAACmNyZWF0ZWRfYXQAAAAAAAYAAAAAAAAADmN1cnJlbnRfYmV0X3AxAAAAAAALAAAAAAAAAA5jdXJyZW50X2JldF9wMgAAAAAACwAAAAAAAAAPZGVhbGVyX3Bvc2l0aW9uAAAAAAQAAAAAAAAACmZpbmFsX3NlZWQAAAAAA+gAAAPuAAAAIAAAAAAAAAAGZm9sZGVkAAAAAAPoAAAAEwAAAAAAAAAGZm9ybWF0AAAAAAfQAAAAC01hdGNoRm9ybWF0AAAAAAAAAAAQaGFuZF9jb21taXRtZW50MQAAA+gAAAPuAAAAIAAAAAAAAAAQaGFuZF9jb21taXRtZW50MgAAA+gAAAPuAAAAIAAAAAAAAAAKaGFuZF9yYW5rMQAAAAAD6AAAAAQAAAAAAAAACmhhbmRfcmFuazIAAAAAA+gAAAAEAAAAJkhhbmRzIHdvbiBieSBlYWNoIHBsYXllciB0aGlzIHNlc3Npb24uAAAAAAAKaGFuZHNfd29uMQAAAAAABAAAAAAAAAAKaGFuZHNfd29uMgAAAAAABAAAAAAAAAACaWQAAAAAAAYAAAAAAAAADmxhc3RfYWN0aW9uX2F0AAAAAAAGAAAAAAAAABFsYXN0X3JhaXNlX2Ftb3VudAAAAAAAAAsAAAAAAAAACW1pbl9yYWlzZQAAAAAAAAsAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAPoAAAAEwAAAClDaGlwcyBpbiB0aGUgbWlkZGxlIGZvciB0aGUgY3VycmVudCBoYW5kLgAAAAAAAANwb3QAAAAACwAAAFFSYWtlIHRha2VuIGZyb20gcG90cyB0aGlzIHNlc3Npb247IHBhaWQgdG8gdGhlIHRyZWFzdXJ5IHdoZW4gdGhlIHNlc3Npb24gc2V0dGxlcy4AAAAAAAAEcmFrZQAAAAsAAAAAAAAAEHNlZWRfY29tbWl0bWVudDEAAAPoAAAD7gAAACAAAAAAAAAAEHNlZWRfY29tbWl0bWVudDIAAAPoAAAD7gAAACAAAAAAAAAADHNlZWRfcmV2ZWFsMQAAA+gAAAPuAAAAIAAAAAAAAAAMc2VlZF9yZXZlYWwyAAAD6AAAA+4AAAAgAAAAAAAAAAtzbWFsbF9ibGluZAAAAAALAAAAMkNoaXBzIGJlaGluZCBmb3IgZWFjaCBwbGF5ZXIgKG5vdCB5ZXQgaW4gdGhlIHBvdCkuAAAAAAAGc3RhY2sxAAAAAAALAAAAAAAAAAZzdGFjazIAAAAAAAsAAAAAAAAABXN0YXRlAAAAAAAH0AAAAAlHYW1lU3RhdGUAAAAAAAAAAAAACHRhYmxlX2lkAAAABgAAAClTZWNvbmRzIG9mIHRpbWViYW5rIGxlZnQgZm9yIGVhY2ggcGxheWVyLgAAAAAAAAl0aW1lYmFuazEAAAAAAAAGAAAAAAAAAAl0aW1lYmFuazIAAAAAAAAGAAAAAAAAAAx0b3RhbF9iZXRfcDEAAAALAAAAAAAAAAx0b3RhbF9iZXRfcDIAAAALAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
+        "AAAAAQAAAIVDb21wYWN0IHJlY29yZCBvZiBhIGdhbWUsIGFyY2hpdmVkIGluIHBlcnNpc3RlbnQgc3RvcmFnZSBvbmNlIGl0IGlzIGZpbmlzaGVkIG9yIGNhbmNlbGxlZApzbyB0aGUgcmVzdWx0IG91dGxpdmVzIHRoZSBmdWxsIEdhbWUgZW50cnkuAAAAAAAAAAAAAAtHYW1lU3VtbWFyeQAAAAAOAAAAAAAAAApib3VnaHRfaW4xAAAAAAALAAAAAAAAAApib3VnaHRfaW4yAAAAAAALAAAAAAAAAAZidXlfaW4AAAAAAAsAAAAAAAAACmNyZWF0ZWRfYXQAAAAAAAYAAAAAAAAACGVuZGVkX2F0AAAABgAAAAAAAAAGZm9ybWF0AAAAAAfQAAAAC01hdGNoRm9ybWF0AAAAAAAAAAAKaGFuZHNfd29uMQAAAAAABAAAAAAAAAAKaGFuZHNfd29uMgAAAAAABAAAAAAAAAACaWQAAAAAAAYAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAPoAAAAEwAAAAAAAAAFc3RhdGUAAAAAAAfQAAAACUdhbWVTdGF0ZQAAAAAAAAAAAAAIdGFibGVfaWQAAAAGAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
+        "AAAAAwAAAAAAAAAAAAAAD1RvdXJuYW1lbnRTdGF0ZQAAAAAEAAAAAAAAAAtSZWdpc3RlcmluZwAAAAAAAAAAAAAAAAdSdW5uaW5nAAAAAAEAAAAAAAAACEZpbmlzaGVkAAAAAgAAAAAAAAAJQ2FuY2VsbGVkAAAAAAAAAw==",
+        "AAAAAQAAAChPbmUgc3RlcCBvZiBhIHRvdXJuYW1lbnQgYmxpbmQgc2NoZWR1bGUuAAAAAAAAAApCbGluZExldmVsAAAAAAACAAAAAAAAAAliaWdfYmxpbmQAAAAAAAALAAAAAAAAAAtzbWFsbF9ibGluZAAAAAAL",
+        "AAAAAQAAAJ9BIHRvdXJuYW1lbnQgdGFibGUuIEdhbWVzIGFyZSBoZWFkcy11cCwgc28gYSB0YWJsZSBzZWF0cyBhdCBtb3N0IHR3byBwbGF5ZXJzOyBhIGxvbmUKc3Vydml2b3Igd2FpdHMgaGVyZSB1bnRpbCBiYWxhbmNpbmcgYnJpbmdzIGFuIG9wcG9uZW50IGZyb20gYW5vdGhlciB0YWJsZS4AAAAAAAAAAA9Ub3VybmFtZW50VGFibGUAAAAAAgAAAE9HYW1lIGN1cnJlbnRseSBiZWluZyBwbGF5ZWQgYXQgdGhpcyB0YWJsZSAoTm9uZSB3aGlsZSBmZXdlciB0aGFuIDIgYXJlIHNlYXRlZCkuAAAAAAdnYW1lX2lkAAAAA+gAAAAGAAAAAAAAAAdwbGF5ZXJzAAAAA+oAAAAT",
+        "AAAAAQAAAClUb3VybmFtZW50IHBhcmFtZXRlcnMsIGZpeGVkIGF0IGNyZWF0aW9uLgAAAAAAAAAAAAAQVG91cm5hbWVudENvbmZpZwAAAAgAAAAAAAAADGJsaW5kX2xldmVscwAAA+oAAAfQAAAACkJsaW5kTGV2ZWwAAAAAAAAAAAAJZW50cnlfZmVlAAAAAAAACwAAAH1TZWNvbmRzIHBlciBibGluZCBsZXZlbCAoMCA9IGZpcnN0IGxldmVsIGZvciB0aGUgd2hvbGUgdG91cm5hbWVudCkuIFRhYmxlcyBwaWNrIHVwCnRoZSBsZXZlbCBpbiBmb3JjZSB3aGVuIHRoZWlyIGdhbWUgc3RhcnRzLgAAAAAAAA5sZXZlbF9kdXJhdGlvbgAAAAAABgAAAAAAAAAMbWF4X2VudHJhbnRzAAAABAAAAEpQcml6ZSBzaGFyZSBwZXIgZmluaXNoaW5nIHBvc2l0aW9uIGluIGJhc2lzIHBvaW50czsgaW5kZXggMCBpcyB0aGUgd2lubmVyLgAAAAAAB3BheW91dHMAAAAD6gAAAAQAAAAAAAAAFXJlZ2lzdHJhdGlvbl9kZWFkbGluZQAAAAAAAAYAAAAAAAAADnN0YXJ0aW5nX2NoaXBzAAAAAAALAAAAR1RhYmxlIGRlZmluaXRpb24gdGhlIHRvdXJuYW1lbnQgaXMgcGxheWVkIG9uICh2YXJpYW50L3N0YWtlcyB0ZW1wbGF0ZSkuAAAAAAh0YWJsZV9pZAAAAAY=",
+        "AAAAAQAAAQJNdWx0aS10YWJsZSBmcmVlemVvdXQuIFBsYXllcnMgcmVnaXN0ZXIgdW50aWwgdGhlIGRlYWRsaW5lLCBhcmUgc2VhdGVkIGF0IHJhbmRvbSB3aGVuIGl0CnN0YXJ0cywgYW5kIGFyZSBlbGltaW5hdGVkIHdoZW4gdGhleSBsb3NlIHRoZWlyIGNoaXBzLiBUYWJsZXMgYXJlIG1lcmdlZCBhcyBwbGF5ZXJzIGJ1c3QKdW50aWwgb25lIGZpbmFsIHRhYmxlIGRlY2lkZXMgdGhlIHdpbm5lcjsgcHJpemVzIGFyZSBwYWlkIGJ5IGZpbmlzaGluZyBwb3NpdGlvbi4AAAAAAAAAAAAKVG91cm5hbWVudAAAAAAACQAAAAAAAAAFY2hpcHMAAAAAAAPsAAAAEwAAAAsAAAAAAAAABmNvbmZpZwAAAAAH0AAAABBUb3VybmFtZW50Q29uZmlnAAAANkJ1c3RlZCBwbGF5ZXJzIGluIGVsaW1pbmF0aW9uIG9yZGVyIChmaXJzdCBvdXQgZmlyc3QpLgAAAAAACmVsaW1pbmF0ZWQAAAAAA+oAAAATAAAAAAAAAAhlbnRyYW50cwAAA+oAAAATAAAAAAAAAAJpZAAAAAAABgAAAAAAAAAJcmVtYWluaW5nAAAAAAAABAAAAAAAAAAKc3RhcnRlZF9hdAAAAAAABgAAAAAAAAAFc3RhdGUAAAAAAAfQAAAAD1RvdXJuYW1lbnRTdGF0ZQAAAAAAAAAABnRhYmxlcwAAAAAD6gAAB9AAAAAPVG91cm5hbWVudFRhYmxlAA==",
         "AAAAAAAAAAAAAAADYWN0AAAAAAYAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAHZ2FtZV9pZAAAAAAGAAAAAAAAAAZhY3Rpb24AAAAAB9AAAAAGQWN0aW9uAAAAAAAAAAAADHJhaXNlX2Ftb3VudAAAAAsAAAAAAAAABXByb29mAAAAAAAD6AAAA+4AAAEAAAAAAAAAAApjb21taXRtZW50AAAAAAPoAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
-        "AAAAAQAAAJFPbmUgcGxheWVyIHdhaXRpbmcgYXQgYSB0YWJsZS4gV2hlbiAybmQgc2l0cywgZ2FtZSBpcyBjcmVhdGVkLgpJZiBub2JvZHkgam9pbnMgd2l0aGluIHdhaXRpbmdfdGltZW91dCwgYW55b25lIGNhbiBjYW5jZWwgYW5kIHBsYXllcjEgaXMgcmVmdW5kZWQuAAAAAAAAAAAAAA5XYWl0aW5nU2Vzc2lvbgAAAAAAAwAAAAAAAAAGYnV5X2luAAAAAAALAAAAAAAAAApjcmVhdGVkX2F0AAAAAAAGAAAAAAAAAAdwbGF5ZXIxAAAAABM=",
-        "AAAAAAAAAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
+        "AAAAAAAAAMdFbWVyZ2VuY3kgc3RvcCAob3duZXIgb3IgZ3VhcmRpYW4pOiBubyBuZXcgc2VhdHMsIGdhbWVzLCBqb2lucywgcmVtYXRjaGVzLCByZWJ1eXMgb3IKdG91cm5hbWVudCBlbnRyaWVzLCBzbyBubyBuZXcgZnVuZHMgYXJlIGxvY2tlZC4gR2FtZXMgaW4gcHJvZ3Jlc3MgcGxheSBvbiBhbmQgY2FuCnN0aWxsIGJlIGNhbmNlbGxlZCBhbmQgcmVmdW5kZWQuAAAAAAVwYXVzZQAAAAAAAAEAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAJNSZWJ1eSBhZnRlciBidXN0aW5nOiBiZXR3ZWVuIGhhbmRzLCBhIHBsYXllciB3aXRoIG5vIGNoaXBzIGJ1eXMgYmFjayBpbiBmb3IgYW4gYW1vdW50CndpdGhpbiB0aGUgdGFibGUncyBidXktaW4gbGltaXRzLiBGdW5kcyBhcmUgbG9ja2VkIGluIGVzY3Jvdy4AAAAABXJlYnV5AAAAAAAAAwAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAdnYW1lX2lkAAAAAAYAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAFdUb3AgdXAgYSBzdGFjayBiZXR3ZWVuIGhhbmRzLCB1cCB0byB0aGUgdGFibGUncyBtYXhfYnV5X2luLiBGdW5kcyBhcmUgbG9ja2VkIGluIGVzY3Jvdy4AAAAABnRvcF91cAAAAAAAAwAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAdnYW1lX2lkAAAAAAYAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAAj9Pd25lcjogcmV3cml0ZSBlbnRyaWVzIHN0b3JlZCBpbiBhbiBvbGRlciBsYXlvdXQsIGhhbmRsaW5nIGF0IG1vc3QgYGxpbWl0YCB0YWJsZSBpZHMsIGdhbWUKaWRzIGFuZCBzZXNzaW9ucyBwZXIgY2FsbCAoY2FwcGVkIGF0IDEwMCkgc28gZWFjaCBjYWxsIGZpdHMgaW4gYSB0cmFuc2FjdGlvbi4gVGhlIGNvbmZpZwppcyByZXdyaXR0ZW4gYnkgdGhlIGZpcnN0IGNhbGwuIFNlcXVlbnRpYWwgZ2FtZXMgYXJlIGZvdW5kIGJ5IHNjYW5uaW5nIHRoZWlyIGlkczsKc3RhcnRfZ2FtZSBzZXNzaW9ucyBjYW5ub3QgYmUsIHNvIHBhc3MgdGhlaXIgc2Vzc2lvbiBpZHMgaW4gYHNlc3Npb25faWRzYCAob24gYW55IGNhbGwKYmVmb3JlIHRoZSBzdGVwIGVuZHM7IHJlcGVhdHMgYXJlIGlnbm9yZWQpLiBSZXR1cm5zIGhvdyBtYW55IGlkcyBhbmQgc2Vzc2lvbnMgYXJlIGxlZnQ7CnRoZSBzdG9yZWQgdmVyc2lvbiBtb3ZlcyB1cCBvbmNlIHRoYXQgcmVhY2hlcyAwLiBVbnRpbCB0aGVuIG9sZCBlbnRyaWVzIGFyZSBzdGlsbCByZWFkCmFzIHRoZXkgYXJlLCBhbmQgYW55IHdyaXRlIHRvIGEgZ2FtZSBhbHNvIG1vdmVzIGl0LgAAAAAHbWlncmF0ZQAAAAACAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAAAAAAAC3Nlc3Npb25faWRzAAAAA+oAAAAGAAAAAQAAA+kAAAAGAAAAAw==",
+        "AAAAAAAAAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAACVMaWZ0IHRoZSBlbWVyZ2VuY3kgc3RvcCAob3duZXIgb25seSkuAAAAAAAAB3VucGF1c2UAAAAAAAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAH1SZXBsYWNlIHRoZSBjb250cmFjdCBjb2RlIChvd25lciBvbmx5KS4gSWYgdGhlIG5ldyBjb2RlIGhhcyBhIGhpZ2hlciBTQ0hFTUFfVkVSU0lPTiwgY2FsbAptaWdyYXRlIHVudGlsIHZlcnNpb24oKSByZXBvcnRzIGl0LgAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAElTdG9yYWdlIGxheW91dCBpbiB1c2U6IFNDSEVNQV9WRVJTSU9OIG9uY2UgZXZlcnkgZW50cnkgaGFzIGJlZW4gbWlncmF0ZWQuAAAAAAAAB3ZlcnNpb24AAAAAAAAAAAEAAAAE",
         "AAAAAAAAAAAAAAAIZ2V0X2dhbWUAAAABAAAAAAAAAAdnYW1lX2lkAAAAAAYAAAABAAAD6QAAB9AAAAAER2FtZQAAAAM=",
-        "AAAAAAAAAEBBZGQgYSBuZXcgdGFibGUgKG1lc2EpLiBPbmx5IGFkbWluLiBtYXhfc2VhdHMgZS5nLiAyIChNVlApIG9yIDQuAAAACWFkZF90YWJsZQAAAAAAAAYAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAALc21hbGxfYmxpbmQAAAAACwAAAAAAAAAJYmlnX2JsaW5kAAAAAAAACwAAAAAAAAAKbWluX2J1eV9pbgAAAAAACwAAAAAAAAAKbWF4X2J1eV9pbgAAAAAACwAAAAAAAAAJbWF4X3NlYXRzAAAAAAAABAAAAAEAAAPpAAAABgAAAAM=",
-        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAC1XaG8gaG9sZHMgYSByb2xlICh0aGUgb3duZXIgd2hlbiB1bmFzc2lnbmVkKS4AAAAAAAAIZ2V0X3JvbGUAAAABAAAAAAAAAARyb2xlAAAH0AAAAARSb2xlAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAADJBc3NpZ24gKFNvbWUpIG9yIGNsZWFyIChOb25lKSBhIHJvbGUgKG93bmVyIG9ubHkpLgAAAAAACHNldF9yb2xlAAAAAgAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAAAAAAHYWNjb3VudAAAAAPoAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAG1BZGQgYSBuZXcgdGFibGUgKG1lc2EpLiBPcGVyYXRvciBvbmx5ICh0aGUgb3duZXIgdW50aWwgYW4gb3BlcmF0b3IgaXMgYXNzaWduZWQpLiBtYXhfc2VhdHMgZS5nLiAyIChNVlApIG9yIDQuAAAAAAAACWFkZF90YWJsZQAAAAAAAAYAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAALc21hbGxfYmxpbmQAAAAACwAAAAAAAAAJYmlnX2JsaW5kAAAAAAAACwAAAAAAAAAKbWluX2J1eV9pbgAAAAAACwAAAAAAAAAKbWF4X2J1eV9pbgAAAAAACwAAAAAAAAAJbWF4X3NlYXRzAAAAAAAABAAAAAEAAAPpAAAABgAAAAM=",
+        "AAAAAAAAAKZFeHRlbmQgdGhlIHN0b3JhZ2Ugb2YgYSBnYW1lIChhbmQgaXRzIGFyY2hpdmVkIHN1bW1hcnkpIHRvIHRoZSBmdWxsIGdhbWUgVFRMLiBBbnlvbmUgY2FuCnBheSBmb3IgaXQ7IGFjdGl2ZSBnYW1lcyBhcmUgYWxzbyBleHRlbmRlZCB3aGVuZXZlciB0aGV5IGFyZSByZWFkIG9yIHdyaXR0ZW4uAAAAAAAJYnVtcF9nYW1lAAAAAAAAAQAAAAAAAAAHZ2FtZV9pZAAAAAAGAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAApUaGUgb3duZXIuAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAAURMb2JieTogYWN0aXZlIGdhbWVzIChub3QgZmluaXNoZWQgb3IgY2FuY2VsbGVkKSwgb3B0aW9uYWxseSBvbmx5IHRob3NlIGluIGBzdGF0ZWAsIGZyb20KcG9zaXRpb24gYGN1cnNvcmAgb2YgdGhlIGFjdGl2ZSBpbmRleCAoMCBmb3IgdGhlIGZpcnN0IHBhZ2UpLiBDb250aW51ZSBmcm9tIGBuZXh0YCBmb3IKdGhlIGZvbGxvd2luZyBwYWdlLiBBIGdhbWUgdGhhdCBzZXR0bGVzIGhhbmRzIGl0cyBwb3NpdGlvbiB0byB0aGUgbGFzdCBsaXN0ZWQgZ2FtZSwgc28KYSB3YWxrIHRocm91Z2ggdGhlIHBhZ2VzIGNhbiBtaXNzIGEgZ2FtZSB0aGF0IG1vdmVkIG1lYW53aGlsZS4AAAAJZ2V0X2dhbWVzAAAAAAAAAwAAAAAAAAAFc3RhdGUAAAAAAAPoAAAH0AAAAAlHYW1lU3RhdGUAAAAAAAAAAAAABmN1cnNvcgAAAAAABgAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAB9AAAAAIR2FtZVBhZ2U=",
         "AAAAAAAAAAAAAAAJZ2V0X3RhYmxlAAAAAAAAAQAAAAAAAAAIdGFibGVfaWQAAAAGAAAAAQAAA+kAAAfQAAAABVRhYmxlAAAAAAAAAw==",
         "AAAAAAAAAAAAAAAJam9pbl9nYW1lAAAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAdnYW1lX2lkAAAAAAYAAAABAAAD6QAAAAIAAAAD",
         "AAAAAAAAAI5TZW5kIGFuIG9uLWNoYWluIGNoYXQgbWVzc2FnZSBmb3IgYSBnYW1lLgpNZXNzYWdlIGlzIGVtaXR0ZWQgYXMgYSBjb250cmFjdCBldmVudCAobm90IHN0b3JlZCkg4oCUIGNvc3QgaXMganVzdCB0aGUgdHggZmVlLgpNYXggMjgwIGNoYXJhY3RlcnMuAAAAAAAJc2VuZF9jaGF0AAAAAAAAAwAAAAAAAAAGc2VuZGVyAAAAAAATAAAAAAAAAAdnYW1lX2lkAAAAAAYAAAAAAAAAB21lc3NhZ2UAAAAAEAAAAAEAAAPpAAAAAgAAAAM=",
-        "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
+        "AAAAAAAAAMNDbG9zZSBhIGZpbmlzaGVkIG9yIGNhbmNlbGxlZCBnYW1lOiBpdHMgYXJjaGl2ZWQgc3VtbWFyeSBzdGF5cyBhbmQgdGhlIGZ1bGwgZW50cnkgaXMKZGVsZXRlZCwgc28gbm8gcmVudCBpcyBwYWlkIG9uIGl0LiBnZXRfZ2FtZSBrZWVwcyBhbnN3ZXJpbmcgZnJvbSB0aGUgc3VtbWFyeS4gQW55b25lCmNhbiBjbG9zZSBhIHNldHRsZWQgZ2FtZS4AAAAACmNsb3NlX2dhbWUAAAAAAAEAAAAAAAAAB2dhbWVfaWQAAAAABgAAAAEAAAPpAAAAAgAAAAM=",
         "AAAAAAAAAAAAAAAKZ2V0X2NvbmZpZwAAAAAAAAAAAAEAAAPpAAAH0AAAAApHYW1lQ29uZmlnAAAAAAAD",
-        "AAAAAAAAAFVJbml0aWFsaXplIHdpdGggYWRtaW4gYW5kIGNvbmZpZyAob3IgdXNlIF9fY29uc3RydWN0b3IoYWRtaW4sIGdhbWVfaHViKSBmb3IgbWluaW1hbCkuAAAAAAAACmluaXRpYWxpemUAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAGY29uZmlnAAAAAAfQAAAACkdhbWVDb25maWcAAAAAAAEAAAPpAAAAAgAAAAM=",
-        "AAAAAAAAAPRTdGFydCBhIGdhbWUgd2l0aCBhIGNsaWVudC1wcm92aWRlZCBzZXNzaW9uIElEIChYRFIgLyBHYW1lIFN0dWRpbyBmbG93KS4KUDEgc2lnbnMgYXV0aCBmb3IgKHNlc3Npb25faWQsIHRhYmxlX2lkLCBidXlfaW4pOyBQMiBzdWJtaXRzIHRoZSB0eCB3aXRoIFAxJ3MgYXV0aCBpbmplY3RlZC4KR2FtZSBpcyBzdG9yZWQgdW5kZXIgU2Vzc2lvbkdhbWUoc2Vzc2lvbl9pZCk7IGdldF9nYW1lKHNlc3Npb25faWQpIHJldHVybnMgaXQuAAAACnN0YXJ0X2dhbWUAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAYAAAAAAAAACHRhYmxlX2lkAAAABgAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAABmJ1eV9pbgAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAHFMb2JieTogdGFibGVzIGBzdGFydC4uc3RhcnQrbGltaXRgIChsaW1pdCBjYXBwZWQgYXQgTUFYX1BBR0UpIHdpdGggdGhlaXIgd2FpdGluZyBzZWF0CmFuZCBudW1iZXIgb2YgYWN0aXZlIGdhbWVzLgAAAAAAAApnZXRfdGFibGVzAAAAAAACAAAAAAAAAAVzdGFydAAAAAAAAAYAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAH0AAAAAlUYWJsZUluZm8AAAA=",
+        "AAAAAAAAAO5TZXQgdGhlIGFkbWluIGFuZCBjb25maWcgb2YgYSBjb250cmFjdCB0aGF0IGhhcyBuZWl0aGVyIChfX2NvbnN0cnVjdG9yIHNldHMgYm90aCwgc28KZGVwbG95ZWQgY29udHJhY3RzIGNoYW5nZSB0aGVpciBjb25maWcgd2l0aCBzZXRfY29uZmlnKS4gRmFpbHMgd2l0aCBBbHJlYWR5SW5pdGlhbGl6ZWQKb25jZSBib3RoIGFyZSBzZXQuIEl0IGNhbm5vdCBjaGFuZ2UgdGhlIG93bmVyIChzZWUgcHJvcG9zZV9vd25lcikuAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAZjb25maWcAAAAAB9AAAAAKR2FtZUNvbmZpZwAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAUNMZWF2ZSBhIGNhc2ggc2Vzc2lvbiBiZXR3ZWVuIGhhbmRzIChvciBhIGdhbWUgbm9ib2R5IGhhcyBqb2luZWQgeWV0KS4gQm90aCBzdGFja3MgYW5kCnRoZSBzZXNzaW9uIHJha2UgYXJlIHBhaWQgb3V0IG9mIGVzY3JvdywgdGhlIGdhbWUgZW5kcyBhbmQgdGhlIGh1YiBzZXNzaW9uIGlzIGNsb3NlZDsKdGhlIHBsYXllciB3aG8gY2FtZSBvdXQgYWhlYWQgKHN0YWNrIG1pbnVzIGNoaXBzIGJvdWdodCkgaXMgcmVjb3JkZWQgYXMgdGhlIHdpbm5lci4KTGVhdmluZyBhIGZyZWV6ZW91dCBvciBiZXN0LW9mLU4gbWF0Y2ggZm9yZmVpdHMgaXQgdG8gdGhlIG9wcG9uZW50LgAAAAAKbGVhdmVfZ2FtZQAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAdnYW1lX2lkAAAAAAYAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAEFSZXBsYWNlIHRoZSBjb25maWcgKG93bmVyIG9ubHkpLiBUaGUgaHViIGZvbGxvd3MgY29uZmlnLmdhbWVfaHViLgAAAAAAAApzZXRfY29uZmlnAAAAAAABAAAAAAAAAAZjb25maWcAAAAAB9AAAAAKR2FtZUNvbmZpZwAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAWNbRGVwcmVjYXRlZF0gVXNlIHN0YXJ0X2dhbWVfd2l0aF9pbnZpdGU6IHRoaXMgYXV0aCBoYXMgbm8gZXhwaXJ5IGFuZCBpcyBub3QgYm91bmQgdG8gcGxheWVyMi4KU3RhcnQgYSBnYW1lIHdpdGggYSBjbGllbnQtcHJvdmlkZWQgc2Vzc2lvbiBJRCAoWERSIC8gR2FtZSBTdHVkaW8gZmxvdykuClAxIHNpZ25zIGF1dGggZm9yIChzZXNzaW9uX2lkLCB0YWJsZV9pZCwgYnV5X2luKTsgUDIgc3VibWl0cyB0aGUgdHggd2l0aCBQMSdzIGF1dGggaW5qZWN0ZWQuCmBzZXNzaW9uX2lkYCBtdXN0IGJlIGJlbG93IEhVQl9TRVFVRU5USUFMX0JBU0U7IHRoZSBnYW1lIGlkIGlzIGdldF9zZXNzaW9uX2dhbWVfaWQoc2Vzc2lvbl9pZCkuAAAAAApzdGFydF9nYW1lAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAGAAAAAAAAAAh0YWJsZV9pZAAAAAYAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAAZidXlfaW4AAAAAAAsAAAABAAAD6QAAAAIAAAAD",
         "AAAAAAAAAAAAAAALY29tbWl0X3NlZWQAAAAAAwAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAdnYW1lX2lkAAAAAAYAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
         "AAAAAAAAAAAAAAALY3JlYXRlX2dhbWUAAAAAAwAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAh0YWJsZV9pZAAAAAYAAAAAAAAABmJ1eV9pbgAAAAAACwAAAAEAAAPpAAAABgAAAAM=",
+        "AAAAAAAAACBQZW5kaW5nIHJlbWF0Y2ggb2ZmZXIgb24gYSBnYW1lLgAAAAtnZXRfcmVtYXRjaAAAAAABAAAAAAAAAAdnYW1lX2lkAAAAAAYAAAABAAAD6QAAB9AAAAAMUmVtYXRjaE9mZmVyAAAAAw==",
+        "AAAAAAAAAH9TdG9wIChwYXVzZWQgPSB0cnVlKSBvciByZXN1bWUgbmV3IHNlYXRzIGF0IGEgdGFibGUgKG9wZXJhdG9yIG9ubHkpLiBXYWl0aW5nIHBsYXllcnMgc3RheQpxdWV1ZWQgYW5kIGdhbWVzIGluIHByb2dyZXNzIHBsYXkgb24uAAAAAAtwYXVzZV90YWJsZQAAAAACAAAAAAAAAAh0YWJsZV9pZAAAAAYAAAAAAAAABnBhdXNlZAAAAAAAAQAAAAEAAAPpAAAAAgAAAAM=",
         "AAAAAAAAAAAAAAALcG9zdF9ibGluZHMAAAAAAQAAAAAAAAAHZ2FtZV9pZAAAAAAGAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAJ5Ecm9wIGluZGV4IGVudHJpZXMgZm9yIGdhbWVzIHdob3NlIHN0b3JhZ2UgaGFzIGV4cGlyZWQsIGNoZWNraW5nIHVwIHRvIGBsaW1pdGAgZW50cmllcwpmcm9tIHBvc2l0aW9uIGBjdXJzb3JgLiBBbnlvbmUgbWF5IGNhbGwuIFJldHVybnMgaG93IG1hbnkgd2VyZSByZW1vdmVkLgAAAAAAC3BydW5lX2dhbWVzAAAAAAIAAAAAAAAABmN1cnNvcgAAAAAABgAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAAAQ=",
         "AAAAAAAAAD1SZXZlYWwgaGFuZCBhdCBzaG93ZG93bi4gQ2FsbHMgUHJvb2ZWZXJpZmllciB3aGVuIGNvbmZpZ3VyZWQuAAAAAAAAC3JldmVhbF9oYW5kAAAAAAUAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAHZ2FtZV9pZAAAAAAGAAAAAAAAAApob2xlX2NhcmRzAAAAAAPqAAAABAAAAAAAAAAMY2xhaW1lZF9yYW5rAAAABAAAAAAAAAAFcHJvb2YAAAAAAAPuAAABAAAAAAEAAAPpAAAAAgAAAAM=",
         "AAAAAAAAAAAAAAALcmV2ZWFsX3NlZWQAAAAAAwAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAdnYW1lX2lkAAAAAAYAAAAAAAAABHNlZWQAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
-        "AAAAAAAAANdTaXQgYXQgYSB0YWJsZS4gSWYgbm9ib2R5IHdhaXRpbmcg4oaSIHlvdSdyZSAxLzIgKHdhaXRpbmcpLiBJZiBvbmUgd2FpdGluZyDihpIgZ2FtZSBzdGFydHMgKDIvMiksIHJldHVybnMgZ2FtZV9pZC4KQ29uZm9ybWUgdsOjbyBlbnRyYW5kbyB1c3XDoXJpb3MsIG1vbnRhbS1zZSBhcyBtZXNhcyB2aXJ0dWFpczsgYSBtZXNhIGluaWNpYSBxdWFuZG8gdGVtIDIgam9nYWRvcmVzLgAAAAAMc2l0X2F0X3RhYmxlAAAAAwAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAh0YWJsZV9pZAAAAAYAAAAAAAAABmJ1eV9pbgAAAAAACwAAAAEAAAPpAAAH0AAAAAlTaXRSZXN1bHQAAAAAAAAD",
-        "AAAAAAAAAN9Db25zdHJ1Y3RvcjogYWRtaW4sIGdhbWVfaHViLCBhbmQgZmlyc3QgdGFibGUgKG1lc2EpLiBGdXJ0aGVyIHRhYmxlcyB2aWEgYWRkX3RhYmxlIChhZG1pbiBvbmx5KS4KbWF4X3NlYXRzOiBlLmcuIDIgZm9yIE1WUCwgNCBmb3IgNC1wbGF5ZXIgdGFibGVzIGxhdGVyLgpBbHNvIHNldHMgQ29uZmlnIHNvIHN0YXJ0X2dhbWUgYW5kIG90aGVyIGZ1bmN0aW9ucyB3b3JrIChsb2FkX2NvbmZpZykuAAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAABwAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAAAAAAC3NtYWxsX2JsaW5kAAAAAAsAAAAAAAAACWJpZ19ibGluZAAAAAAAAAsAAAAAAAAACm1pbl9idXlfaW4AAAAAAAsAAAAAAAAACm1heF9idXlfaW4AAAAAAAsAAAAAAAAACW1heF9zZWF0cwAAAAAAAAQAAAAA",
+        "AAAAAAAAAB5UaGUgcHJvcG9zZWQgb3duZXIgdGFrZXMgb3Zlci4AAAAAAAxhY2NlcHRfb3duZXIAAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAO1DbG9zZSBhIHRhYmxlIGZvciBnb29kIChvcGVyYXRvciBvbmx5KS4gSXQgdGFrZXMgbm8gbmV3IHNlYXRzLCBpdHMgd2FpdGluZyBsaXN0IGlzCnJlZnVuZGVkIGFuZCBnYW1lcyBpbiBwcm9ncmVzcyBwbGF5IG9uIHRvIHRoZSBlbmQuIFRoZSB0YWJsZSBpcyB0aGVuIGRlbGV0ZWQ6IGF0IG9uY2UKaWYgaXQgaGFzIG5vIGdhbWVzIGluIHByb2dyZXNzLCBvdGhlcndpc2Ugd2hlbiB0aGUgbGFzdCBvbmUgc2V0dGxlcy4AAAAAAAAMcmV0aXJlX3RhYmxlAAAAAQAAAAAAAAAIdGFibGVfaWQAAAAGAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAZpTaXQgYXQgYSB0YWJsZS4gWW91IGFyZSBwYWlyZWQgd2l0aCB0aGUgd2FpdGluZyBwbGF5ZXIgd2hvc2UgYnV5LWluIGlzIGNsb3Nlc3QgKG9sZGVzdCBmaXJzdCBvbgp0aWVzKSwgaWYgaXQgaXMgd2l0aGluIHRoZSB0YWJsZSdzIHRvbGVyYW5jZSwgYW5kIGEgZ2FtZSBzdGFydHMgd2l0aCB0aGUgZWZmZWN0aXZlIHN0YWNrICh0aGUKc21hbGxlciBidXktaW4pLCByZXR1cm5pbmcgZ2FtZV9pZC4gT3RoZXJ3aXNlIHlvdSBqb2luIHRoZSB0YWJsZSdzIHdhaXRpbmcgbGlzdC4gTWFueSBwYWlycyBjYW4KcGxheSBhdCB0aGUgc2FtZSB0YWJsZS4KQ29uZm9ybWUgdsOjbyBlbnRyYW5kbyB1c3XDoXJpb3MsIG1vbnRhbS1zZSBhcyBtZXNhcyB2aXJ0dWFpczsgYSBtZXNhIGluaWNpYSBxdWFuZG8gdGVtIDIgam9nYWRvcmVzLgAAAAAADHNpdF9hdF90YWJsZQAAAAMAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAIdGFibGVfaWQAAAAGAAAAAAAAAAZidXlfaW4AAAAAAAsAAAABAAAD6QAAB9AAAAAJU2l0UmVzdWx0AAAAAAAAAw==",
+        "AAAAAAAAAKRDaGFuZ2UgYSB0YWJsZSdzIGJsaW5kcywgYnV5LWluIGxpbWl0cyBvciBzZWF0cyAob3BlcmF0b3Igb25seSkuIEdhbWVzIGFscmVhZHkgc3RhcnRlZAprZWVwIHRoZSBzdGFrZXMgdGhleSBzdGFydGVkIHdpdGg7IHBsYXllcnMgYWxyZWFkeSB3YWl0aW5nIGtlZXAgdGhlaXIgYnV5LWluLgAAAAx1cGRhdGVfdGFibGUAAAACAAAAAAAAAAh0YWJsZV9pZAAAAAYAAAAAAAAABXRhYmxlAAAAAAAH0AAAAAVUYWJsZQAAAAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAOJDb25zdHJ1Y3RvcjogYWRtaW4sIGdhbWVfaHViLCBhbmQgZmlyc3QgdGFibGUgKG1lc2EpLiBGdXJ0aGVyIHRhYmxlcyB2aWEgYWRkX3RhYmxlIChvcGVyYXRvciBvbmx5KS4KbWF4X3NlYXRzOiBlLmcuIDIgZm9yIE1WUCwgNCBmb3IgNC1wbGF5ZXIgdGFibGVzIGxhdGVyLgpBbHNvIHNldHMgQ29uZmlnIHNvIHN0YXJ0X2dhbWUgYW5kIG90aGVyIGZ1bmN0aW9ucyB3b3JrIChsb2FkX2NvbmZpZykuAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAcAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAIZ2FtZV9odWIAAAATAAAAAAAAAAtzbWFsbF9ibGluZAAAAAALAAAAAAAAAAliaWdfYmxpbmQAAAAAAAALAAAAAAAAAAptaW5fYnV5X2luAAAAAAALAAAAAAAAAAptYXhfYnV5X2luAAAAAAALAAAAAAAAAAltYXhfc2VhdHMAAAAAAAAEAAAAAA==",
         "AAAAAAAAAGhbRGVwcmVjYXRlZF0gVXNlIGFkdmFuY2VfdGltZW91dC4gUmVjbGFtYXIgdGltZW91dCAoam9nYWRvciBpbmF0aXZvIHBlcmRlKS4gTWFudGlkbyBwb3IgY29tcGF0aWJpbGlkYWRlLgAAAA1jbGFpbV90aW1lb3V0AAAAAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAdnYW1lX2lkAAAAAAYAAAABAAAD6QAAAAIAAAAD",
-        "AAAAAAAAAQ9DYW5jZWwgYSB3YWl0aW5nIHNlc3Npb24gKDEvMikuIEZyZWVzIHRoZSB0YWJsZSBmb3IgbmV3IHBsYXllcnMuCi0gSWYgY2FsbGVyIGlzIHRoZSB3YWl0aW5nIHBsYXllciAocGxheWVyMSk6IGNhbiBjYW5jZWwgYW55dGltZSDihpIgcmVmdW5kIGFuZCBjbGVhci4KLSBFbHNlOiBvbmx5IGFmdGVyIHdhaXRpbmdfdGltZW91dCBzZWNvbmRzIOKGkiByZWZ1bmQgcGxheWVyMSBhbmQgY2xlYXIgKHNvIHRoZSBtZXNhIGNhbiBiZSAiZW5jZXJyYWRhIiBhZnRlciBhIHBlcmlvZCkuAAAAAA5jYW5jZWxfd2FpdGluZwAAAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAh0YWJsZV9pZAAAAAYAAAABAAAD6QAAAAIAAAAD",
-        "AAAAAAAAAWVRdWFuZG8gbyB0ZW1wbyBkZSByZXNwb3N0YSBkbyBqb2dhZG9yIGFjYWJhLCBvIGpvZ28gYXZhbsOnYSBhdXRvbWF0aWNhbWVudGU6Ci0gRW0gYXBvc3Rhczogc2UgbsOjbyBow6EgYXBvc3RhIGEgcGFnYXIg4oaSIENIRUNLIGF1dG9tw6F0aWNvOyBzZW7Do28g4oaSIEZPTEQgYXV0b23DoXRpY28uIE8gam9nbyBjb250aW51YS4KLSBFbSBjb21taXQvcmV2ZWFsIG91IHNob3dkb3duOiBvIGpvZ2Fkb3IgcXVlIG7Do28gYWdpdSBwZXJkZSBhIG3Do28gKG91dHJvIGdhbmhhKS4KUXVhbHF1ZXIgdW0gcG9kZSBjaGFtYXIgKGV4LjogYWR2ZXJzw6FyaW8gb3UgZnJvbnRlbmQpIHBhcmEgbyBqb2dvIG7Do28gZmljYXIgdHJhdmFkby4AAAAAAAAPYWR2YW5jZV90aW1lb3V0AAAAAAEAAAAAAAAAB2dhbWVfaWQAAAAABgAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAADFXaGVuIHRoZSBjb250cmFjdCB3YXMgcGF1c2VkLCBOb25lIHdoaWxlIGl0IHJ1bnMuAAAAAAAADWdldF9wYXVzZWRfYXQAAAAAAAAAAAAAAQAAA+gAAAAG",
+        "AAAAAAAAAVBDYW5jZWwgYSBjYXNoIGdhbWUgYnkgYWdyZWVtZW50LiBCb3RoIHBsYXllcnMgYXV0aG9yaXplIGAoZ2FtZV9pZCwgaGFuZClgLCB3aGVyZSBgaGFuZGAKaXMgdGhlIG51bWJlciBvZiBoYW5kcyBhbHJlYWR5IHNldHRsZWQgKGBoYW5kc193b24xICsgaGFuZHNfd29uMmApLCBzbyBhbiBhZ3JlZW1lbnQKY2Fubm90IGJlIHJlcGxheWVkIG9uIGEgbGF0ZXIgaGFuZC4gQmV0cyBpbiB0aGUgY3VycmVudCBoYW5kIGdvIGJhY2sgdG8gdGhlaXIgb3duZXJzLAplYWNoIHN0YWtlIGlzIHJlZnVuZGVkIGZyb20gZXNjcm93IGFuZCB0aGUgZ2FtZSBlbmRzIENhbmNlbGxlZCB3aXRoIG5vIHdpbm5lci4AAAANbXV0dWFsX2NhbmNlbAAAAAAAAAIAAAAAAAAAB2dhbWVfaWQAAAAABgAAAAAAAAAEaGFuZAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAM5PZmZlciB0aGUgb3Bwb25lbnQgb2YgYSBzZXR0bGVkIGNhc2ggZ2FtZSBhIHJlbWF0Y2ggYXQgdGhlIHNhbWUgdGFibGUgYW5kIHN0YWtlcy4gVGhlCmdhbWUncyBidXktaW4gaXMgbG9ja2VkIG5vdywgc28gdGhlIG9wcG9uZW50IGFsb25lIHN0YXJ0cyB0aGUgcmVtYXRjaCB3aXRoCmFjY2VwdF9yZW1hdGNoLiBBIGdhbWUgY2FuIGJlIHJlbWF0Y2hlZCBvbmNlLgAAAAAADW9mZmVyX3JlbWF0Y2gAAAAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAB2dhbWVfaWQAAAAABgAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAIxTdGFydCBoYW5kaW5nIHRoZSBjb250cmFjdCB0byBgbmV3X293bmVyYCAob3duZXIgb25seSk7IE5vbmUgd2l0aGRyYXdzIHRoZSBwcm9wb3NhbC4KTm90aGluZyBjaGFuZ2VzIHVudGlsIHRoZSBuZXcgb3duZXIgY2FsbHMgYWNjZXB0X293bmVyLgAAAA1wcm9wb3NlX293bmVyAAAAAAAAAQAAAAAAAAAJbmV3X293bmVyAAAAAAAD6AAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAGxSZXZva2UgYW4gdW51c2VkIGludml0ZSAocGxheWVyMSBvbmx5KS4gUGFzcyB0aGUgaW52aXRlIHNvIHRoZSByZXZvY2F0aW9uIGxhc3RzIHVudGlsIGl0CndvdWxkIGhhdmUgZXhwaXJlZC4AAAANcmV2b2tlX2ludml0ZQAAAAAAAAEAAAAAAAAABmludml0ZQAAAAAH0AAAAAZJbnZpdGUAAAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAdJBY2NlcHQgdGhlIG9wcG9uZW50J3MgcmVtYXRjaCBvZmZlcjogYSBuZXcgZ2FtZSBzdGFydHMgd2l0aCB0aGUgc2FtZSBwbGF5ZXJzLCB0YWJsZSBhbmQKc3Rha2VzLCBhbmQgdGhlIGRlYWxlciBidXR0b24gbW92ZXMgb24gYXMgaWYgaXQgd2VyZSB0aGUgbmV4dCBoYW5kLiBUaGUgb2ZmZXJpbmcKcGxheWVyJ3MgYnV5LWluIHdhcyBsb2NrZWQgd2l0aCB0aGUgb2ZmZXIsIHRoZSBhY2NlcHRpbmcgcGxheWVyJ3MgaXMgbG9ja2VkIG5vdzsgZWFjaApsb2NrIHJldXNlcyB3aGF0IHRoZSBQYXltZW50Q29udHJvbGxlciBzdGlsbCBob2xkcyBmb3IgdGhlIHBsYXllciAoc2V0X2tlZXBfYmFsYW5jZSkuClRoZSB0YWJsZSBtdXN0IHN0aWxsIGJlIG9wZW4gYW5kIHRha2UgdGhlIG9mZmVyJ3MgYnV5LWluOyBvdGhlcndpc2UgdGhlIG9mZmVyIGNhbiBvbmx5CmJlIGNhbmNlbGxlZC4gUmV0dXJucyB0aGUgbmV3IGdhbWUgaWQuAAAAAAAOYWNjZXB0X3JlbWF0Y2gAAAAAAAIAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAHZ2FtZV9pZAAAAAAGAAAAAQAAA+kAAAAGAAAAAw==",
+        "AAAAAAAAAJdXaXRoZHJhdyBhIHJlbWF0Y2ggb2ZmZXIgd2l0aCBhIHJlZnVuZC4gVGhlIG9mZmVyaW5nIHBsYXllciBjYW4gZG8gc28gYW55dGltZTsgYW55b25lCmVsc2Ugb25jZSB0aGUgdGFibGUncyB3YWl0aW5nIHRpbWVvdXQgaGFzIHBhc3NlZCBzaW5jZSB0aGUgb2ZmZXIuAAAAAA5jYW5jZWxfcmVtYXRjaAAAAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAdnYW1lX2lkAAAAAAYAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAOVMZWF2ZSBhIHRhYmxlJ3Mgd2FpdGluZyBsaXN0LCB3aXRoIGEgcmVmdW5kLgotIElmIGNhbGxlciBpcyB3YWl0aW5nIGF0IHRoZSB0YWJsZTogcmVtb3ZlcyB0aGVpciBvd24gc2VhdCwgYW55dGltZS4KLSBFbHNlOiByZW1vdmVzIGV2ZXJ5IHNlYXQgb2xkZXIgdGhhbiB0aGUgdGFibGUncyB3YWl0aW5nIHRpbWVvdXQgKHNvIHRoZSBtZXNhIGNhbiBiZSAiZW5jZXJyYWRhIiBhZnRlciBhIHBlcmlvZCkuAAAAAAAADmNhbmNlbF93YWl0aW5nAAAAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACHRhYmxlX2lkAAAABgAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAAAAAAAOZ2V0X3RhYmxlX21ldGEAAAAAAAEAAAAAAAAACHRhYmxlX2lkAAAABgAAAAEAAAPpAAAH0AAAAAlUYWJsZU1ldGEAAAAAAAAD",
+        "AAAAAAAAAFJUb3VybmFtZW50IHN0YXRlOiBlbnRyYW50cywgY2hpcCBjb3VudHMsIHRhYmxlcywgZWxpbWluYXRpb25zIGFuZCByZW1haW5pbmcgY291bnQuAAAAAAAOZ2V0X3RvdXJuYW1lbnQAAAAAAAEAAAAAAAAADXRvdXJuYW1lbnRfaWQAAAAAAAAGAAAAAQAAA+kAAAfQAAAAClRvdXJuYW1lbnQAAAAAAAM=",
+        "AAAAAAAAADhXaG8gbXVzdCBhY3Qgbm93LCB0aGVpciBkZWFkbGluZSBhbmQgcmVtYWluaW5nIHRpbWViYW5rLgAAAA5nZXRfdHVybl9jbG9jawAAAAAAAQAAAAAAAAAHZ2FtZV9pZAAAAAAGAAAAAQAAA+kAAAfQAAAACVR1cm5DbG9jawAAAAAAAAM=",
+        "AAAAAAAAAE5TZXQgYSB0YWJsZSdzIGxvYmJ5IG1ldGFkYXRhIChvcGVyYXRvciBvbmx5KS4gTmFtZXMgYXJlIGF0IG1vc3QgNjQgY2hhcmFjdGVycy4AAAAAAA5zZXRfdGFibGVfbWV0YQAAAAAAAgAAAAAAAAAIdGFibGVfaWQAAAAGAAAAAAAAAARtZXRhAAAH0AAAAAlUYWJsZU1ldGEAAAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAArZRdWFuZG8gbyB0ZW1wbyBkZSByZXNwb3N0YSBkbyBqb2dhZG9yIGFjYWJhLCBvIGpvZ28gYXZhbsOnYSBhdXRvbWF0aWNhbWVudGU6Ci0gRW0gYXBvc3Rhczogc2UgbsOjbyBow6EgYXBvc3RhIGEgcGFnYXIg4oaSIENIRUNLIGF1dG9tw6F0aWNvOyBzZW7Do28g4oaSIEZPTEQgYXV0b23DoXRpY28uIE8gam9nbyBjb250aW51YS4KLSBFbSBjb21taXQvcmV2ZWFsOiBvIGpvZ28gw6kgY2FuY2VsYWRvIGUgb3Mgc3Rha2VzIGRldm9sdmlkb3MgKGNvbmZpZy5hYmFuZG9uX3BvbGljeSBwb2RlCmFwbGljYXIgdW1hIG11bHRhIGEgcXVlbSBhYmFuZG9ub3UpLiBFbSBtZXNhcyBkZSB0b3JuZWlvIHF1ZW0gYWJhbmRvbm91IHBlcmRlIG8gc3RhY2suCi0gRW0gc2hvd2Rvd246IG8gam9nYWRvciBxdWUgbsOjbyBhZ2l1IHBlcmRlIGEgbcOjbyAob3V0cm8gZ2FuaGEpLgpRdWFscXVlciB1bSBwb2RlIGNoYW1hciAoZXguOiBhZHZlcnPDoXJpbyBvdSBmcm9udGVuZCkgcGFyYSBvIGpvZ28gbsOjbyBmaWNhciB0cmF2YWRvLgpTZSBob3V2ZXIgS2VlcGVyQm91bnR5IGNvbmZpZ3VyYWRvLCBgY2FsbGVyYCByZWNlYmUgbyBib3VudHksIGV4Y2V0byBzZSBmb3IgdW0gZG9zCmVuZGVyZcOnb3MgZGEgbWVzYSAodmVyaWZpY2HDp8OjbyBkZSBtZWxob3IgZXNmb3LDp286IHVtYSBzZWd1bmRhIGNvbnRhIHJlY2ViZSBjb21vIGtlZXBlcikuAAAAAAAPYWR2YW5jZV90aW1lb3V0AAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAHZ2FtZV9pZAAAAAAGAAAAAQAAA+kAAAACAAAAAw==",
         "AAAAAAAAAFlOdW1iZXIgb2YgdGFibGVzIChpZHMgMC4uY291bnQtMSkuIEZyb250ZW5kIGNhbiBpdGVyYXRlIGdldF90YWJsZSgwKS4uZ2V0X3RhYmxlKGNvdW50LTEpLgAAAAAAAA9nZXRfdGFibGVfY291bnQAAAAAAAAAAAEAAAAG",
-        "AAAAAAAAAEZHZXQgY3VycmVudCB3YWl0aW5nIHNlc3Npb24gZm9yIGEgdGFibGUgKDEvMikuIFJldHVybnMgZXJyb3Igd2hlbiAwLzIuAAAAAAARZ2V0X3RhYmxlX3dhaXRpbmcAAAAAAAABAAAAAAAAAAh0YWJsZV9pZAAAAAYAAAABAAAD6QAAB9AAAAAOV2FpdGluZ1Nlc3Npb24AAAAAAAM=",
-        "AAAAAAAAAH5TZXQgb3IgY2xlYXIgdGhlIHBheW1lbnQgY29udHJvbGxlciAoZXNjcm93KS4gQWRtaW4gb25seS4gRW5hYmxlcyBsb2NrX2Z1bmRzIG9uIHNpdC9jcmVhdGUvam9pbiBhbmQgcGF5b3V0X3dpbm5lciBvbiBnYW1lIGVuZC4AAAAAABZzZXRfcGF5bWVudF9jb250cm9sbGVyAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAEnBheW1lbnRfY29udHJvbGxlcgAAAAAD6AAAABMAAAAA" ]),
+        "AAAAAAAAAC1BbGwgcGxheWVycyB3YWl0aW5nIGF0IGEgdGFibGUsIG9sZGVzdCBmaXJzdC4AAAAAAAAPZ2V0X3RhYmxlX3F1ZXVlAAAAAAEAAAAAAAAACHRhYmxlX2lkAAAABgAAAAEAAAPpAAAD6gAAB9AAAAAOV2FpdGluZ1Nlc3Npb24AAAAAAAM=",
+        "AAAAAAAAAFpHYW1lcyB0aGUgcGxheWVyIGlzIGluIChub3QgZmluaXNoZWQgb3IgY2FuY2VsbGVkKSwgc28gYSBjbGllbnQgY2FuIHJlc3VtZSBhZnRlciBhIHJlbG9hZC4AAAAAABBnZXRfYWN0aXZlX2dhbWVzAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+oAAAAG",
+        "AAAAAAAAAFZTdW1tYXJ5IG9mIGEgZ2FtZTogdGhlIGFyY2hpdmVkIHJlY29yZCBvbmNlIGl0IGlzIG92ZXIsIG90aGVyd2lzZSBidWlsdCBmcm9tIHRoZSBnYW1lLgAAAAAAEGdldF9nYW1lX3N1bW1hcnkAAAABAAAAAAAAAAdnYW1lX2lkAAAAAAYAAAABAAAD6QAAB9AAAAALR2FtZVN1bW1hcnkAAAAAAw==",
+        "AAAAAAAAADxUaGUgcGxheWVyJ3MgbGFzdCBmaW5pc2hlZCBvciBjYW5jZWxsZWQgZ2FtZXMsIG5ld2VzdCBmaXJzdC4AAAAQZ2V0X3JlY2VudF9nYW1lcwAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPqAAAABg==",
+        "AAAAAAAAAAAAAAAQZ2V0X3RhYmxlX2FjY2VzcwAAAAEAAAAAAAAACHRhYmxlX2lkAAAABgAAAAEAAAPpAAAH0AAAAAtUYWJsZUFjY2VzcwAAAAAD",
+        "AAAAAAAAAAAAAAAQZ2V0X3RhYmxlX2Zvcm1hdAAAAAEAAAAAAAAACHRhYmxlX2lkAAAABgAAAAEAAAPpAAAH0AAAAAtNYXRjaEZvcm1hdAAAAAAD",
+        "AAAAAAAAAAAAAAAQZ2V0X3RhYmxlX3N0YXR1cwAAAAEAAAAAAAAACHRhYmxlX2lkAAAABgAAAAEAAAPpAAAH0AAAAAtUYWJsZVN0YXR1cwAAAAAD",
+        "AAAAAAAAAIBXaGVyZSB0aGUgcGxheWVyIGlzIHdhaXRpbmcgZm9yIGFuIG9wcG9uZW50ICh0aGVpciBlYXJsaWVzdCBzZWF0IGlmIHRoZXkgd2FpdCBhdApzZXZlcmFsIHRhYmxlcykuIFJldHVybnMgZXJyb3Igd2hlbiBub3Qgc2VhdGVkLgAAABBnZXRfd2FpdGluZ19zZWF0AAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAfQAAAAC1dhaXRpbmdTZWF0AAAAAAM=",
+        "AAAAAAAAAH9NYWtlIGEgdGFibGUgcHJpdmF0ZSAoQWxsb3dsaXN0IG9yIFBhc3N3b3JkKSBvciBwdWJsaWMgYWdhaW4gKG9wZXJhdG9yIG9ubHkpLiBTZWF0cyBhbmQKZ2FtZXMgYWxyZWFkeSBzdGFydGVkIGFyZSBub3QgYWZmZWN0ZWQuAAAAABBzZXRfdGFibGVfYWNjZXNzAAAAAgAAAAAAAAAIdGFibGVfaWQAAAAGAAAAAAAAAAZhY2Nlc3MAAAAAB9AAAAALVGFibGVBY2Nlc3MAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAGdTZXQgdGhlIG1hdGNoIGZvcm1hdCBmb3IgbmV3IGdhbWVzIGF0IGEgdGFibGUgKG9wZXJhdG9yIG9ubHkpLiBHYW1lcyBrZWVwIHRoZSBmb3JtYXQKdGhleSBzdGFydGVkIHdpdGguAAAAABBzZXRfdGFibGVfZm9ybWF0AAAAAgAAAAAAAAAIdGFibGVfaWQAAAAGAAAAAAAAAAZmb3JtYXQAAAAAB9AAAAALTWF0Y2hGb3JtYXQAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAK5TdGFydCBhIHRvdXJuYW1lbnQgb25jZSByZWdpc3RyYXRpb24gaGFzIGNsb3NlZCAoYW55b25lIG1heSBjYWxsKS4gRW50cmFudHMgYXJlIHNlYXRlZAphdCByYW5kb20sIHR3byBwZXIgdGFibGUuIFdpdGggZmV3ZXIgdGhhbiAyIGVudHJhbnRzIGl0IGlzIGNhbmNlbGxlZCBhbmQgZmVlcyByZWZ1bmRlZC4AAAAAABBzdGFydF90b3VybmFtZW50AAAAAQAAAAAAAAANdG91cm5hbWVudF9pZAAAAAAAAAYAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAFlDcmVhdGUgYSB0b3VybmFtZW50IChvcGVyYXRvciBvbmx5KS4gUmVnaXN0cmF0aW9uIHN0YXlzIG9wZW4gdW50aWwgcmVnaXN0cmF0aW9uX2RlYWRsaW5lLgAAAAAAABFjcmVhdGVfdG91cm5hbWVudAAAAAAAAAEAAAAAAAAABmNvbmZpZwAAAAAH0AAAABBUb3VybmFtZW50Q29uZmlnAAAAAQAAA+kAAAAGAAAAAw==",
+        "AAAAAAAAAG1Mb2JieTogYWN0aXZlIGdhbWVzIHdob3NlIHBsYXllciBvbiB0aGUgY2xvY2sgaXMgb3V0IG9mIHRpbWUgKGFkdmFuY2VfdGltZW91dCBhcHBsaWVzKS4KUGFnZWQgbGlrZSBnZXRfZ2FtZXMuAAAAAAAAEWdldF9leHBpcmVkX2dhbWVzAAAAAAAAAgAAAAAAAAAGY3Vyc29yAAAAAAAGAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6QAAB9AAAAAIR2FtZVBhZ2UAAAAD",
+        "AAAAAAAAAAAAAAARZ2V0X2tlZXBlcl9ib3VudHkAAAAAAAAAAAAAAQAAA+gAAAfQAAAADEtlZXBlckJvdW50eQ==",
+        "AAAAAAAAAAAAAAARZ2V0X3BlbmRpbmdfb3duZXIAAAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAEJPbGRlc3QgcGxheWVyIHdhaXRpbmcgYXQgYSB0YWJsZS4gUmV0dXJucyBlcnJvciB3aGVuIG5vYm9keSB3YWl0cy4AAAAAABFnZXRfdGFibGVfd2FpdGluZwAAAAAAAAEAAAAAAAAACHRhYmxlX2lkAAAABgAAAAEAAAPpAAAH0AAAAA5XYWl0aW5nU2Vzc2lvbgAAAAAAAw==",
+        "AAAAAAAAAERTZXQgb3IgY2xlYXIgdGhlIGtlZXBlciBib3VudHkgcGFpZCBieSBhZHZhbmNlX3RpbWVvdXQgKGFkbWluIG9ubHkpLgAAABFzZXRfa2VlcGVyX2JvdW50eQAAAAAAAAEAAAAAAAAABmJvdW50eQAAAAAD6AAAB9AAAAAMS2VlcGVyQm91bnR5AAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAATNzaXRfYXRfdGFibGUgZm9yIHByaXZhdGUgcGxheTogYHNlY3JldGAgaXMgcmV2ZWFsZWQgdG8gcGFzcyBhIFBhc3N3b3JkIHRhYmxlIG9yIHNlYXQsIGFuZApgc2VhdF9hY2Nlc3NgIHJlc3RyaWN0cyB3aG8gbWF5IGJlIHBhaXJlZCB3aXRoIHlvdSBpZiB5b3UgZW5kIHVwIHdhaXRpbmcuIEEgcHJpdmF0ZSB0YWJsZQpyZWplY3RzIGFueW9uZSBpdCBkb2VzIG5vdCBhZG1pdCB3aXRoIE5vdEludml0ZWQ7IGEgcHJpdmF0ZSBzZWF0IGlzIG9ubHkgcGFpcmVkIHdpdGggcGxheWVycwppdCBhZG1pdHMgKG90aGVycyBxdWV1ZSBhcyB1c3VhbCkuAAAAABFzaXRfYXRfdGFibGVfd2l0aAAAAAAAAAUAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAIdGFibGVfaWQAAAAGAAAAAAAAAAZidXlfaW4AAAAAAAsAAAAAAAAABnNlY3JldAAAAAAD6AAAAA4AAAAAAAAAC3NlYXRfYWNjZXNzAAAAB9AAAAALVGFibGVBY2Nlc3MAAAAAAQAAA+kAAAfQAAAACVNpdFJlc3VsdAAAAAAAAAM=",
+        "AAAAAAAAAXJPbmNlIHRoZSBjb250cmFjdCBoYXMgYmVlbiBwYXVzZWQgZm9yIEVNRVJHRU5DWV9HUkFDRSwgZWl0aGVyIHBsYXllciBjYW4gZW5kIGEgY2FzaApnYW1lIG9uIHRoZWlyIG93bjogYmV0cyBpbiB0aGUgY3VycmVudCBoYW5kIGdvIGJhY2ssIGVhY2ggc3Rha2UgaXMgcmVmdW5kZWQgZnJvbSBlc2Nyb3cKKGNoaXBzIHdvbiBiZXlvbmQgaXQgYXJlIHBhaWQgb3V0KSBhbmQgdGhlIGdhbWUgaXMgQ2FuY2VsbGVkLiBBIGdhbWUgc3RpbGwgd2FpdGluZyBmb3IKYW4gb3Bwb25lbnQgcmVmdW5kcyBpdHMgY3JlYXRvci4gVG91cm5hbWVudCBnYW1lcyBhcmUgbm90IGNvdmVyZWQ6IHRoZWlyIGNoaXBzIGFyZSB0aGUKdG91cm5hbWVudCdzIHByaXplIHBvb2wuAAAAAAASZW1lcmdlbmN5X3dpdGhkcmF3AAAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAB2dhbWVfaWQAAAAABgAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAEFUaW1lb3V0cyBpbiBmb3JjZSBhdCBhIHRhYmxlIChpdHMgb3ZlcnJpZGUgb3IgdGhlIGdsb2JhbCBjb25maWcpLgAAAAAAABJnZXRfdGFibGVfdGltZW91dHMAAAAAAAEAAAAAAAAACHRhYmxlX2lkAAAABgAAAAEAAAPpAAAH0AAAAA1UaW1lb3V0Q29uZmlnAAAAAAAAAw==",
+        "AAAAAAAAAKxPdmVycmlkZSAoU29tZSkgb3IgY2xlYXIgKE5vbmUpIGEgdGFibGUncyB0aW1lb3V0cy4gT3BlcmF0b3Igb25seS4gUGhhc2UgdGltZW91dHMgbXVzdCBiZQpub24temVybzsgd2FpdGluZyA9IDAga2VlcHMgd2FpdGluZyBzZXNzaW9ucyBvcGVuIHVudGlsIGNhbmNlbGxlZCBieSB0aGVpciBwbGF5ZXIuAAAAEnNldF90YWJsZV90aW1lb3V0cwAAAAAAAgAAAAAAAAAIdGFibGVfaWQAAAAGAAAAAAAAAAh0aW1lb3V0cwAAA+gAAAfQAAAADVRpbWVvdXRDb25maWcAAAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAFBHYW1lIGlkIG9mIHRoZSBzZXNzaW9uIHN0YXJ0ZWQgd2l0aCBgc2Vzc2lvbl9pZGAgKFNFU1NJT05fSURfQkFTRSArIHNlc3Npb25faWQpLgAAABNnZXRfc2Vzc2lvbl9nYW1lX2lkAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAYAAAABAAAD6QAAAAYAAAAD",
+        "AAAAAAAAAAAAAAATZ2V0X3RhYmxlX3RvbGVyYW5jZQAAAAABAAAAAAAAAAh0YWJsZV9pZAAAAAYAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAE1SZWdpc3RlciBmb3IgYSB0b3VybmFtZW50IGJlZm9yZSBpdHMgZGVhZGxpbmUuIExvY2tzIHRoZSBlbnRyeSBmZWUgaW4gZXNjcm93LgAAAAAAABNyZWdpc3Rlcl90b3VybmFtZW50AAAAAAIAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAANdG91cm5hbWVudF9pZAAAAAAAAAYAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAIFTZXQgaG93IGZhciBhcGFydCB0d28gYnV5LWlucyBtYXkgYmUgKGJwcyBvZiB0aGUgbGFyZ2VyIG9uZSkgdG8gYmUgcGFpcmVkIGF0IGEgdGFibGUKKG9wZXJhdG9yIG9ubHkpLiAwIHBhaXJzIG9ubHkgZXF1YWwgYnV5LWlucy4AAAAAAAATc2V0X3RhYmxlX3RvbGVyYW5jZQAAAAACAAAAAAAAAAh0YWJsZV9pZAAAAAYAAAAAAAAADXRvbGVyYW5jZV9icHMAAAAAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAEdCbGluZCBsZXZlbCBpbiBmb3JjZSBub3cgKHRoZSBmaXJzdCBsZXZlbCB1bnRpbCB0aGUgdG91cm5hbWVudCBzdGFydHMpLgAAAAAUZ2V0X3RvdXJuYW1lbnRfbGV2ZWwAAAABAAAAAAAAAA10b3VybmFtZW50X2lkAAAAAAAABgAAAAEAAAPpAAAH0AAAAApCbGluZExldmVsAAAAAAAD",
+        "AAAAAAAAAH5TZXQgb3IgY2xlYXIgdGhlIHBheW1lbnQgY29udHJvbGxlciAoZXNjcm93KS4gT3duZXIgb25seS4gRW5hYmxlcyBsb2NrX2Z1bmRzIG9uIHNpdC9jcmVhdGUvam9pbiBhbmQgcGF5b3V0X3dpbm5lciBvbiBnYW1lIGVuZC4AAAAAABZzZXRfcGF5bWVudF9jb250cm9sbGVyAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAEnBheW1lbnRfY29udHJvbGxlcgAAAAAD6AAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAARlTdGFydCBhIHNlc3Npb24gZnJvbSBwbGF5ZXIxJ3MgaW52aXRlIChYRFIgZmxvdyk6IHBsYXllcjEgc2lnbnMgKHNlc3Npb25faWQsIGludml0ZSkgYW5kCnBsYXllcjIgc3VibWl0cy4gRmFpbHMgd2l0aCBOb3RJbnZpdGVkIGlmIHRoZSBpbnZpdGUgbmFtZXMgYW5vdGhlciBvcHBvbmVudCwgSW52aXRlRXhwaXJlZAphZnRlciBgZXhwaXJlc19sZWRnZXJgLCBhbmQgSW52aXRlVXNlZCAvIEludml0ZVJldm9rZWQgb25jZSBjb25zdW1lZCBvciByZXZva2VkLgpSZXR1cm5zIHRoZSBnYW1lIGlkLgAAAAAAABZzdGFydF9nYW1lX3dpdGhfaW52aXRlAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAGAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAABmludml0ZQAAAAAH0AAAAAZJbnZpdGUAAAAAAAEAAAPpAAAABgAAAAM=",
+        "AAAAAAAAACxQbGF5ZXJzIHN0aWxsIGluIHRoZSB0b3VybmFtZW50IChjaGlwcyA+IDApLgAAABhnZXRfdG91cm5hbWVudF9yZW1haW5pbmcAAAABAAAAAAAAAA10b3VybmFtZW50X2lkAAAAAAAABgAAAAEAAAPpAAAD6gAAABMAAAAD",
+        "AAAAAAAAARpEZWFsIGEgbmV3IGdhbWUgYXQgYSB0b3VybmFtZW50IHRhYmxlIHdob3NlIGdhbWUgd2FzIGxvc3QgYmVjYXVzZSBpdHMgc3RvcmFnZSBleHBpcmVkCihhbnlvbmUgbWF5IGNhbGwpLiBUaGUgcGxheWVycyBnbyBvbiB3aXRoIHRoZSBjaGlwcyB0aGV5IGhhZCBhZnRlciB0aGVpciBsYXN0IHNldHRsZWQKaGFuZCwgc28gdGhlIGhhbmQgaW4gcHJvZ3Jlc3MgaXMgdm9pZDsgaXRzIGh1YiBzZXNzaW9uIGlzIGNsb3NlZCBmb3IgdGhlIGNoaXAgbGVhZGVyLgpSZXR1cm5zIHRoZSBuZXcgZ2FtZSBpZC4AAAAAABhyZXN0YXJ0X3RvdXJuYW1lbnRfdGFibGUAAAACAAAAAAAAAA10b3VybmFtZW50X2lkAAAAAAAABgAAAAAAAAAJdGFibGVfaWR4AAAAAAAABAAAAAEAAAPpAAAABgAAAAM=" ]),
       options
     )
   }
   public readonly fromJSON = {
     act: this.txFromJSON<Result<void>>,
-        get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
+        pause: this.txFromJSON<Result<void>>,
+        rebuy: this.txFromJSON<Result<void>>,
+        top_up: this.txFromJSON<Result<void>>,
+        get_hub: this.txFromJSON<Result<string>>,
+        migrate: this.txFromJSON<Result<u64>>,
+        set_hub: this.txFromJSON<Result<void>>,
+        unpause: this.txFromJSON<Result<void>>,
+        upgrade: this.txFromJSON<Result<void>>,
+        version: this.txFromJSON<u32>,
         get_game: this.txFromJSON<Result<Game>>,
+        get_role: this.txFromJSON<Result<string>>,
+        set_role: this.txFromJSON<Result<void>>,
         add_table: this.txFromJSON<Result<u64>>,
-        get_admin: this.txFromJSON<string>,
+        bump_game: this.txFromJSON<Result<void>>,
+        get_admin: this.txFromJSON<Result<string>>,
+        get_games: this.txFromJSON<GamePage>,
         get_table: this.txFromJSON<Result<Table>>,
         join_game: this.txFromJSON<Result<void>>,
         send_chat: this.txFromJSON<Result<void>>,
-        set_admin: this.txFromJSON<null>,
+        close_game: this.txFromJSON<Result<void>>,
         get_config: this.txFromJSON<Result<GameConfig>>,
+        get_tables: this.txFromJSON<Array<TableInfo>>,
         initialize: this.txFromJSON<Result<void>>,
+        leave_game: this.txFromJSON<Result<void>>,
+        set_config: this.txFromJSON<Result<void>>,
         start_game: this.txFromJSON<Result<void>>,
         commit_seed: this.txFromJSON<Result<void>>,
         create_game: this.txFromJSON<Result<u64>>,
+        get_rematch: this.txFromJSON<Result<RematchOffer>>,
+        pause_table: this.txFromJSON<Result<void>>,
         post_blinds: this.txFromJSON<Result<void>>,
+        prune_games: this.txFromJSON<u32>,
         reveal_hand: this.txFromJSON<Result<void>>,
         reveal_seed: this.txFromJSON<Result<void>>,
+        accept_owner: this.txFromJSON<Result<void>>,
+        retire_table: this.txFromJSON<Result<void>>,
         sit_at_table: this.txFromJSON<Result<SitResult>>,
+        update_table: this.txFromJSON<Result<void>>,
         claim_timeout: this.txFromJSON<Result<void>>,
+        get_paused_at: this.txFromJSON<Option<u64>>,
+        mutual_cancel: this.txFromJSON<Result<void>>,
+        offer_rematch: this.txFromJSON<Result<void>>,
+        propose_owner: this.txFromJSON<Result<void>>,
+        revoke_invite: this.txFromJSON<Result<void>>,
+        accept_rematch: this.txFromJSON<Result<u64>>,
+        cancel_rematch: this.txFromJSON<Result<void>>,
         cancel_waiting: this.txFromJSON<Result<void>>,
+        get_table_meta: this.txFromJSON<Result<TableMeta>>,
+        get_tournament: this.txFromJSON<Result<Tournament>>,
+        get_turn_clock: this.txFromJSON<Result<TurnClock>>,
+        set_table_meta: this.txFromJSON<Result<void>>,
         advance_timeout: this.txFromJSON<Result<void>>,
         get_table_count: this.txFromJSON<u64>,
+        get_table_queue: this.txFromJSON<Result<Array<WaitingSession>>>,
+        get_active_games: this.txFromJSON<Array<u64>>,
+        get_game_summary: this.txFromJSON<Result<GameSummary>>,
+        get_recent_games: this.txFromJSON<Array<u64>>,
+        get_table_access: this.txFromJSON<Result<TableAccess>>,
+        get_table_format: this.txFromJSON<Result<MatchFormat>>,
+        get_table_status: this.txFromJSON<Result<TableStatus>>,
+        get_waiting_seat: this.txFromJSON<Result<WaitingSeat>>,
+        set_table_access: this.txFromJSON<Result<void>>,
+        set_table_format: this.txFromJSON<Result<void>>,
+        start_tournament: this.txFromJSON<Result<void>>,
+        create_tournament: this.txFromJSON<Result<u64>>,
+        get_expired_games: this.txFromJSON<Result<GamePage>>,
+        get_keeper_bounty: this.txFromJSON<Option<KeeperBounty>>,
+        get_pending_owner: this.txFromJSON<Option<string>>,
         get_table_waiting: this.txFromJSON<Result<WaitingSession>>,
-        set_payment_controller: this.txFromJSON<null>
+        set_keeper_bounty: this.txFromJSON<Result<void>>,
+        sit_at_table_with: this.txFromJSON<Result<SitResult>>,
+        emergency_withdraw: this.txFromJSON<Result<void>>,
+        get_table_timeouts: this.txFromJSON<Result<TimeoutConfig>>,
+        set_table_timeouts: this.txFromJSON<Result<void>>,
+        get_session_game_id: this.txFromJSON<Result<u64>>,
+        get_table_tolerance: this.txFromJSON<Result<u32>>,
+        register_tournament: this.txFromJSON<Result<void>>,
+        set_table_tolerance: this.txFromJSON<Result<void>>,
+        get_tournament_level: this.txFromJSON<Result<BlindLevel>>,
+        set_payment_controller: this.txFromJSON<Result<void>>,
+        start_game_with_invite: this.txFromJSON<Result<u64>>,
+        get_tournament_remaining: this.txFromJSON<Result<Array<string>>>,
+        restart_tournament_table: this.txFromJSON<Result<u64>>
   }
 }
\ No newline at end of file
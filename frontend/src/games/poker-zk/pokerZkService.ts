@@ -75,14 +75,21 @@ export function getMyHoleCardsFromGame(game: Game | null, userAddress: string):
   return null;
 }
 
-/** Generate a random session ID (u64 range, 0 to 2^32-1 for simplicity). Use as bigint for contract. */
+/** Generate a random session ID for start_game. The contract accepts ids below 2^31 (the hub's other half numbers sequential games). */
 export function createRandomSessionId(): bigint {
   const bytes = new Uint8Array(4);
   if (typeof crypto !== 'undefined' && crypto.getRandomValues) {
     crypto.getRandomValues(bytes);
   }
   const view = new DataView(bytes.buffer);
-  return BigInt(view.getUint32(0, true));
+  return BigInt(view.getUint32(0, true) & 0x7fffffff);
+}
+
+/** Game id of a start_game session: the contract stores sessions above every sequential game id. */
+export const SESSION_ID_BASE = 1n << 32n;
+
+export function sessionGameId(sessionId: bigint): bigint {
+  return SESSION_ID_BASE + sessionId;
 }
 
 /**
@@ -374,14 +381,14 @@ export class PokerZkService {
   }
 
   /**
-   * Import P1 auth, inject into tx, sign with P2 and submit.
+   * Import P1 auth, inject into tx, sign with P2 and submit. Returns the id of the started game.
    * Fluxo do template: após inject, re-importar via toXDR/txFromXDR para que signAndSend use auth entries do parsing XDR (evita "reading 'switch'").
    */
   async importAuthEntryAndSubmit(
     player1AuthEntryXDR: string,
     player2: string,
     player2Signer: Pick<ClientOptions, 'signTransaction' | 'signAuthEntry'>
-  ): Promise<unknown> {
+  ): Promise<bigint> {
     console.log(LOG, 'Import Auth Entry: start', { player2: player2.slice(0, 8) + '…' });
     const { sessionId, tableId, player1, buyIn } = this.parseAuthEntry(player1AuthEntryXDR);
     const client = new PokerZkClient({
@@ -400,7 +407,7 @@ export class PokerZkService {
       ...player2Signer,
     } as contract.ClientOptions);
     console.log(LOG, 'Import Auth Entry: ok', { result: sent?.result != null ? 'ok' : 'null' });
-    return sent?.result ?? sent;
+    return sessionGameId(sessionId);
   }
 
   /**
@@ -0,0 +1,164 @@
+//! Poker ZK keeper: finds games whose turn clock has run out and waiting seats past their
+//! table's waiting timeout, then calls `advance_timeout` / `cancel_waiting` on them.
+//!
+//! Expired games come from the contract's `get_expired_games` lobby view, so sequential games
+//! and `start_game` sessions are found alike. The keeper only talks to the contract through
+//! [`PokerRpc`], so the same scan runs against Stellar RPC ([`rpc::StellarRpc`]), a local
+//! Soroban sandbox or a mock in tests.
+
+pub mod rpc;
+
+use std::fmt;
+
+pub use poker_game_manager::Error as ContractError;
+use soroban_sdk::InvokeError;
+
+/// Error from a contract read or call.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RpcError {
+    /// The contract returned one of its errors.
+    Contract(ContractError),
+    /// Transport, RPC or decoding failure (including error codes the contract does not define).
+    Other(String),
+}
+
+impl RpcError {
+    /// Map `Error(Contract, #code)` onto the contract's error enum.
+    pub fn from_code(code: u32) -> Self {
+        ContractError::try_from(InvokeError::Contract(code))
+            .map(RpcError::Contract)
+            .unwrap_or_else(|_| RpcError::Other(format!("contract error #{code}")))
+    }
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RpcError::Contract(err) => write!(f, "contract error {err:?} (#{})", *err as u32),
+            RpcError::Other(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for RpcError {}
+
+/// One page of `get_expired_games`: the expired game ids and the cursor of the next page.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ExpiredPage {
+    pub games: Vec<u64>,
+    pub next: Option<u64>,
+}
+
+/// A player waiting alone at a table, from `get_table_waiting`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WaitingSeat {
+    pub created_at: u64,
+}
+
+/// The poker contract views and calls the keeper needs.
+pub trait PokerRpc {
+    /// Current ledger timestamp (seconds).
+    fn now(&self) -> Result<u64, RpcError>;
+    fn table_count(&self) -> Result<u64, RpcError>;
+    /// Waiting seat at a table, if any.
+    fn table_waiting(&self, table_id: u64) -> Result<Option<WaitingSeat>, RpcError>;
+    /// Seconds a seat may wait at this table before anyone can close it (0 = never).
+    fn waiting_timeout(&self, table_id: u64) -> Result<u64, RpcError>;
+    /// Games whose player on the clock is out of time, from position `cursor` of the
+    /// contract's active index.
+    fn expired_games(&self, cursor: u64, limit: u32) -> Result<ExpiredPage, RpcError>;
+    fn advance_timeout(&self, game_id: u64) -> Result<(), RpcError>;
+    fn cancel_waiting(&self, table_id: u64) -> Result<(), RpcError>;
+}
+
+/// What one pass over the contract did.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    /// Games advanced with `advance_timeout`.
+    pub advanced: Vec<u64>,
+    /// Tables whose waiting seat was closed with `cancel_waiting`.
+    pub cancelled: Vec<u64>,
+    /// Calls that failed, with their error (retried on the next pass).
+    pub failed: Vec<(Target, RpcError)>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Target {
+    Game(u64),
+    Table(u64),
+}
+
+/// Pages through the contract's expired games and every table, acting on what has expired.
+pub struct Keeper<R> {
+    rpc: R,
+    /// Games asked for per `get_expired_games` page.
+    page_size: u32,
+}
+
+impl<R: PokerRpc> Keeper<R> {
+    pub fn new(rpc: R) -> Self {
+        Self { rpc, page_size: 50 }
+    }
+
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    pub fn rpc(&self) -> &R {
+        &self.rpc
+    }
+
+    /// One pass: advance expired games, then close expired waiting seats. A call that fails
+    /// for one game or table is reported and left for the next pass; only failed reads of the
+    /// lobby views abort the pass.
+    pub fn scan_once(&mut self) -> Result<Report, RpcError> {
+        let mut report = Report::default();
+        let now = self.rpc.now()?;
+        self.scan_games(&mut report)?;
+        self.scan_tables(now, &mut report)?;
+        Ok(report)
+    }
+
+    fn scan_games(&mut self, report: &mut Report) -> Result<(), RpcError> {
+        // Read every page before acting: a game that settles hands its index position to
+        // another game, which would shift the pages still to come.
+        let mut expired = Vec::new();
+        let mut cursor = 0;
+        loop {
+            let page = self.rpc.expired_games(cursor, self.page_size)?;
+            expired.extend(page.games);
+            match page.next {
+                Some(next) if next > cursor => cursor = next,
+                _ => break,
+            }
+        }
+        for game_id in expired {
+            match self.rpc.advance_timeout(game_id) {
+                Ok(()) => report.advanced.push(game_id),
+                Err(e) => report.failed.push((Target::Game(game_id), e)),
+            }
+        }
+        Ok(())
+    }
+
+    fn scan_tables(&mut self, now: u64, report: &mut Report) -> Result<(), RpcError> {
+        for table_id in 0..self.rpc.table_count()? {
+            let Some(seat) = self.rpc.table_waiting(table_id)? else {
+                continue;
+            };
+            let timeout = self.rpc.waiting_timeout(table_id)?;
+            if timeout == 0 || now < seat.created_at.saturating_add(timeout) {
+                continue;
+            }
+            match self.rpc.cancel_waiting(table_id) {
+                Ok(()) => report.cancelled.push(table_id),
+                Err(e) => report.failed.push((Target::Table(table_id), e)),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
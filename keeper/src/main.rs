@@ -0,0 +1,86 @@
+//! poker-keeper: advance expired Poker ZK games and close expired waiting seats.
+//!
+//! Configuration comes from the environment:
+//! - `KEEPER_CONTRACT_ID` (required): poker contract id.
+//! - `KEEPER_SECRET` (required): secret key (S...) of the account that signs keeper
+//!   transactions; it is also the `caller` keeper bounties are paid to.
+//! - `KEEPER_RPC_URL`: Stellar RPC endpoint (default: testnet).
+//! - `KEEPER_NETWORK_PASSPHRASE`: network passphrase (default: testnet).
+//! - `KEEPER_INTERVAL`: seconds between passes (default: 30).
+//! - `KEEPER_PAGE_SIZE`: expired games read per `get_expired_games` page (default: 50).
+//!
+//! Pass `--once` to run a single pass and exit.
+
+use std::env;
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+use poker_keeper::rpc::StellarRpc;
+use poker_keeper::{Keeper, Target};
+
+const TESTNET_RPC_URL: &str = "https://soroban-testnet.stellar.org";
+const TESTNET_PASSPHRASE: &str = "Test SDF Network ; September 2015";
+
+fn var(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|v| !v.is_empty())
+}
+
+fn number(name: &str, default: u64) -> Result<u64, String> {
+    match var(name) {
+        Some(v) => v.parse().map_err(|_| format!("{name} must be a number, got {v}")),
+        None => Ok(default),
+    }
+}
+
+fn run() -> Result<(), String> {
+    let contract_id = var("KEEPER_CONTRACT_ID").ok_or("KEEPER_CONTRACT_ID is not set")?;
+    let secret = var("KEEPER_SECRET").ok_or("KEEPER_SECRET is not set")?;
+    let rpc = StellarRpc::new(
+        var("KEEPER_RPC_URL").unwrap_or_else(|| TESTNET_RPC_URL.to_string()),
+        var("KEEPER_NETWORK_PASSPHRASE").unwrap_or_else(|| TESTNET_PASSPHRASE.to_string()),
+        &contract_id,
+        &secret,
+    )
+    .map_err(|e| e.to_string())?;
+    println!("keeper account {}", rpc.account());
+    let interval = Duration::from_secs(number("KEEPER_INTERVAL", 30)?);
+    let once = env::args().any(|a| a == "--once");
+    let page_size = u32::try_from(number("KEEPER_PAGE_SIZE", 50)?)
+        .map_err(|_| "KEEPER_PAGE_SIZE is too large".to_string())?;
+    let mut keeper = Keeper::new(rpc).with_page_size(page_size);
+
+    loop {
+        match keeper.scan_once() {
+            Ok(report) => {
+                for game_id in &report.advanced {
+                    println!("advanced game {game_id}");
+                }
+                for table_id in &report.cancelled {
+                    println!("closed waiting seat at table {table_id}");
+                }
+                for (target, err) in &report.failed {
+                    match target {
+                        Target::Game(id) => eprintln!("game {id}: {err}"),
+                        Target::Table(id) => eprintln!("table {id}: {err}"),
+                    }
+                }
+            }
+            Err(err) => eprintln!("scan failed: {err}"),
+        }
+        if once {
+            return Ok(());
+        }
+        thread::sleep(interval);
+    }
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("poker-keeper: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,430 @@
+//! [`PokerRpc`] over Stellar RPC. Every view and call is driven by the function's entry in the
+//! poker contract's spec (`PokerZkContract::spec_xdr_*`): the function name comes from the
+//! spec, the arguments are checked against its inputs before anything is sent, and the result
+//! is decoded into the contract's own Rust type. A contract change that breaks a keeper call
+//! fails the keeper's build or tests instead of a transaction.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ed25519_dalek::{Signer, SigningKey};
+use poker_game_manager::{GamePage, PokerZkContract, TimeoutConfig, WaitingSession};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use soroban_sdk::xdr::{
+    AccountId, DecoratedSignature, Hash, HostFunction, InvokeContractArgs, InvokeHostFunctionOp,
+    LedgerEntryData, LedgerKey, LedgerKeyAccount, Limits, Memo, MuxedAccount, Operation,
+    OperationBody, Preconditions, PublicKey, ReadXdr, ScAddress, ScSpecEntry,
+    ScSpecFunctionV0, ScSpecTypeDef, ScVal, SequenceNumber, Signature, SignatureHint,
+    SorobanAuthorizationEntry, SorobanTransactionData, Transaction, TransactionEnvelope,
+    TransactionExt, TransactionSignaturePayload, TransactionSignaturePayloadTaggedTransaction,
+    TransactionV1Envelope, Uint256, WriteXdr,
+};
+use soroban_sdk::{Address, Env, IntoVal, TryFromVal, Val, Vec};
+
+use crate::{ContractError, ExpiredPage, PokerRpc, RpcError, WaitingSeat};
+
+/// Inclusion fee offered per transaction, on top of the resource fee from simulation.
+const BASE_FEE: u32 = 100;
+/// Times a sent transaction is looked up (one second apart) before giving up on it.
+const POLL_ATTEMPTS: u32 = 30;
+
+/// Sends a JSON-RPC request body to the RPC server and returns the response body.
+pub trait Transport {
+    fn post(&self, url: &str, body: &str) -> Result<String, RpcError>;
+}
+
+/// HTTP POST through the `curl` binary.
+pub struct Curl;
+
+impl Transport for Curl {
+    fn post(&self, url: &str, body: &str) -> Result<String, RpcError> {
+        let mut child = Command::new("curl")
+            .args(["-sS", "--fail-with-body", "-X", "POST", url])
+            .args(["-H", "Content-Type: application/json", "--data-binary", "@-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| RpcError::Other(format!("curl: {e}")))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(body.as_bytes())
+                .map_err(|e| RpcError::Other(format!("curl: {e}")))?;
+        }
+        let out = child
+            .wait_with_output()
+            .map_err(|e| RpcError::Other(format!("curl: {e}")))?;
+        if !out.status.success() {
+            let stderr = String::from_utf8_lossy(&out.stderr);
+            return Err(RpcError::Other(format!("{url}: {}", stderr.trim())));
+        }
+        String::from_utf8(out.stdout).map_err(|e| RpcError::Other(format!("{url}: {e}")))
+    }
+}
+
+pub struct StellarRpc<T = Curl> {
+    /// Stellar RPC endpoint (e.g. https://soroban-testnet.stellar.org).
+    pub url: String,
+    pub network_passphrase: String,
+    contract: ScAddress,
+    /// Signs and pays for keeper transactions, and is the `caller` keeper bounties go to.
+    signer: SigningKey,
+    caller: Address,
+    transport: T,
+    /// Host used to convert between the contract's types and XDR; nothing runs in it.
+    env: Env,
+}
+
+impl StellarRpc<Curl> {
+    /// `contract_id` is the poker contract (C...), `secret` the keeper account's seed (S...).
+    pub fn new(
+        url: String,
+        network_passphrase: String,
+        contract_id: &str,
+        secret: &str,
+    ) -> Result<Self, RpcError> {
+        Self::with_transport(url, network_passphrase, contract_id, secret, Curl)
+    }
+}
+
+impl<T: Transport> StellarRpc<T> {
+    pub fn with_transport(
+        url: String,
+        network_passphrase: String,
+        contract_id: &str,
+        secret: &str,
+        transport: T,
+    ) -> Result<Self, RpcError> {
+        let contract = contract_id
+            .parse::<ScAddress>()
+            .map_err(|_| RpcError::Other(format!("not a contract id: {contract_id}")))?;
+        let seed = stellar_strkey::ed25519::PrivateKey::from_string(secret)
+            .map_err(|_| RpcError::Other("not a secret key (S...)".to_string()))?;
+        let signer = SigningKey::from_bytes(&seed.0);
+        let env = Env::default();
+        let account = ScVal::Address(ScAddress::Account(account_id(&signer)));
+        let caller = Address::try_from_val(&env, &account)
+            .map_err(|_| RpcError::Other("cannot use the keeper account as caller".to_string()))?;
+        Ok(Self {
+            url,
+            network_passphrase,
+            contract,
+            signer,
+            caller,
+            transport,
+            env,
+        })
+    }
+
+    /// Account that signs keeper transactions and receives keeper bounties (G...).
+    pub fn account(&self) -> String {
+        stellar_strkey::ed25519::PublicKey(self.signer.verifying_key().to_bytes()).to_string()
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    fn request(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let response = self.transport.post(&self.url, &body.to_string())?;
+        let mut response: Value = serde_json::from_str(&response)
+            .map_err(|e| RpcError::Other(format!("{method}: {e}")))?;
+        if let Some(error) = response.get("error") {
+            return Err(RpcError::Other(format!("{method}: {error}")));
+        }
+        Ok(response["result"].take())
+    }
+
+    /// Simulate a contract function; with `send`, also sign, submit and wait for it.
+    fn invoke<A, R>(&self, spec: &[u8], args: A, send: bool) -> Result<R, RpcError>
+    where
+        A: IntoVal<Env, Vec<Val>>,
+        R: TryFromVal<Env, Val>,
+    {
+        let function = spec_function(spec);
+        let name = function.name.to_utf8_string_lossy();
+        let args = to_xdr_args(&self.env, args)?;
+        check_args(&function, &args)
+            .map_err(|input| RpcError::Other(format!("{name}: {input} does not match the spec")))?;
+        let sequence = if send { self.next_sequence()? } else { 0 };
+        let mut tx = self.transaction(&function, args, sequence)?;
+
+        let unsigned = envelope(&tx, None)?;
+        let sim = self.request("simulateTransaction", json!({ "transaction": unsigned }))?;
+        if let Some(error) = sim["error"].as_str() {
+            return Err(parse_error(error));
+        }
+        let result = &sim["results"][0];
+        let value = result["xdr"].as_str().unwrap_or_default();
+        let value = ScVal::from_xdr_base64(value, Limits::none())
+            .map_err(|e| RpcError::Other(format!("{name}: result: {e}")))?;
+        let value = Val::try_from_val(&self.env, &value)
+            .ok()
+            .and_then(|val| R::try_from_val(&self.env, &val).ok())
+            .ok_or_else(|| RpcError::Other(format!("{name}: unexpected result {value:?}")))?;
+        if !send {
+            return Ok(value);
+        }
+
+        let data = SorobanTransactionData::from_xdr_base64(
+            sim["transactionData"].as_str().unwrap_or_default(),
+            Limits::none(),
+        )
+        .map_err(|e| RpcError::Other(format!("{name}: transaction data: {e}")))?;
+        let resource_fee: u32 = as_u64(&sim["minResourceFee"])?
+            .try_into()
+            .map_err(|_| RpcError::Other(format!("{name}: resource fee too high")))?;
+        let auth = result["auth"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|entry| entry.as_str().unwrap_or_default())
+            .map(|entry| SorobanAuthorizationEntry::from_xdr_base64(entry, Limits::none()))
+            .collect::<Result<std::vec::Vec<_>, _>>()
+            .map_err(|e| RpcError::Other(format!("{name}: auth: {e}")))?;
+        tx.fee = BASE_FEE.saturating_add(resource_fee);
+        tx.ext = TransactionExt::V1(data);
+        let mut operations = tx.operations.to_vec();
+        if let OperationBody::InvokeHostFunction(op) = &mut operations[0].body {
+            op.auth = auth.try_into().map_err(|_| RpcError::Other(format!("{name}: auth")))?;
+        }
+        tx.operations = operations
+            .try_into()
+            .map_err(|_| RpcError::Other(format!("{name}: operation")))?;
+        let signature = self.sign(&tx)?;
+        let sent = self.request(
+            "sendTransaction",
+            json!({ "transaction": envelope(&tx, Some(signature))? }),
+        )?;
+        match sent["status"].as_str() {
+            Some("PENDING") | Some("DUPLICATE") => {}
+            status => return Err(RpcError::Other(format!("{name}: not accepted ({status:?})"))),
+        }
+        let hash = sent["hash"].as_str().unwrap_or_default().to_string();
+        for attempt in 0..POLL_ATTEMPTS {
+            let status = self.request("getTransaction", json!({ "hash": hash }))?;
+            match status["status"].as_str() {
+                Some("SUCCESS") => return Ok(value),
+                Some("NOT_FOUND") if attempt + 1 < POLL_ATTEMPTS => {
+                    thread::sleep(Duration::from_secs(1))
+                }
+                status => return Err(RpcError::Other(format!("{name} {hash}: {status:?}"))),
+            }
+        }
+        Err(RpcError::Other(format!("{name} {hash}: not found")))
+    }
+
+    fn next_sequence(&self) -> Result<i64, RpcError> {
+        let key = LedgerKey::Account(LedgerKeyAccount {
+            account_id: account_id(&self.signer),
+        });
+        let key = key
+            .to_xdr_base64(Limits::none())
+            .map_err(|e| RpcError::Other(e.to_string()))?;
+        let entries = self.request("getLedgerEntries", json!({ "keys": [key] }))?;
+        let entry = entries["entries"][0]["xdr"]
+            .as_str()
+            .ok_or_else(|| RpcError::Other(format!("account {} not found", self.account())))?;
+        match LedgerEntryData::from_xdr_base64(entry, Limits::none()) {
+            Ok(LedgerEntryData::Account(account)) => Ok(account.seq_num.0.saturating_add(1)),
+            _ => Err(RpcError::Other(format!("account {}: unexpected entry", self.account()))),
+        }
+    }
+
+    fn transaction(
+        &self,
+        function: &ScSpecFunctionV0,
+        args: std::vec::Vec<ScVal>,
+        sequence: i64,
+    ) -> Result<Transaction, RpcError> {
+        let invoke = InvokeContractArgs {
+            contract_address: self.contract.clone(),
+            function_name: function.name.clone(),
+            args: args.try_into().map_err(|_| RpcError::Other("too many arguments".into()))?,
+        };
+        let op = Operation {
+            source_account: None,
+            body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+                host_function: HostFunction::InvokeContract(invoke),
+                auth: Default::default(),
+            }),
+        };
+        Ok(Transaction {
+            source_account: MuxedAccount::Ed25519(Uint256(self.signer.verifying_key().to_bytes())),
+            fee: BASE_FEE,
+            seq_num: SequenceNumber(sequence),
+            cond: Preconditions::None,
+            memo: Memo::None,
+            operations: [op].try_into().map_err(|_| RpcError::Other("operation".into()))?,
+            ext: TransactionExt::V0,
+        })
+    }
+
+    fn sign(&self, tx: &Transaction) -> Result<DecoratedSignature, RpcError> {
+        let payload = TransactionSignaturePayload {
+            network_id: Hash(Sha256::digest(self.network_passphrase.as_bytes()).into()),
+            tagged_transaction: TransactionSignaturePayloadTaggedTransaction::Tx(tx.clone()),
+        };
+        let payload = payload
+            .to_xdr(Limits::none())
+            .map_err(|e| RpcError::Other(e.to_string()))?;
+        let signature = self.signer.sign(&Sha256::digest(payload));
+        let public = self.signer.verifying_key().to_bytes();
+        Ok(DecoratedSignature {
+            hint: SignatureHint([public[28], public[29], public[30], public[31]]),
+            signature: Signature(
+                signature
+                    .to_bytes()
+                    .to_vec()
+                    .try_into()
+                    .map_err(|_| RpcError::Other("signature".into()))?,
+            ),
+        })
+    }
+}
+
+impl<T: Transport> PokerRpc for StellarRpc<T> {
+    /// Ledger close times track wall-clock time closely; a call made a few seconds early is
+    /// rejected with TimeoutNotReached and retried on the next pass.
+    fn now(&self) -> Result<u64, RpcError> {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .map_err(|e| RpcError::Other(e.to_string()))
+    }
+
+    fn table_count(&self) -> Result<u64, RpcError> {
+        self.invoke(&PokerZkContract::spec_xdr_get_table_count(), (), false)
+    }
+
+    fn table_waiting(&self, table_id: u64) -> Result<Option<WaitingSeat>, RpcError> {
+        let spec = PokerZkContract::spec_xdr_get_table_waiting();
+        match self.invoke::<_, WaitingSession>(&spec, (table_id,), false) {
+            Ok(seat) => Ok(Some(WaitingSeat {
+                created_at: seat.created_at,
+            })),
+            Err(RpcError::Contract(ContractError::NoWaitingSession)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn waiting_timeout(&self, table_id: u64) -> Result<u64, RpcError> {
+        let spec = PokerZkContract::spec_xdr_get_table_timeouts();
+        self.invoke::<_, TimeoutConfig>(&spec, (table_id,), false)
+            .map(|timeouts| timeouts.waiting)
+    }
+
+    fn expired_games(&self, cursor: u64, limit: u32) -> Result<ExpiredPage, RpcError> {
+        let spec = PokerZkContract::spec_xdr_get_expired_games();
+        let page: GamePage = self.invoke(&spec, (cursor, limit), false)?;
+        Ok(ExpiredPage {
+            games: page.games.iter().map(|game| game.id).collect(),
+            next: page.next,
+        })
+    }
+
+    fn advance_timeout(&self, game_id: u64) -> Result<(), RpcError> {
+        let spec = PokerZkContract::spec_xdr_advance_timeout();
+        self.invoke(&spec, (self.caller.clone(), game_id), true)
+    }
+
+    fn cancel_waiting(&self, table_id: u64) -> Result<(), RpcError> {
+        let spec = PokerZkContract::spec_xdr_cancel_waiting();
+        self.invoke(&spec, (self.caller.clone(), table_id), true)
+    }
+}
+
+/// Pull the contract error code out of a simulation error (`... Error(Contract, #22) ...`).
+pub fn parse_error(message: &str) -> RpcError {
+    const MARKER: &str = "Error(Contract, #";
+    message
+        .find(MARKER)
+        .and_then(|at| {
+            let rest = &message[at + MARKER.len()..];
+            let end = rest.find(|c: char| !c.is_ascii_digit())?;
+            rest[..end].parse().ok()
+        })
+        .map(RpcError::from_code)
+        .unwrap_or_else(|| RpcError::Other(message.trim().to_string()))
+}
+
+/// The contract function a `spec_xdr_*` entry describes. Those entries are generated with the
+/// contract, so one that does not decode is a build problem, not a runtime one.
+pub fn spec_function(spec: &[u8]) -> ScSpecFunctionV0 {
+    match ScSpecEntry::from_xdr(spec, Limits::none()) {
+        Ok(ScSpecEntry::FunctionV0(function)) => function,
+        _ => panic!("not a contract function spec entry"),
+    }
+}
+
+/// Check arguments against the function's inputs; on a mismatch, returns the input's name.
+pub fn check_args(function: &ScSpecFunctionV0, args: &[ScVal]) -> Result<(), String> {
+    if function.inputs.len() != args.len() {
+        return Err(format!("{} arguments", args.len()));
+    }
+    for (input, arg) in function.inputs.iter().zip(args) {
+        if !matches_type(&input.type_, arg) {
+            return Err(input.name.to_utf8_string_lossy());
+        }
+    }
+    Ok(())
+}
+
+fn matches_type(ty: &ScSpecTypeDef, val: &ScVal) -> bool {
+    match (ty, val) {
+        (ScSpecTypeDef::Bool, ScVal::Bool(_))
+        | (ScSpecTypeDef::U32, ScVal::U32(_))
+        | (ScSpecTypeDef::U64, ScVal::U64(_))
+        | (ScSpecTypeDef::I128, ScVal::I128(_))
+        | (ScSpecTypeDef::Address, ScVal::Address(_))
+        | (ScSpecTypeDef::Option(_), ScVal::Void) => true,
+        (ScSpecTypeDef::Option(inner), val) => matches_type(&inner.value_type, val),
+        (ScSpecTypeDef::Vec(vec), ScVal::Vec(Some(items))) => {
+            items.iter().all(|item| matches_type(&vec.element_type, item))
+        }
+        _ => false,
+    }
+}
+
+fn to_xdr_args<A: IntoVal<Env, Vec<Val>>>(
+    env: &Env,
+    args: A,
+) -> Result<std::vec::Vec<ScVal>, RpcError> {
+    let args: Vec<Val> = args.into_val(env);
+    args.iter()
+        .map(|arg| ScVal::try_from_val(env, &arg))
+        .collect::<Result<_, _>>()
+        .map_err(|e| RpcError::Other(format!("argument: {e:?}")))
+}
+
+fn account_id(signer: &SigningKey) -> AccountId {
+    AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(
+        signer.verifying_key().to_bytes(),
+    )))
+}
+
+/// A transaction envelope in base64, signed or (for simulation) not.
+fn envelope(tx: &Transaction, signature: Option<DecoratedSignature>) -> Result<String, RpcError> {
+    TransactionEnvelope::Tx(TransactionV1Envelope {
+        tx: tx.clone(),
+        signatures: signature
+            .into_iter()
+            .collect::<std::vec::Vec<_>>()
+            .try_into()
+            .unwrap_or_default(),
+    })
+    .to_xdr_base64(Limits::none())
+    .map_err(|e| RpcError::Other(e.to_string()))
+}
+
+/// Numbers from RPC come as JSON numbers or, when large, as strings.
+fn as_u64(value: &Value) -> Result<u64, RpcError> {
+    value
+        .as_u64()
+        .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+        .ok_or_else(|| RpcError::Other(format!("expected an integer, got {value}")))
+}
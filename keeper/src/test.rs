@@ -0,0 +1,402 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, VecDeque};
+
+use game_hub::GameHub;
+use poker_game_manager::{Error, GameState, PokerZkContract, PokerZkContractClient, SESSION_ID_BASE};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use ed25519_dalek::{SigningKey, Verifier};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use soroban_sdk::xdr::{
+    AccountEntry, AccountEntryExt, AccountId, ContractId, Hash, HostFunction, InvokeContractArgs,
+    LedgerEntryData, LedgerFootprint, Limits, OperationBody, PublicKey, ReadXdr, ScAddress, ScVal,
+    SequenceNumber, SorobanResources, SorobanTransactionData, SorobanTransactionDataExt,
+    Thresholds, TransactionEnvelope, TransactionExt, TransactionSignaturePayload,
+    TransactionSignaturePayloadTaggedTransaction, TransactionV1Envelope, Uint256, WriteXdr,
+};
+use soroban_sdk::{Address, Env, IntoVal, InvokeError, TryFromVal, Val};
+
+use crate::rpc::{check_args, parse_error, spec_function, StellarRpc, Transport};
+use crate::{ExpiredPage, Keeper, PokerRpc, RpcError, Target, WaitingSeat};
+
+/// The poker contract in a local Soroban sandbox, driven through its generated client.
+struct Sandbox {
+    env: Env,
+    admin: Address,
+    client: PokerZkContractClient<'static>,
+    keeper: Address,
+}
+
+/// Map a `try_*` result from the generated client onto the keeper's errors.
+fn call<T, C: std::fmt::Debug>(
+    result: Result<Result<T, C>, Result<Error, InvokeError>>,
+) -> Result<T, RpcError> {
+    match result {
+        Ok(value) => value.map_err(|e| RpcError::Other(format!("{e:?}"))),
+        Err(Ok(err)) => Err(RpcError::Contract(err)),
+        Err(Err(err)) => Err(RpcError::Other(format!("{err:?}"))),
+    }
+}
+
+impl PokerRpc for Sandbox {
+    fn now(&self) -> Result<u64, RpcError> {
+        Ok(self.env.ledger().timestamp())
+    }
+
+    fn table_count(&self) -> Result<u64, RpcError> {
+        Ok(self.client.get_table_count())
+    }
+
+    fn table_waiting(&self, table_id: u64) -> Result<Option<WaitingSeat>, RpcError> {
+        match call(self.client.try_get_table_waiting(&table_id)) {
+            Ok(seat) => Ok(Some(WaitingSeat {
+                created_at: seat.created_at,
+            })),
+            Err(RpcError::Contract(Error::NoWaitingSession)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn waiting_timeout(&self, table_id: u64) -> Result<u64, RpcError> {
+        call(self.client.try_get_table_timeouts(&table_id)).map(|t| t.waiting)
+    }
+
+    fn expired_games(&self, cursor: u64, limit: u32) -> Result<ExpiredPage, RpcError> {
+        let page = call(self.client.try_get_expired_games(&cursor, &limit))?;
+        Ok(ExpiredPage {
+            games: page.games.iter().map(|game| game.id).collect(),
+            next: page.next,
+        })
+    }
+
+    fn advance_timeout(&self, game_id: u64) -> Result<(), RpcError> {
+        call(self.client.try_advance_timeout(&self.keeper, &game_id))
+    }
+
+    fn cancel_waiting(&self, table_id: u64) -> Result<(), RpcError> {
+        call(self.client.try_cancel_waiting(&self.keeper, &table_id))
+    }
+}
+
+fn sandbox() -> Sandbox {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_441_065_600;
+        li.min_temp_entry_ttl = u32::MAX / 2;
+        li.min_persistent_entry_ttl = u32::MAX / 2;
+        li.max_entry_ttl = u32::MAX / 2;
+    });
+    let hub = env.register(GameHub, ());
+    let admin = Address::generate(&env);
+    let contract_id = env.register(
+        PokerZkContract,
+        (&admin, &hub, &5i128, &10i128, &100i128, &10_000i128, &2u32),
+    );
+    let client = PokerZkContractClient::new(&env, &contract_id);
+    let keeper = Address::generate(&env);
+    Sandbox {
+        env,
+        admin,
+        client,
+        keeper,
+    }
+}
+
+fn advance_time(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+#[test]
+fn test_sandbox_advances_expired_games_and_closes_waiting_seats() {
+    let sandbox = sandbox();
+    let env = sandbox.env.clone();
+    let client = PokerZkContractClient::new(&env, &sandbox.client.address);
+    let table = client.add_table(&sandbox.admin, &10i128, &20i128, &100i128, &10_000i128, &2u32);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let game_id = client.sit_at_table(&player1, &0u64, &1000).game_id;
+    client.sit_at_table(&player2, &0u64, &1000);
+    // A start_game session lives above the sequential ids; the keeper finds it all the same.
+    client.start_game(&7u64, &0u64, &Address::generate(&env), &Address::generate(&env), &1000);
+    let session_game = SESSION_ID_BASE + 7;
+    client.sit_at_table(&Address::generate(&env), &table, &1000);
+    let mut keeper = Keeper::new(sandbox).with_page_size(1);
+
+    // Nothing has expired yet.
+    assert_eq!(keeper.scan_once().unwrap(), Default::default());
+
+    let clock = client.get_turn_clock(&game_id);
+    let waiting = client.get_table_timeouts(&table).waiting;
+    advance_time(&env, (clock.deadline - env.ledger().timestamp()).max(waiting) + 1);
+    let report = keeper.scan_once().unwrap();
+    assert_eq!(report.advanced, vec![game_id, session_game]);
+    assert_eq!(report.cancelled, vec![table]);
+    assert!(report.failed.is_empty());
+    // Nobody committed a seed, so both cash games were cancelled and leave the lobby.
+    assert_eq!(client.get_game(&game_id).state, GameState::Cancelled);
+    assert_eq!(client.get_game(&session_game).state, GameState::Cancelled);
+    assert_eq!(keeper.scan_once().unwrap(), Default::default());
+}
+
+/// Scripted contract for exercising the scan without a ledger.
+#[derive(Default)]
+struct MockRpc {
+    now: u64,
+    /// Expired game ids, in index order.
+    expired: Vec<u64>,
+    /// Games whose advance_timeout fails, with the error.
+    reject: BTreeMap<u64, Error>,
+    pages: RefCell<Vec<u64>>,
+    advanced: RefCell<Vec<u64>>,
+}
+
+impl PokerRpc for MockRpc {
+    fn now(&self) -> Result<u64, RpcError> {
+        Ok(self.now)
+    }
+    fn table_count(&self) -> Result<u64, RpcError> {
+        Ok(0)
+    }
+    fn table_waiting(&self, _table_id: u64) -> Result<Option<WaitingSeat>, RpcError> {
+        Ok(None)
+    }
+    fn waiting_timeout(&self, _table_id: u64) -> Result<u64, RpcError> {
+        Ok(0)
+    }
+    fn expired_games(&self, cursor: u64, limit: u32) -> Result<ExpiredPage, RpcError> {
+        self.pages.borrow_mut().push(cursor);
+        let end = (cursor + limit as u64).min(self.expired.len() as u64);
+        Ok(ExpiredPage {
+            games: self.expired[cursor as usize..end as usize].to_vec(),
+            next: (end < self.expired.len() as u64).then_some(end),
+        })
+    }
+    fn advance_timeout(&self, game_id: u64) -> Result<(), RpcError> {
+        if let Some(err) = self.reject.get(&game_id) {
+            return Err(RpcError::Contract(*err));
+        }
+        self.advanced.borrow_mut().push(game_id);
+        Ok(())
+    }
+    fn cancel_waiting(&self, _table_id: u64) -> Result<(), RpcError> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_scan_pages_through_expired_games_and_skips_failures() {
+    let rpc = MockRpc {
+        now: 100,
+        expired: vec![3, 7, SESSION_ID_BASE + 2, 9, 12],
+        reject: BTreeMap::from([(7, Error::GameExpired), (9, Error::TimeoutNotReached)]),
+        ..Default::default()
+    };
+    let mut keeper = Keeper::new(rpc).with_page_size(2);
+    let report = keeper.scan_once().unwrap();
+    // One failing game does not stop the pass.
+    assert_eq!(report.advanced, vec![3, SESSION_ID_BASE + 2, 12]);
+    assert_eq!(
+        report.failed,
+        vec![
+            (Target::Game(7), RpcError::Contract(Error::GameExpired)),
+            (Target::Game(9), RpcError::Contract(Error::TimeoutNotReached)),
+        ]
+    );
+    assert_eq!(*keeper.rpc().pages.borrow(), vec![0, 2, 4]);
+}
+
+/// Stellar RPC server that replies from a script and records every request.
+#[derive(Default)]
+struct ScriptedRpc {
+    replies: RefCell<VecDeque<Value>>,
+    requests: RefCell<Vec<Value>>,
+}
+
+impl Transport for &ScriptedRpc {
+    fn post(&self, _url: &str, body: &str) -> Result<String, RpcError> {
+        self.requests.borrow_mut().push(serde_json::from_str(body).unwrap());
+        let reply = self.replies.borrow_mut().pop_front().expect("unscripted request");
+        Ok(json!({ "jsonrpc": "2.0", "id": 1, "result": reply }).to_string())
+    }
+}
+
+const SECRET: [u8; 32] = [7; 32];
+
+fn stellar_rpc(server: &ScriptedRpc, replies: Vec<Value>) -> StellarRpc<&ScriptedRpc> {
+    server.replies.replace(replies.into());
+    StellarRpc::with_transport(
+        "http://rpc.test".into(),
+        "Test SDF Network ; September 2015".into(),
+        &stellar_strkey::Contract([1; 32]).to_string(),
+        &stellar_strkey::ed25519::PrivateKey(SECRET).to_string(),
+        server,
+    )
+    .unwrap()
+}
+
+fn xdr<T: WriteXdr>(value: &T) -> Value {
+    Value::String(value.to_xdr_base64(Limits::none()).unwrap())
+}
+
+/// The transaction sent with request `index`, and its contract call.
+fn sent(server: &ScriptedRpc, index: usize) -> (TransactionV1Envelope, InvokeContractArgs) {
+    let requests = server.requests.borrow();
+    let envelope = requests[index]["params"]["transaction"].as_str().unwrap();
+    let TransactionEnvelope::Tx(envelope) =
+        TransactionEnvelope::from_xdr_base64(envelope, Limits::none()).unwrap()
+    else {
+        panic!("not a v1 envelope");
+    };
+    let OperationBody::InvokeHostFunction(op) = &envelope.tx.operations[0].body else {
+        panic!("not a contract call");
+    };
+    let HostFunction::InvokeContract(call) = &op.host_function else {
+        panic!("not a contract call");
+    };
+    let call = call.clone();
+    (envelope, call)
+}
+
+#[test]
+fn test_rpc_views_decode_contract_types() {
+    let sandbox = sandbox();
+    let env = sandbox.env.clone();
+    let client = PokerZkContractClient::new(&env, &sandbox.client.address);
+    let game_id = client.sit_at_table(&Address::generate(&env), &0u64, &1000).game_id;
+    client.sit_at_table(&Address::generate(&env), &0u64, &1000);
+    advance_time(&env, client.get_turn_clock(&game_id).deadline);
+    let page = client.get_expired_games(&0u64, &10u32);
+    assert_eq!(page.games.len(), 1);
+
+    let page: Val = page.into_val(&env);
+    let page = ScVal::try_from_val(&env, &page).unwrap();
+
+    let server = ScriptedRpc::default();
+    let rpc = stellar_rpc(&server, vec![json!({ "results": [{ "xdr": xdr(&page) }] })]);
+    let expired = rpc.expired_games(0, 10).unwrap();
+    assert_eq!(expired, ExpiredPage { games: vec![game_id], next: None });
+
+    let requests = server.requests.borrow();
+    assert_eq!(requests[0]["method"], "simulateTransaction");
+    drop(requests);
+    let (_, call) = sent(&server, 0);
+    assert_eq!(call.function_name.to_utf8_string_lossy(), "get_expired_games");
+    assert_eq!(call.args.to_vec(), vec![ScVal::U64(0), ScVal::U32(10)]);
+}
+
+#[test]
+fn test_rpc_view_with_the_wrong_result_type_is_an_error() {
+    let server = ScriptedRpc::default();
+    let rpc = stellar_rpc(&server, vec![json!({ "results": [{ "xdr": xdr(&ScVal::U32(3)) }] })]);
+    assert!(matches!(rpc.table_count(), Err(RpcError::Other(_))));
+}
+
+#[test]
+fn test_rpc_contract_errors_are_decoded() {
+    let server = ScriptedRpc::default();
+    let rpc = stellar_rpc(
+        &server,
+        vec![
+            json!({ "error": "HostError: Error(Contract, #26)" }),
+            json!({ "error": "HostError: Error(Contract, #47)" }),
+        ],
+    );
+    // No waiting seat is not a failure.
+    assert_eq!(rpc.table_waiting(4), Ok(None));
+    assert_eq!(
+        rpc.waiting_timeout(4),
+        Err(RpcError::Contract(Error::GameExpired))
+    );
+    assert_eq!(
+        parse_error("HostError: Error(Contract, #999)"),
+        RpcError::Other("contract error #999".into())
+    );
+    assert_eq!(
+        parse_error("network unreachable\n"),
+        RpcError::Other("network unreachable".into())
+    );
+}
+
+#[test]
+fn test_rpc_calls_are_signed_and_submitted() {
+    let signer = SigningKey::from_bytes(&SECRET);
+    let public = signer.verifying_key();
+    let account = AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(public.to_bytes())));
+    let entry = LedgerEntryData::Account(AccountEntry {
+        account_id: account.clone(),
+        balance: 100_000_000,
+        seq_num: SequenceNumber(41),
+        num_sub_entries: 0,
+        inflation_dest: None,
+        flags: 0,
+        home_domain: Default::default(),
+        thresholds: Thresholds([1, 0, 0, 0]),
+        signers: Default::default(),
+        ext: AccountEntryExt::V0,
+    });
+    let data = SorobanTransactionData {
+        ext: SorobanTransactionDataExt::V0,
+        resources: SorobanResources {
+            footprint: LedgerFootprint {
+                read_only: Default::default(),
+                read_write: Default::default(),
+            },
+            instructions: 1_000,
+            disk_read_bytes: 0,
+            write_bytes: 0,
+        },
+        resource_fee: 900,
+    };
+    let server = ScriptedRpc::default();
+    let rpc = stellar_rpc(
+        &server,
+        vec![
+            json!({ "entries": [{ "xdr": xdr(&entry) }] }),
+            json!({
+                "results": [{ "xdr": xdr(&ScVal::Void), "auth": [] }],
+                "transactionData": xdr(&data),
+                "minResourceFee": "900",
+            }),
+            json!({ "status": "PENDING", "hash": "ab" }),
+            json!({ "status": "SUCCESS" }),
+        ],
+    );
+    assert_eq!(rpc.account(), stellar_strkey::ed25519::PublicKey(public.to_bytes()).to_string());
+    rpc.advance_timeout(9).unwrap();
+
+    let methods: Vec<_> = server.requests.borrow().iter().map(|r| r["method"].clone()).collect();
+    assert_eq!(
+        methods,
+        vec!["getLedgerEntries", "simulateTransaction", "sendTransaction", "getTransaction"]
+    );
+    let (envelope, call) = sent(&server, 2);
+    assert_eq!(call.function_name.to_utf8_string_lossy(), "advance_timeout");
+    // The keeper account is both the source and the caller the bounty goes to.
+    assert_eq!(
+        call.args.to_vec(),
+        vec![ScVal::Address(ScAddress::Account(account)), ScVal::U64(9)]
+    );
+    assert_eq!(envelope.tx.seq_num, SequenceNumber(42));
+    assert_eq!(envelope.tx.fee, 1_000);
+    assert_eq!(envelope.tx.ext, TransactionExt::V1(data));
+    let payload = TransactionSignaturePayload {
+        network_id: Hash(Sha256::digest("Test SDF Network ; September 2015").into()),
+        tagged_transaction: TransactionSignaturePayloadTaggedTransaction::Tx(envelope.tx.clone()),
+    };
+    let hash = Sha256::digest(payload.to_xdr(Limits::none()).unwrap());
+    let signature = &envelope.signatures[0];
+    assert_eq!(signature.hint.0, public.to_bytes()[28..]);
+    let signature = ed25519_dalek::Signature::from_slice(&signature.signature).unwrap();
+    public.verify(&hash, &signature).unwrap();
+}
+
+#[test]
+fn test_rpc_arguments_are_checked_against_the_spec() {
+    let spec = spec_function(&PokerZkContract::spec_xdr_advance_timeout());
+    let caller = ScVal::Address(ScAddress::Contract(ContractId(Hash([1; 32]))));
+    assert_eq!(check_args(&spec, &[caller.clone(), ScVal::U64(1)]), Ok(()));
+    assert_eq!(check_args(&spec, &[caller.clone(), ScVal::U32(1)]), Err("game_id".into()));
+    assert_eq!(check_args(&spec, &[ScVal::U64(1), caller]), Err("caller".into()));
+    assert_eq!(check_args(&spec, &[ScVal::U64(1)]), Err("1 arguments".into()));
+}
@@ -91,7 +91,7 @@ update_env_key() {
   fi
 }
 
-# Build and deploy PaymentController (optional, for escrow)
+# Build and deploy PaymentController (optional, for escrow): __constructor(admin)
 echo "Building payment-controller..."
 stellar contract build --package payment-controller 2>/dev/null || true
 PC_WASM="$ROOT/target/wasm32v1-none/release/payment_controller.wasm"
@@ -102,7 +102,9 @@ if [[ -f "$PC_WASM" ]]; then
     --source-account "$SOURCE_ACCOUNT" \
     --network testnet \
     --rpc-url "$RPC_URL" \
-    --network-passphrase "$NETWORK_PASSPHRASE")
+    --network-passphrase "$NETWORK_PASSPHRASE" \
+    -- \
+    --admin "$ADMIN")
   echo "Deployed payment-controller: $PC_ID"
   echo ""
   echo "To enable escrow:"
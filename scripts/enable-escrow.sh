@@ -72,7 +72,8 @@ if [[ ! -f "$PC_WASM" ]]; then
 fi
 echo "Deploying payment-controller..."
 PC_ID=$(stellar contract deploy --wasm "$PC_WASM" --source-account "$SOURCE_ACCOUNT" \
-  --network testnet --rpc-url "$RPC_URL" --network-passphrase "$NETWORK_PASSPHRASE")
+  --network testnet --rpc-url "$RPC_URL" --network-passphrase "$NETWORK_PASSPHRASE" \
+  -- --admin "$ADMIN")
 echo "Payment-controller: $PC_ID"
 echo ""
 